@@ -0,0 +1,86 @@
+//! Added ninth chord implementation.
+//!
+//! The added ninth chord adds a major ninth to the major triad, without the
+//! seventh of the major ninth chord.
+//!
+//! # Chord Structure
+//!
+//! The added ninth chord built on C is made of the following notes:
+//! ```text
+//! C E G D
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The added ninth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Major 9th (14)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for added ninth chords.
+///
+/// This type is used to distinguish added ninth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddedNinthType;
+
+impl ChordType for AddedNinthType {
+    fn name() -> &'static str {
+        "added ninth"
+    }
+
+    fn symbol() -> &'static str {
+        "add9"
+    }
+}
+
+/// The pattern for an added ninth chord.
+///
+/// This pattern defines the intervals that make up an added ninth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Major 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddedNinthPattern;
+
+impl ChordPattern for AddedNinthPattern {
+    type Pattern = [Interval; 4];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_NINTH];
+
+    type ChordTyp = AddedNinthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MajorTriadPattern;
+
+    #[test]
+    fn test_added_ninth_chord() {
+        let chord = AddedNinthPattern::apply(F3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 4);
+        assert_eq!(pitches[0], F3);
+        assert_eq!(pitches[1], A3);
+        assert_eq!(pitches[2], C4);
+        assert_eq!(pitches[3], G4);
+
+        assert_eq!(chord.root(), F3);
+        assert_eq!(chord.name(), "added ninth");
+        assert_eq!(chord.symbol(), "add9");
+        assert_eq!(chord.to_string(), "F3 added ninth");
+    }
+
+    #[test]
+    fn test_added_ninth_lower_structure() {
+        // The root, third and fifth form a major triad
+        let chord = AddedNinthPattern::apply(F3);
+        assert_eq!(
+            &chord.pitches()[..3],
+            MajorTriadPattern::apply(F3).pitches()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! Minor sixth chord implementation.
+//!
+//! The minor sixth chord adds a major sixth to the minor triad. It is the
+//! tonic chord of the dorian and melodic minor modes.
+//!
+//! # Chord Structure
+//!
+//! The minor sixth chord built on C is made of the following notes:
+//! ```text
+//! C Eb G A
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The minor sixth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 3rd (3) + Perfect 5th (7) + Major 6th (9)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for minor sixth chords.
+///
+/// This type is used to distinguish minor sixth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorSixthType;
+
+impl ChordType for MinorSixthType {
+    fn name() -> &'static str {
+        "minor sixth"
+    }
+
+    fn symbol() -> &'static str {
+        "m6"
+    }
+}
+
+/// The pattern for a minor sixth chord.
+///
+/// This pattern defines the intervals that make up a minor sixth chord:
+/// ```text
+/// Root + Minor 3rd + Perfect 5th + Major 6th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorSixthPattern;
+
+impl ChordPattern for MinorSixthPattern {
+    type Pattern = [Interval; 4];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MINOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+
+    type ChordTyp = MinorSixthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minor_sixth_chord() {
+        let chord = MinorSixthPattern::apply(D4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 4);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], F4);
+        assert_eq!(pitches[2], A4);
+        assert_eq!(pitches[3], B4);
+
+        assert_eq!(chord.root(), D4);
+        assert_eq!(chord.name(), "minor sixth");
+        assert_eq!(chord.symbol(), "m6");
+        assert_eq!(chord.to_string(), "D4 minor sixth");
+    }
+
+    #[test]
+    fn test_minor_sixth_spelling() {
+        let names: Vec<_> = MinorSixthPattern::spell(C4.spelled())
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect();
+        assert_eq!(names, ["C4", "Eb4", "G4", "A4"]);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod add9;
+pub mod minor_sixth;
+pub mod six_nine;
+pub mod sixth;
+
+pub use add9::*;
+pub use minor_sixth::*;
+pub use six_nine::*;
+pub use sixth::*;
@@ -0,0 +1,93 @@
+//! Six nine chord implementation.
+//!
+//! The six nine chord adds both a major sixth and a major ninth to the major
+//! triad. Its pitches are those of the major pentatonic scale.
+//!
+//! # Chord Structure
+//!
+//! The six nine chord built on C is made of the following notes:
+//! ```text
+//! C E G A D
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The six nine chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Major 6th (9) +
+//! Major 9th (14)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for six nine chords.
+///
+/// This type is used to distinguish six nine chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SixNineType;
+
+impl ChordType for SixNineType {
+    fn name() -> &'static str {
+        "six nine"
+    }
+
+    fn symbol() -> &'static str {
+        "6/9"
+    }
+}
+
+/// The pattern for a six nine chord.
+///
+/// This pattern defines the intervals that make up a six nine chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Major 6th +
+/// Major 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SixNinePattern;
+
+impl ChordPattern for SixNinePattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MAJOR_NINTH,
+    ];
+
+    type ChordTyp = SixNineType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_six_nine_chord() {
+        let chord = SixNinePattern::apply(G3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], G3);
+        assert_eq!(pitches[1], B3);
+        assert_eq!(pitches[2], D4);
+        assert_eq!(pitches[3], E4);
+        assert_eq!(pitches[4], A4);
+
+        assert_eq!(chord.root(), G3);
+        assert_eq!(chord.name(), "six nine");
+        assert_eq!(chord.symbol(), "6/9");
+        assert_eq!(chord.to_string(), "G3 six nine");
+    }
+
+    #[test]
+    fn test_six_nine_pitch_classes() {
+        // C6/9 holds the pitches of C major pentatonic
+        let chord: PitchClassSet = SixNinePattern::apply(C4).pitches().iter().collect();
+        let expected: PitchClassSet = [C, D, E, G, A].into_iter().collect();
+        assert_eq!(chord, expected);
+    }
+}
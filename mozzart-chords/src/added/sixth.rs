@@ -0,0 +1,84 @@
+//! Major sixth chord implementation.
+//!
+//! The major sixth chord adds a major sixth to the major triad. It is a common
+//! tonic chord in swing and early jazz.
+//!
+//! # Chord Structure
+//!
+//! The major sixth chord built on C is made of the following notes:
+//! ```text
+//! C E G A
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The major sixth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Major 6th (9)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for major sixth chords.
+///
+/// This type is used to distinguish major sixth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorSixthType;
+
+impl ChordType for MajorSixthType {
+    fn name() -> &'static str {
+        "major sixth"
+    }
+
+    fn symbol() -> &'static str {
+        "6"
+    }
+}
+
+/// The pattern for a major sixth chord.
+///
+/// This pattern defines the intervals that make up a major sixth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Major 6th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorSixthPattern;
+
+impl ChordPattern for MajorSixthPattern {
+    type Pattern = [Interval; 4];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+
+    type ChordTyp = MajorSixthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_major_sixth_chord() {
+        let chord = MajorSixthPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 4);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], A4);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "major sixth");
+        assert_eq!(chord.symbol(), "6");
+        assert_eq!(chord.to_string(), "C4 major sixth");
+    }
+
+    #[test]
+    fn test_major_sixth_relative_minor_seventh() {
+        // C6 holds the pitches of Am7
+        let chord: PitchClassSet = MajorSixthPattern::apply(C4).pitches().iter().collect();
+        let expected: PitchClassSet = [A, C, E, G].into_iter().collect();
+        assert_eq!(chord, expected);
+    }
+}
@@ -0,0 +1,100 @@
+//! Altered dominant chord implementation.
+//!
+//! The altered dominant chord keeps the third and seventh of the dominant
+//! seventh chord, and replaces its fifth and ninth by every altered tension:
+//! the flat and sharp ninths, the sharp eleventh and the flat thirteenth.
+//! Its pitches are those of the altered scale.
+//!
+//! # Chord Structure
+//!
+//! The altered dominant chord built on C is made of the following notes:
+//! ```text
+//! C E Bb Db D# F# Ab
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The altered dominant chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Minor 7th (10) + Minor 9th (13) +
+//! Augmented 9th (15) + Augmented 11th (18) + Minor 13th (20)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for altered dominant chords.
+///
+/// This type is used to distinguish altered dominant chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlteredDominantType;
+
+impl ChordType for AlteredDominantType {
+    fn name() -> &'static str {
+        "altered dominant"
+    }
+
+    fn symbol() -> &'static str {
+        "7alt"
+    }
+}
+
+/// The pattern for an altered dominant chord.
+///
+/// This pattern defines the intervals that make up an altered dominant chord:
+/// ```text
+/// Root + Major 3rd + Minor 7th + Minor 9th +
+/// Augmented 9th + Augmented 11th + Minor 13th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlteredDominantPattern;
+
+impl ChordPattern for AlteredDominantPattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        MINOR_SEVENTH,
+        MINOR_NINTH,
+        AUGMENTED_NINTH,
+        AUGMENTED_ELEVENTH,
+        MINOR_THIRTEENTH,
+    ];
+
+    type ChordTyp = AlteredDominantType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_altered_dominant_chord() {
+        let chord = AlteredDominantPattern::apply(G3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], G3);
+        assert_eq!(pitches[1], B3);
+        assert_eq!(pitches[2], F4);
+        assert_eq!(pitches[3], AFLAT4);
+        assert_eq!(pitches[4], BFLAT4);
+        assert_eq!(pitches[5], DFLAT5);
+        assert_eq!(pitches[6], EFLAT5);
+
+        assert_eq!(chord.root(), G3);
+        assert_eq!(chord.name(), "altered dominant");
+        assert_eq!(chord.symbol(), "7alt");
+        assert_eq!(chord.to_string(), "G3 altered dominant");
+    }
+
+    #[test]
+    fn test_altered_dominant_pitch_classes() {
+        let chord: PitchClassSet = AlteredDominantPattern::apply(C4).pitches().iter().collect();
+        let altered: PitchClassSet = [C, DFLAT, EFLAT, E, GFLAT, AFLAT, BFLAT]
+            .into_iter()
+            .collect();
+        assert_eq!(chord, altered);
+    }
+}
@@ -0,0 +1,94 @@
+//! Dominant seventh flat ninth chord implementation.
+//!
+//! The seventh flat ninth chord adds a minor ninth to the dominant seventh
+//! chord. It is the dominant chord of minor keys, built on the fifth degree
+//! of the harmonic minor scale.
+//!
+//! # Chord Structure
+//!
+//! The seventh flat ninth chord built on C is made of the following notes:
+//! ```text
+//! C E G Bb Db
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The seventh flat ninth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Minor 7th (10) +
+//! Minor 9th (13)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for seventh flat ninth chords.
+///
+/// This type is used to distinguish seventh flat ninth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhFlatNinthType;
+
+impl ChordType for DominantSeventhFlatNinthType {
+    fn name() -> &'static str {
+        "dominant seventh flat ninth"
+    }
+
+    fn symbol() -> &'static str {
+        "7b9"
+    }
+}
+
+/// The pattern for a seventh flat ninth chord.
+///
+/// This pattern defines the intervals that make up a seventh flat ninth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Minor 7th +
+/// Minor 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhFlatNinthPattern;
+
+impl ChordPattern for DominantSeventhFlatNinthPattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MINOR_NINTH,
+    ];
+
+    type ChordTyp = DominantSeventhFlatNinthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominant_seventh_flat_ninth_chord() {
+        let chord = DominantSeventhFlatNinthPattern::apply(E4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], E4);
+        assert_eq!(pitches[1], AFLAT4);
+        assert_eq!(pitches[2], B4);
+        assert_eq!(pitches[3], D5);
+        assert_eq!(pitches[4], F5);
+
+        assert_eq!(chord.root(), E4);
+        assert_eq!(chord.name(), "dominant seventh flat ninth");
+        assert_eq!(chord.symbol(), "7b9");
+        assert_eq!(chord.to_string(), "E4 dominant seventh flat ninth");
+    }
+
+    #[test]
+    fn test_dominant_seventh_flat_ninth_spelling() {
+        let names: Vec<_> = DominantSeventhFlatNinthPattern::spell(C4.spelled())
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect();
+        assert_eq!(names, ["C4", "E4", "G4", "Bb4", "Db5"]);
+    }
+}
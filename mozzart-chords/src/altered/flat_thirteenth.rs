@@ -0,0 +1,93 @@
+//! Dominant seventh flat thirteenth chord implementation.
+//!
+//! The seventh flat thirteenth chord adds a minor thirteenth to the dominant
+//! seventh chord. It is the chord of the mixolydian b6 mode.
+//!
+//! # Chord Structure
+//!
+//! The seventh flat thirteenth chord built on C is made of the following notes:
+//! ```text
+//! C E G Bb Ab
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The seventh flat thirteenth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Minor 7th (10) +
+//! Minor 13th (20)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for seventh flat thirteenth chords.
+///
+/// This type is used to distinguish seventh flat thirteenth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhFlatThirteenthType;
+
+impl ChordType for DominantSeventhFlatThirteenthType {
+    fn name() -> &'static str {
+        "dominant seventh flat thirteenth"
+    }
+
+    fn symbol() -> &'static str {
+        "7b13"
+    }
+}
+
+/// The pattern for a seventh flat thirteenth chord.
+///
+/// This pattern defines the intervals that make up a seventh flat thirteenth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Minor 7th +
+/// Minor 13th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhFlatThirteenthPattern;
+
+impl ChordPattern for DominantSeventhFlatThirteenthPattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MINOR_THIRTEENTH,
+    ];
+
+    type ChordTyp = DominantSeventhFlatThirteenthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominant_seventh_flat_thirteenth_chord() {
+        let chord = DominantSeventhFlatThirteenthPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], BFLAT4);
+        assert_eq!(pitches[4], AFLAT5);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "dominant seventh flat thirteenth");
+        assert_eq!(chord.symbol(), "7b13");
+        assert_eq!(chord.to_string(), "C4 dominant seventh flat thirteenth");
+    }
+
+    #[test]
+    fn test_dominant_seventh_flat_thirteenth_spelling() {
+        let names: Vec<_> = DominantSeventhFlatThirteenthPattern::spell(C4.spelled())
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect();
+        assert_eq!(names, ["C4", "E4", "G4", "Bb4", "Ab5"]);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod altered_dominant;
+pub mod flat_ninth;
+pub mod flat_thirteenth;
+pub mod sharp_eleventh;
+pub mod sharp_ninth;
+
+pub use altered_dominant::*;
+pub use flat_ninth::*;
+pub use flat_thirteenth::*;
+pub use sharp_eleventh::*;
+pub use sharp_ninth::*;
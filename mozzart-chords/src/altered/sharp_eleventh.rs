@@ -0,0 +1,93 @@
+//! Dominant seventh sharp eleventh chord implementation.
+//!
+//! The seventh sharp eleventh chord adds an augmented eleventh to the dominant
+//! seventh chord. It is the chord of the lydian dominant mode.
+//!
+//! # Chord Structure
+//!
+//! The seventh sharp eleventh chord built on C is made of the following notes:
+//! ```text
+//! C E G Bb F#
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The seventh sharp eleventh chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Minor 7th (10) +
+//! Augmented 11th (18)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for seventh sharp eleventh chords.
+///
+/// This type is used to distinguish seventh sharp eleventh chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhSharpEleventhType;
+
+impl ChordType for DominantSeventhSharpEleventhType {
+    fn name() -> &'static str {
+        "dominant seventh sharp eleventh"
+    }
+
+    fn symbol() -> &'static str {
+        "7#11"
+    }
+}
+
+/// The pattern for a seventh sharp eleventh chord.
+///
+/// This pattern defines the intervals that make up a seventh sharp eleventh chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Minor 7th +
+/// Augmented 11th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhSharpEleventhPattern;
+
+impl ChordPattern for DominantSeventhSharpEleventhPattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        AUGMENTED_ELEVENTH,
+    ];
+
+    type ChordTyp = DominantSeventhSharpEleventhType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominant_seventh_sharp_eleventh_chord() {
+        let chord = DominantSeventhSharpEleventhPattern::apply(D4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], FSHARP4);
+        assert_eq!(pitches[2], A4);
+        assert_eq!(pitches[3], C5);
+        assert_eq!(pitches[4], GSHARP5);
+
+        assert_eq!(chord.root(), D4);
+        assert_eq!(chord.name(), "dominant seventh sharp eleventh");
+        assert_eq!(chord.symbol(), "7#11");
+        assert_eq!(chord.to_string(), "D4 dominant seventh sharp eleventh");
+    }
+
+    #[test]
+    fn test_dominant_seventh_sharp_eleventh_spelling() {
+        let names: Vec<_> = DominantSeventhSharpEleventhPattern::spell(C4.spelled())
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect();
+        assert_eq!(names, ["C4", "E4", "G4", "Bb4", "F#5"]);
+    }
+}
@@ -0,0 +1,94 @@
+//! Dominant seventh sharp ninth chord implementation.
+//!
+//! The seventh sharp ninth chord adds an augmented ninth to the dominant
+//! seventh chord, clashing with its major third. It is a staple of blues and
+//! rock, where it is known as the Hendrix chord.
+//!
+//! # Chord Structure
+//!
+//! The seventh sharp ninth chord built on C is made of the following notes:
+//! ```text
+//! C E G Bb D#
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The seventh sharp ninth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Minor 7th (10) +
+//! Augmented 9th (15)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for seventh sharp ninth chords.
+///
+/// This type is used to distinguish seventh sharp ninth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhSharpNinthType;
+
+impl ChordType for DominantSeventhSharpNinthType {
+    fn name() -> &'static str {
+        "dominant seventh sharp ninth"
+    }
+
+    fn symbol() -> &'static str {
+        "7#9"
+    }
+}
+
+/// The pattern for a seventh sharp ninth chord.
+///
+/// This pattern defines the intervals that make up a seventh sharp ninth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Minor 7th +
+/// Augmented 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhSharpNinthPattern;
+
+impl ChordPattern for DominantSeventhSharpNinthPattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        AUGMENTED_NINTH,
+    ];
+
+    type ChordTyp = DominantSeventhSharpNinthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominant_seventh_sharp_ninth_chord() {
+        let chord = DominantSeventhSharpNinthPattern::apply(E3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], E3);
+        assert_eq!(pitches[1], AFLAT3);
+        assert_eq!(pitches[2], B3);
+        assert_eq!(pitches[3], D4);
+        assert_eq!(pitches[4], G4);
+
+        assert_eq!(chord.root(), E3);
+        assert_eq!(chord.name(), "dominant seventh sharp ninth");
+        assert_eq!(chord.symbol(), "7#9");
+        assert_eq!(chord.to_string(), "E3 dominant seventh sharp ninth");
+    }
+
+    #[test]
+    fn test_dominant_seventh_sharp_ninth_spelling() {
+        let names: Vec<_> = DominantSeventhSharpNinthPattern::spell(C4.spelled())
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect();
+        assert_eq!(names, ["C4", "E4", "G4", "Bb4", "D#5"]);
+    }
+}
@@ -0,0 +1,98 @@
+//! Dominant eleventh chord implementation.
+//!
+//! The dominant eleventh chord adds a perfect eleventh to the dominant ninth
+//! chord. In practice the third is often left out, as it clashes with the
+//! eleventh; the full stack of thirds is given here.
+//!
+//! # Chord Structure
+//!
+//! The dominant eleventh chord built on C is made of the following notes:
+//! ```text
+//! C E G Bb D F
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The dominant eleventh chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Minor 7th (10) +
+//! Major 9th (14) + Perfect 11th (17)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for dominant eleventh chords.
+///
+/// This type is used to distinguish dominant eleventh chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantEleventhType;
+
+impl ChordType for DominantEleventhType {
+    fn name() -> &'static str {
+        "dominant eleventh"
+    }
+
+    fn symbol() -> &'static str {
+        "11"
+    }
+}
+
+/// The pattern for a dominant eleventh chord.
+///
+/// This pattern defines the intervals that make up a dominant eleventh chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Minor 7th +
+/// Major 9th + Perfect 11th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantEleventhPattern;
+
+impl ChordPattern for DominantEleventhPattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MAJOR_NINTH,
+        PERFECT_ELEVENTH,
+    ];
+
+    type ChordTyp = DominantEleventhType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MajorTriadPattern;
+
+    #[test]
+    fn test_dominant_eleventh_chord() {
+        let chord = DominantEleventhPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], BFLAT4);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], F5);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "dominant eleventh");
+        assert_eq!(chord.symbol(), "11");
+        assert_eq!(chord.to_string(), "C4 dominant eleventh");
+    }
+
+    #[test]
+    fn test_dominant_eleventh_upper_structure() {
+        // The seventh, ninth and eleventh form a major triad a tone below the root
+        let chord = DominantEleventhPattern::apply(C4);
+        assert_eq!(
+            &chord.pitches()[3..],
+            MajorTriadPattern::apply(BFLAT4).pitches()
+        );
+    }
+}
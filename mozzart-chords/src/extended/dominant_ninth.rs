@@ -0,0 +1,95 @@
+//! Dominant ninth chord implementation.
+//!
+//! The dominant ninth chord adds a major ninth to the dominant seventh chord.
+//! It is the most common extension of the dominant chord in jazz and funk.
+//!
+//! # Chord Structure
+//!
+//! The dominant ninth chord built on C is made of the following notes:
+//! ```text
+//! C E G Bb D
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The dominant ninth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Minor 7th (10) +
+//! Major 9th (14)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for dominant ninth chords.
+///
+/// This type is used to distinguish dominant ninth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantNinthType;
+
+impl ChordType for DominantNinthType {
+    fn name() -> &'static str {
+        "dominant ninth"
+    }
+
+    fn symbol() -> &'static str {
+        "9"
+    }
+}
+
+/// The pattern for a dominant ninth chord.
+///
+/// This pattern defines the intervals that make up a dominant ninth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Minor 7th +
+/// Major 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantNinthPattern;
+
+impl ChordPattern for DominantNinthPattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MAJOR_NINTH,
+    ];
+
+    type ChordTyp = DominantNinthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MinorTriadPattern;
+
+    #[test]
+    fn test_dominant_ninth_chord() {
+        let chord = DominantNinthPattern::apply(G3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], G3);
+        assert_eq!(pitches[1], B3);
+        assert_eq!(pitches[2], D4);
+        assert_eq!(pitches[3], F4);
+        assert_eq!(pitches[4], A4);
+
+        assert_eq!(chord.root(), G3);
+        assert_eq!(chord.name(), "dominant ninth");
+        assert_eq!(chord.symbol(), "9");
+        assert_eq!(chord.to_string(), "G3 dominant ninth");
+    }
+
+    #[test]
+    fn test_dominant_ninth_upper_structure() {
+        // The fifth, seventh and ninth form a minor triad
+        let chord = DominantNinthPattern::apply(G3);
+        assert_eq!(
+            &chord.pitches()[2..],
+            MinorTriadPattern::apply(D4).pitches()
+        );
+    }
+}
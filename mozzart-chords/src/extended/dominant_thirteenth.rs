@@ -0,0 +1,93 @@
+//! Dominant thirteenth chord implementation.
+//!
+//! The dominant thirteenth chord adds a major thirteenth to the dominant ninth
+//! chord. The eleventh, which clashes with the major third, is left out.
+//!
+//! # Chord Structure
+//!
+//! The dominant thirteenth chord built on C is made of the following notes:
+//! ```text
+//! C E G Bb D A
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The dominant thirteenth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Minor 7th (10) +
+//! Major 9th (14) + Major 13th (21)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for dominant thirteenth chords.
+///
+/// This type is used to distinguish dominant thirteenth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantThirteenthType;
+
+impl ChordType for DominantThirteenthType {
+    fn name() -> &'static str {
+        "dominant thirteenth"
+    }
+
+    fn symbol() -> &'static str {
+        "13"
+    }
+}
+
+/// The pattern for a dominant thirteenth chord.
+///
+/// This pattern defines the intervals that make up a dominant thirteenth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Minor 7th +
+/// Major 9th + Major 13th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantThirteenthPattern;
+
+impl ChordPattern for DominantThirteenthPattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MAJOR_NINTH,
+        MAJOR_THIRTEENTH,
+    ];
+
+    type ChordTyp = DominantThirteenthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominant_thirteenth_chord() {
+        let chord = DominantThirteenthPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], BFLAT4);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], A5);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "dominant thirteenth");
+        assert_eq!(chord.symbol(), "13");
+        assert_eq!(chord.to_string(), "C4 dominant thirteenth");
+    }
+
+    #[test]
+    fn test_dominant_thirteenth_without_eleventh() {
+        let chord = DominantThirteenthPattern::apply(C4);
+        assert!(!chord.pitches().contains(&F5));
+        assert_eq!(chord.pitches().last(), Some(&A5));
+    }
+}
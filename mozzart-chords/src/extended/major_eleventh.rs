@@ -0,0 +1,98 @@
+//! Major eleventh chord implementation.
+//!
+//! The major eleventh chord adds a perfect eleventh to the major ninth chord.
+//! The eleventh clashes with the third a minor ninth below it, so the chord
+//! is rarely played with every note.
+//!
+//! # Chord Structure
+//!
+//! The major eleventh chord built on C is made of the following notes:
+//! ```text
+//! C E G B D F
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The major eleventh chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Major 7th (11) +
+//! Major 9th (14) + Perfect 11th (17)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for major eleventh chords.
+///
+/// This type is used to distinguish major eleventh chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorEleventhType;
+
+impl ChordType for MajorEleventhType {
+    fn name() -> &'static str {
+        "major eleventh"
+    }
+
+    fn symbol() -> &'static str {
+        "maj11"
+    }
+}
+
+/// The pattern for a major eleventh chord.
+///
+/// This pattern defines the intervals that make up a major eleventh chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Major 7th +
+/// Major 9th + Perfect 11th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorEleventhPattern;
+
+impl ChordPattern for MajorEleventhPattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MAJOR_SEVENTH,
+        MAJOR_NINTH,
+        PERFECT_ELEVENTH,
+    ];
+
+    type ChordTyp = MajorEleventhType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiminishedTriadPattern;
+
+    #[test]
+    fn test_major_eleventh_chord() {
+        let chord = MajorEleventhPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], B4);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], F5);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "major eleventh");
+        assert_eq!(chord.symbol(), "maj11");
+        assert_eq!(chord.to_string(), "C4 major eleventh");
+    }
+
+    #[test]
+    fn test_major_eleventh_upper_structure() {
+        // The seventh, ninth and eleventh form a diminished triad
+        let chord = MajorEleventhPattern::apply(C4);
+        assert_eq!(
+            &chord.pitches()[3..],
+            DiminishedTriadPattern::apply(B4).pitches()
+        );
+    }
+}
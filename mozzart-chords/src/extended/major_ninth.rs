@@ -0,0 +1,95 @@
+//! Major ninth chord implementation.
+//!
+//! The major ninth chord adds a major ninth to the major seventh chord. It is
+//! a common tonic chord in jazz and bossa nova.
+//!
+//! # Chord Structure
+//!
+//! The major ninth chord built on C is made of the following notes:
+//! ```text
+//! C E G B D
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The major ninth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Major 7th (11) +
+//! Major 9th (14)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for major ninth chords.
+///
+/// This type is used to distinguish major ninth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorNinthType;
+
+impl ChordType for MajorNinthType {
+    fn name() -> &'static str {
+        "major ninth"
+    }
+
+    fn symbol() -> &'static str {
+        "maj9"
+    }
+}
+
+/// The pattern for a major ninth chord.
+///
+/// This pattern defines the intervals that make up a major ninth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Major 7th +
+/// Major 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorNinthPattern;
+
+impl ChordPattern for MajorNinthPattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MAJOR_SEVENTH,
+        MAJOR_NINTH,
+    ];
+
+    type ChordTyp = MajorNinthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MajorTriadPattern;
+
+    #[test]
+    fn test_major_ninth_chord() {
+        let chord = MajorNinthPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], B4);
+        assert_eq!(pitches[4], D5);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "major ninth");
+        assert_eq!(chord.symbol(), "maj9");
+        assert_eq!(chord.to_string(), "C4 major ninth");
+    }
+
+    #[test]
+    fn test_major_ninth_upper_structure() {
+        // The fifth, seventh and ninth form a major triad
+        let chord = MajorNinthPattern::apply(C4);
+        assert_eq!(
+            &chord.pitches()[2..],
+            MajorTriadPattern::apply(G4).pitches()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Major thirteenth chord implementation.
+//!
+//! The major thirteenth chord adds a major thirteenth to the major ninth chord.
+//! The eleventh, which clashes with the major third, is left out.
+//!
+//! # Chord Structure
+//!
+//! The major thirteenth chord built on C is made of the following notes:
+//! ```text
+//! C E G B D A
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The major thirteenth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7) + Major 7th (11) +
+//! Major 9th (14) + Major 13th (21)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for major thirteenth chords.
+///
+/// This type is used to distinguish major thirteenth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorThirteenthType;
+
+impl ChordType for MajorThirteenthType {
+    fn name() -> &'static str {
+        "major thirteenth"
+    }
+
+    fn symbol() -> &'static str {
+        "maj13"
+    }
+}
+
+/// The pattern for a major thirteenth chord.
+///
+/// This pattern defines the intervals that make up a major thirteenth chord:
+/// ```text
+/// Root + Major 3rd + Perfect 5th + Major 7th +
+/// Major 9th + Major 13th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorThirteenthPattern;
+
+impl ChordPattern for MajorThirteenthPattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MAJOR_SEVENTH,
+        MAJOR_NINTH,
+        MAJOR_THIRTEENTH,
+    ];
+
+    type ChordTyp = MajorThirteenthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MajorTriadPattern;
+
+    #[test]
+    fn test_major_thirteenth_chord() {
+        let chord = MajorThirteenthPattern::apply(F3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], F3);
+        assert_eq!(pitches[1], A3);
+        assert_eq!(pitches[2], C4);
+        assert_eq!(pitches[3], E4);
+        assert_eq!(pitches[4], G4);
+        assert_eq!(pitches[5], D5);
+
+        assert_eq!(chord.root(), F3);
+        assert_eq!(chord.name(), "major thirteenth");
+        assert_eq!(chord.symbol(), "maj13");
+        assert_eq!(chord.to_string(), "F3 major thirteenth");
+    }
+
+    #[test]
+    fn test_major_thirteenth_lower_structure() {
+        // The root, third and fifth form a major triad
+        let chord = MajorThirteenthPattern::apply(F3);
+        assert_eq!(
+            &chord.pitches()[..3],
+            MajorTriadPattern::apply(F3).pitches()
+        );
+    }
+}
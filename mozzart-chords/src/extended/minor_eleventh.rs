@@ -0,0 +1,98 @@
+//! Minor eleventh chord implementation.
+//!
+//! The minor eleventh chord adds a perfect eleventh to the minor ninth chord.
+//! Unlike in major chords, the eleventh sits comfortably above the minor
+//! third, giving the chord its open, modal sound.
+//!
+//! # Chord Structure
+//!
+//! The minor eleventh chord built on C is made of the following notes:
+//! ```text
+//! C Eb G Bb D F
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The minor eleventh chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 3rd (3) + Perfect 5th (7) + Minor 7th (10) +
+//! Major 9th (14) + Perfect 11th (17)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for minor eleventh chords.
+///
+/// This type is used to distinguish minor eleventh chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorEleventhType;
+
+impl ChordType for MinorEleventhType {
+    fn name() -> &'static str {
+        "minor eleventh"
+    }
+
+    fn symbol() -> &'static str {
+        "m11"
+    }
+}
+
+/// The pattern for a minor eleventh chord.
+///
+/// This pattern defines the intervals that make up a minor eleventh chord:
+/// ```text
+/// Root + Minor 3rd + Perfect 5th + Minor 7th +
+/// Major 9th + Perfect 11th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorEleventhPattern;
+
+impl ChordPattern for MinorEleventhPattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MAJOR_NINTH,
+        PERFECT_ELEVENTH,
+    ];
+
+    type ChordTyp = MinorEleventhType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MajorTriadPattern;
+
+    #[test]
+    fn test_minor_eleventh_chord() {
+        let chord = MinorEleventhPattern::apply(A3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], A3);
+        assert_eq!(pitches[1], C4);
+        assert_eq!(pitches[2], E4);
+        assert_eq!(pitches[3], G4);
+        assert_eq!(pitches[4], B4);
+        assert_eq!(pitches[5], D5);
+
+        assert_eq!(chord.root(), A3);
+        assert_eq!(chord.name(), "minor eleventh");
+        assert_eq!(chord.symbol(), "m11");
+        assert_eq!(chord.to_string(), "A3 minor eleventh");
+    }
+
+    #[test]
+    fn test_minor_eleventh_upper_structure() {
+        // The seventh, ninth and eleventh form a major triad a tone below the root
+        let chord = MinorEleventhPattern::apply(A3);
+        assert_eq!(
+            &chord.pitches()[3..],
+            MajorTriadPattern::apply(G4).pitches()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Minor ninth chord implementation.
+//!
+//! The minor ninth chord adds a major ninth to the minor seventh chord. It is
+//! the usual voicing of the ii chord in jazz.
+//!
+//! # Chord Structure
+//!
+//! The minor ninth chord built on C is made of the following notes:
+//! ```text
+//! C Eb G Bb D
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The minor ninth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 3rd (3) + Perfect 5th (7) + Minor 7th (10) +
+//! Major 9th (14)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for minor ninth chords.
+///
+/// This type is used to distinguish minor ninth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorNinthType;
+
+impl ChordType for MinorNinthType {
+    fn name() -> &'static str {
+        "minor ninth"
+    }
+
+    fn symbol() -> &'static str {
+        "m9"
+    }
+}
+
+/// The pattern for a minor ninth chord.
+///
+/// This pattern defines the intervals that make up a minor ninth chord:
+/// ```text
+/// Root + Minor 3rd + Perfect 5th + Minor 7th +
+/// Major 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorNinthPattern;
+
+impl ChordPattern for MinorNinthPattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MAJOR_NINTH,
+    ];
+
+    type ChordTyp = MinorNinthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MajorTriadPattern;
+
+    #[test]
+    fn test_minor_ninth_chord() {
+        let chord = MinorNinthPattern::apply(D4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], F4);
+        assert_eq!(pitches[2], A4);
+        assert_eq!(pitches[3], C5);
+        assert_eq!(pitches[4], E5);
+
+        assert_eq!(chord.root(), D4);
+        assert_eq!(chord.name(), "minor ninth");
+        assert_eq!(chord.symbol(), "m9");
+        assert_eq!(chord.to_string(), "D4 minor ninth");
+    }
+
+    #[test]
+    fn test_minor_ninth_upper_structure() {
+        // The third, fifth and seventh form the triad of the relative major
+        let chord = MinorNinthPattern::apply(D4);
+        assert_eq!(
+            &chord.pitches()[1..4],
+            MajorTriadPattern::apply(F4).pitches()
+        );
+    }
+}
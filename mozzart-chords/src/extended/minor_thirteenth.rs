@@ -0,0 +1,99 @@
+//! Minor thirteenth chord implementation.
+//!
+//! The minor thirteenth chord adds a major thirteenth to the minor eleventh
+//! chord, stacking every note of the dorian mode in thirds.
+//!
+//! # Chord Structure
+//!
+//! The minor thirteenth chord built on C is made of the following notes:
+//! ```text
+//! C Eb G Bb D F A
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The minor thirteenth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 3rd (3) + Perfect 5th (7) + Minor 7th (10) +
+//! Major 9th (14) + Perfect 11th (17) + Major 13th (21)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for minor thirteenth chords.
+///
+/// This type is used to distinguish minor thirteenth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorThirteenthType;
+
+impl ChordType for MinorThirteenthType {
+    fn name() -> &'static str {
+        "minor thirteenth"
+    }
+
+    fn symbol() -> &'static str {
+        "m13"
+    }
+}
+
+/// The pattern for a minor thirteenth chord.
+///
+/// This pattern defines the intervals that make up a minor thirteenth chord:
+/// ```text
+/// Root + Minor 3rd + Perfect 5th + Minor 7th +
+/// Major 9th + Perfect 11th + Major 13th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorThirteenthPattern;
+
+impl ChordPattern for MinorThirteenthPattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+        MAJOR_NINTH,
+        PERFECT_ELEVENTH,
+        MAJOR_THIRTEENTH,
+    ];
+
+    type ChordTyp = MinorThirteenthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MinorTriadPattern;
+
+    #[test]
+    fn test_minor_thirteenth_chord() {
+        let chord = MinorThirteenthPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], EFLAT4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], BFLAT4);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], F5);
+        assert_eq!(pitches[6], A5);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "minor thirteenth");
+        assert_eq!(chord.symbol(), "m13");
+        assert_eq!(chord.to_string(), "C4 minor thirteenth");
+    }
+
+    #[test]
+    fn test_minor_thirteenth_upper_structure() {
+        // The ninth, eleventh and thirteenth form a minor triad
+        let chord = MinorThirteenthPattern::apply(C4);
+        assert_eq!(
+            &chord.pitches()[4..],
+            MinorTriadPattern::apply(D5).pitches()
+        );
+    }
+}
@@ -0,0 +1,19 @@
+pub mod dominant_eleventh;
+pub mod dominant_ninth;
+pub mod dominant_thirteenth;
+pub mod major_eleventh;
+pub mod major_ninth;
+pub mod major_thirteenth;
+pub mod minor_eleventh;
+pub mod minor_ninth;
+pub mod minor_thirteenth;
+
+pub use dominant_eleventh::*;
+pub use dominant_ninth::*;
+pub use dominant_thirteenth::*;
+pub use major_eleventh::*;
+pub use major_ninth::*;
+pub use major_thirteenth::*;
+pub use minor_eleventh::*;
+pub use minor_ninth::*;
+pub use minor_thirteenth::*;
@@ -1 +1,59 @@
+pub mod added;
+pub mod altered;
+pub mod extended;
+pub mod stacked;
+pub mod suspended;
+pub mod triads;
 
+pub use added::*;
+pub use altered::*;
+pub use extended::*;
+pub use stacked::*;
+pub use suspended::*;
+pub use triads::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::{ChordPattern, Locale};
+
+    #[test]
+    fn test_names_are_translated() {
+        let names = [
+            AddedNinthPattern::name(),
+            AlteredDominantPattern::name(),
+            AugmentedTriadPattern::name(),
+            DiminishedTriadPattern::name(),
+            DominantEleventhPattern::name(),
+            DominantNinthPattern::name(),
+            DominantSeventhFlatNinthPattern::name(),
+            DominantSeventhFlatThirteenthPattern::name(),
+            DominantSeventhSharpEleventhPattern::name(),
+            DominantSeventhSharpNinthPattern::name(),
+            DominantSeventhSuspendedFourthPattern::name(),
+            DominantThirteenthPattern::name(),
+            MajorEleventhPattern::name(),
+            MajorNinthPattern::name(),
+            MajorSixthPattern::name(),
+            MajorThirteenthPattern::name(),
+            MajorTriadPattern::name(),
+            MinorEleventhPattern::name(),
+            MinorNinthPattern::name(),
+            MinorSixthPattern::name(),
+            MinorThirteenthPattern::name(),
+            MinorTriadPattern::name(),
+            PowerPattern::name(),
+            QuartalTetradPattern::name(),
+            QuartalTriadPattern::name(),
+            QuintalTetradPattern::name(),
+            QuintalTriadPattern::name(),
+            SixNinePattern::name(),
+            SuspendedFourthPattern::name(),
+            SuspendedSecondPattern::name(),
+        ];
+        // German names are capitalized or compounded, so none matches its English name
+        for name in names {
+            assert_ne!(Locale::German.translate(name), name, "{name}");
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod power;
+pub mod quartal_tetrad;
+pub mod quartal_triad;
+pub mod quintal_tetrad;
+pub mod quintal_triad;
+
+pub use power::*;
+pub use quartal_tetrad::*;
+pub use quartal_triad::*;
+pub use quintal_tetrad::*;
+pub use quintal_triad::*;
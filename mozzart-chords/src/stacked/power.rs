@@ -0,0 +1,84 @@
+//! Power chord implementation.
+//!
+//! The power chord doubles the root an octave above its fifth. Without a third
+//! it is neither major nor minor, and stays clear under heavy distortion,
+//! which makes it the backbone of rock and metal guitar.
+//!
+//! # Chord Structure
+//!
+//! The power chord built on C is made of the following notes:
+//! ```text
+//! C G C
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The power chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Perfect 5th (7) + Octave (12)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for power chords.
+///
+/// This type is used to distinguish power chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerType;
+
+impl ChordType for PowerType {
+    fn name() -> &'static str {
+        "power"
+    }
+
+    fn symbol() -> &'static str {
+        "5"
+    }
+}
+
+/// The pattern for a power chord.
+///
+/// This pattern defines the intervals that make up a power chord:
+/// ```text
+/// Root + Perfect 5th + Octave
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerPattern;
+
+impl ChordPattern for PowerPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FIFTH, PERFECT_OCTAVE];
+
+    type ChordTyp = PowerType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_power_chord() {
+        let chord = PowerPattern::apply(E3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], E3);
+        assert_eq!(pitches[1], B3);
+        assert_eq!(pitches[2], E4);
+
+        assert_eq!(chord.root(), E3);
+        assert_eq!(chord.name(), "power");
+        assert_eq!(chord.symbol(), "5");
+        assert_eq!(chord.to_string(), "E3 power");
+    }
+
+    #[test]
+    fn test_power_pitch_classes() {
+        let chord = PowerPattern::apply(E3);
+        let pitch_classes: PitchClassSet = chord.pitches().iter().collect();
+        assert_eq!(pitch_classes.len(), 2);
+        assert_eq!(chord.pitches()[2], E3.octave_up(1));
+    }
+}
@@ -0,0 +1,82 @@
+//! Quartal tetrad implementation.
+//!
+//! The quartal tetrad stacks three perfect fourths. It is the four-note quartal
+//! voicing used in modal jazz piano.
+//!
+//! # Chord Structure
+//!
+//! The quartal tetrad built on C is made of the following notes:
+//! ```text
+//! C F Bb Eb
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The quartal tetrad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Perfect 4th (5) + Minor 7th (10) + Minor 10th (15)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for quartal tetrads.
+///
+/// This type is used to distinguish quartal tetrads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuartalTetradType;
+
+impl ChordType for QuartalTetradType {
+    fn name() -> &'static str {
+        "quartal tetrad"
+    }
+}
+
+/// The pattern for a quartal tetrad.
+///
+/// This pattern defines the intervals that make up a quartal tetrad:
+/// ```text
+/// Root + Perfect 4th + Minor 7th + Minor 10th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuartalTetradPattern;
+
+impl ChordPattern for QuartalTetradPattern {
+    type Pattern = [Interval; 4];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FOURTH, MINOR_SEVENTH, MINOR_TENTH];
+
+    type ChordTyp = QuartalTetradType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QuartalTriadPattern;
+
+    #[test]
+    fn test_quartal_tetrad_chord() {
+        let chord = QuartalTetradPattern::apply(A3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 4);
+        assert_eq!(pitches[0], A3);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], C5);
+
+        assert_eq!(chord.root(), A3);
+        assert_eq!(chord.name(), "quartal tetrad");
+        assert_eq!(chord.symbol(), "quartal tetrad");
+        assert_eq!(chord.to_string(), "A3 quartal tetrad");
+    }
+
+    #[test]
+    fn test_quartal_tetrad_lower_structure() {
+        // The lowest three notes form a quartal triad
+        let chord = QuartalTetradPattern::apply(A3);
+        assert_eq!(
+            &chord.pitches()[..3],
+            QuartalTriadPattern::apply(A3).pitches()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Quartal triad implementation.
+//!
+//! The quartal triad stacks two perfect fourths instead of thirds. Quartal
+//! voicings are common in modal jazz, where they avoid the strong pull of
+//! tertian harmony.
+//!
+//! # Chord Structure
+//!
+//! The quartal triad built on C is made of the following notes:
+//! ```text
+//! C F Bb
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The quartal triad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Perfect 4th (5) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for quartal triads.
+///
+/// This type is used to distinguish quartal triads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuartalTriadType;
+
+impl ChordType for QuartalTriadType {
+    fn name() -> &'static str {
+        "quartal triad"
+    }
+}
+
+/// The pattern for a quartal triad.
+///
+/// This pattern defines the intervals that make up a quartal triad:
+/// ```text
+/// Root + Perfect 4th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuartalTriadPattern;
+
+impl ChordPattern for QuartalTriadPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FOURTH, MINOR_SEVENTH];
+
+    type ChordTyp = QuartalTriadType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quartal_triad_chord() {
+        let chord = QuartalTriadPattern::apply(D4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], G4);
+        assert_eq!(pitches[2], C5);
+
+        assert_eq!(chord.root(), D4);
+        assert_eq!(chord.name(), "quartal triad");
+        assert_eq!(chord.symbol(), "quartal triad");
+        assert_eq!(chord.to_string(), "D4 quartal triad");
+    }
+
+    #[test]
+    fn test_quartal_triad_interval_class_vector() {
+        // Two perfect fourths and the minor seventh they span
+        assert_eq!(
+            QuartalTriadPattern::interval_class_vector(),
+            [0, 1, 0, 0, 2, 0]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Quintal tetrad implementation.
+//!
+//! The quintal tetrad stacks three perfect fifths, spanning a major thirteenth.
+//! Its pitches are those of a quartal tetrad, played in a wider voicing.
+//!
+//! # Chord Structure
+//!
+//! The quintal tetrad built on C is made of the following notes:
+//! ```text
+//! C G D A
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The quintal tetrad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Perfect 5th (7) + Major 9th (14) + Major 13th (21)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for quintal tetrads.
+///
+/// This type is used to distinguish quintal tetrads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuintalTetradType;
+
+impl ChordType for QuintalTetradType {
+    fn name() -> &'static str {
+        "quintal tetrad"
+    }
+}
+
+/// The pattern for a quintal tetrad.
+///
+/// This pattern defines the intervals that make up a quintal tetrad:
+/// ```text
+/// Root + Perfect 5th + Major 9th + Major 13th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuintalTetradPattern;
+
+impl ChordPattern for QuintalTetradPattern {
+    type Pattern = [Interval; 4];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FIFTH, MAJOR_NINTH, MAJOR_THIRTEENTH];
+
+    type ChordTyp = QuintalTetradType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QuartalTetradPattern;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_quintal_tetrad_chord() {
+        let chord = QuintalTetradPattern::apply(C3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 4);
+        assert_eq!(pitches[0], C3);
+        assert_eq!(pitches[1], G3);
+        assert_eq!(pitches[2], D4);
+        assert_eq!(pitches[3], A4);
+
+        assert_eq!(chord.root(), C3);
+        assert_eq!(chord.name(), "quintal tetrad");
+        assert_eq!(chord.symbol(), "quintal tetrad");
+        assert_eq!(chord.to_string(), "C3 quintal tetrad");
+    }
+
+    #[test]
+    fn test_quintal_inverts_quartal_tetrad() {
+        // C G D A holds the pitches of the quartal tetrad A D G C
+        let quintal: PitchClassSet = QuintalTetradPattern::apply(C4).pitches().iter().collect();
+        let other: PitchClassSet = QuartalTetradPattern::apply(A4).pitches().iter().collect();
+        assert_eq!(quintal, other);
+    }
+}
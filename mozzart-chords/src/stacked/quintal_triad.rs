@@ -0,0 +1,80 @@
+//! Quintal triad implementation.
+//!
+//! The quintal triad stacks two perfect fifths. Its pitches are those of a
+//! quartal triad, played in a wider voicing.
+//!
+//! # Chord Structure
+//!
+//! The quintal triad built on C is made of the following notes:
+//! ```text
+//! C G D
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The quintal triad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Perfect 5th (7) + Major 9th (14)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for quintal triads.
+///
+/// This type is used to distinguish quintal triads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuintalTriadType;
+
+impl ChordType for QuintalTriadType {
+    fn name() -> &'static str {
+        "quintal triad"
+    }
+}
+
+/// The pattern for a quintal triad.
+///
+/// This pattern defines the intervals that make up a quintal triad:
+/// ```text
+/// Root + Perfect 5th + Major 9th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuintalTriadPattern;
+
+impl ChordPattern for QuintalTriadPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FIFTH, MAJOR_NINTH];
+
+    type ChordTyp = QuintalTriadType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QuartalTriadPattern;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_quintal_triad_chord() {
+        let chord = QuintalTriadPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], G4);
+        assert_eq!(pitches[2], D5);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "quintal triad");
+        assert_eq!(chord.symbol(), "quintal triad");
+        assert_eq!(chord.to_string(), "C4 quintal triad");
+    }
+
+    #[test]
+    fn test_quintal_inverts_quartal_triad() {
+        // C G D holds the pitches of the quartal triad D G C
+        let quintal: PitchClassSet = QuintalTriadPattern::apply(C4).pitches().iter().collect();
+        let other: PitchClassSet = QuartalTriadPattern::apply(D4).pitches().iter().collect();
+        assert_eq!(quintal, other);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod seventh_sus4;
+pub mod sus2;
+pub mod sus4;
+
+pub use seventh_sus4::*;
+pub use sus2::*;
+pub use sus4::*;
@@ -0,0 +1,86 @@
+//! Dominant seventh suspended fourth chord implementation.
+//!
+//! The seventh suspended fourth chord adds a minor seventh to the suspended
+//! fourth chord. It often stands in for the dominant chord in modal jazz.
+//!
+//! # Chord Structure
+//!
+//! The seventh suspended fourth chord built on C is made of the following notes:
+//! ```text
+//! C F G Bb
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The seventh suspended fourth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Perfect 4th (5) + Perfect 5th (7) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for seventh suspended fourth chords.
+///
+/// This type is used to distinguish seventh suspended fourth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhSuspendedFourthType;
+
+impl ChordType for DominantSeventhSuspendedFourthType {
+    fn name() -> &'static str {
+        "dominant seventh suspended fourth"
+    }
+
+    fn symbol() -> &'static str {
+        "7sus4"
+    }
+}
+
+/// The pattern for a seventh suspended fourth chord.
+///
+/// This pattern defines the intervals that make up a seventh suspended fourth chord:
+/// ```text
+/// Root + Perfect 4th + Perfect 5th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DominantSeventhSuspendedFourthPattern;
+
+impl ChordPattern for DominantSeventhSuspendedFourthPattern {
+    type Pattern = [Interval; 4];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SEVENTH];
+
+    type ChordTyp = DominantSeventhSuspendedFourthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SuspendedFourthPattern;
+
+    #[test]
+    fn test_dominant_seventh_suspended_fourth_chord() {
+        let chord = DominantSeventhSuspendedFourthPattern::apply(G3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 4);
+        assert_eq!(pitches[0], G3);
+        assert_eq!(pitches[1], C4);
+        assert_eq!(pitches[2], D4);
+        assert_eq!(pitches[3], F4);
+
+        assert_eq!(chord.root(), G3);
+        assert_eq!(chord.name(), "dominant seventh suspended fourth");
+        assert_eq!(chord.symbol(), "7sus4");
+        assert_eq!(chord.to_string(), "G3 dominant seventh suspended fourth");
+    }
+
+    #[test]
+    fn test_dominant_seventh_suspended_fourth_lower_structure() {
+        // The root, fourth and fifth form a suspended fourth chord
+        let chord = DominantSeventhSuspendedFourthPattern::apply(G3);
+        assert_eq!(
+            &chord.pitches()[..3],
+            SuspendedFourthPattern::apply(G3).pitches()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! Suspended second chord implementation.
+//!
+//! The suspended second chord replaces the third of a triad by a major second.
+//! Without a third it is neither major nor minor, and sounds open and
+//! unresolved.
+//!
+//! # Chord Structure
+//!
+//! The suspended second chord built on C is made of the following notes:
+//! ```text
+//! C D G
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The suspended second chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Perfect 5th (7)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for suspended second chords.
+///
+/// This type is used to distinguish suspended second chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspendedSecondType;
+
+impl ChordType for SuspendedSecondType {
+    fn name() -> &'static str {
+        "suspended second"
+    }
+
+    fn symbol() -> &'static str {
+        "sus2"
+    }
+}
+
+/// The pattern for a suspended second chord.
+///
+/// This pattern defines the intervals that make up a suspended second chord:
+/// ```text
+/// Root + Major 2nd + Perfect 5th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspendedSecondPattern;
+
+impl ChordPattern for SuspendedSecondPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_SECOND, PERFECT_FIFTH];
+
+    type ChordTyp = SuspendedSecondType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_suspended_second_chord() {
+        let chord = SuspendedSecondPattern::apply(D4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], A4);
+
+        assert_eq!(chord.root(), D4);
+        assert_eq!(chord.name(), "suspended second");
+        assert_eq!(chord.symbol(), "sus2");
+        assert_eq!(chord.to_string(), "D4 suspended second");
+    }
+
+    #[test]
+    fn test_suspended_second_inverts_suspended_fourth() {
+        // Csus2 holds the pitches of Gsus4: G, C and D
+        let chord: PitchClassSet = SuspendedSecondPattern::apply(C4).pitches().iter().collect();
+        let expected: PitchClassSet = [C, D, G].into_iter().collect();
+        assert_eq!(chord, expected);
+    }
+}
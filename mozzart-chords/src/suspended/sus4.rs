@@ -0,0 +1,83 @@
+//! Suspended fourth chord implementation.
+//!
+//! The suspended fourth chord replaces the third of a triad by a perfect fourth,
+//! which traditionally resolves down to the third.
+//!
+//! # Chord Structure
+//!
+//! The suspended fourth chord built on C is made of the following notes:
+//! ```text
+//! C F G
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The suspended fourth chord is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Perfect 4th (5) + Perfect 5th (7)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for suspended fourth chords.
+///
+/// This type is used to distinguish suspended fourth chords from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspendedFourthType;
+
+impl ChordType for SuspendedFourthType {
+    fn name() -> &'static str {
+        "suspended fourth"
+    }
+
+    fn symbol() -> &'static str {
+        "sus4"
+    }
+}
+
+/// The pattern for a suspended fourth chord.
+///
+/// This pattern defines the intervals that make up a suspended fourth chord:
+/// ```text
+/// Root + Perfect 4th + Perfect 5th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspendedFourthPattern;
+
+impl ChordPattern for SuspendedFourthPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FOURTH, PERFECT_FIFTH];
+
+    type ChordTyp = SuspendedFourthType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SuspendedSecondPattern;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_suspended_fourth_chord() {
+        let chord = SuspendedFourthPattern::apply(G3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], G3);
+        assert_eq!(pitches[1], C4);
+        assert_eq!(pitches[2], D4);
+
+        assert_eq!(chord.root(), G3);
+        assert_eq!(chord.name(), "suspended fourth");
+        assert_eq!(chord.symbol(), "sus4");
+        assert_eq!(chord.to_string(), "G3 suspended fourth");
+    }
+
+    #[test]
+    fn test_suspended_fourth_inverts_suspended_second() {
+        let sus4: PitchClassSet = SuspendedFourthPattern::apply(G3).pitches().iter().collect();
+        let sus2: PitchClassSet = SuspendedSecondPattern::apply(C4).pitches().iter().collect();
+        assert_eq!(sus4, sus2);
+    }
+}
@@ -0,0 +1,85 @@
+//! Augmented triad implementation.
+//!
+//! The augmented triad stacks two major thirds, spanning an augmented fifth.
+//! It divides the octave into three equal parts, so its inversions are
+//! augmented triads themselves.
+//!
+//! # Chord Structure
+//!
+//! The augmented triad built on C is made of the following notes:
+//! ```text
+//! C E G#
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The augmented triad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Augmented 5th (8)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for augmented triads.
+///
+/// This type is used to distinguish augmented triads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AugmentedTriadType;
+
+impl ChordType for AugmentedTriadType {
+    fn name() -> &'static str {
+        "augmented"
+    }
+
+    fn symbol() -> &'static str {
+        "aug"
+    }
+}
+
+/// The pattern for an augmented triad.
+///
+/// This pattern defines the intervals that make up an augmented triad:
+/// ```text
+/// Root + Major 3rd + Augmented 5th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AugmentedTriadPattern;
+
+impl ChordPattern for AugmentedTriadPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, AUGMENTED_FIFTH];
+
+    type ChordTyp = AugmentedTriadType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_augmented_triad_chord() {
+        let chord = AugmentedTriadPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], GSHARP4);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "augmented");
+        assert_eq!(chord.symbol(), "aug");
+        assert_eq!(chord.to_string(), "C4 augmented");
+    }
+
+    #[test]
+    fn test_augmented_triad_symmetry() {
+        let c: PitchClassSet = AugmentedTriadPattern::apply(C4).pitches().iter().collect();
+        let e: PitchClassSet = AugmentedTriadPattern::apply(E4).pitches().iter().collect();
+        let f: PitchClassSet = AugmentedTriadPattern::apply(F4).pitches().iter().collect();
+        assert_eq!(c, e);
+        assert_ne!(c, f);
+    }
+}
@@ -0,0 +1,84 @@
+//! Diminished triad implementation.
+//!
+//! The diminished triad stacks two minor thirds, spanning a diminished fifth.
+//! It is the triad built on the seventh degree of the major scale, and sounds
+//! tense and unstable.
+//!
+//! # Chord Structure
+//!
+//! The diminished triad built on C is made of the following notes:
+//! ```text
+//! C Eb Gb
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The diminished triad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 3rd (3) + Diminished 5th (6)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for diminished triads.
+///
+/// This type is used to distinguish diminished triads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiminishedTriadType;
+
+impl ChordType for DiminishedTriadType {
+    fn name() -> &'static str {
+        "diminished"
+    }
+
+    fn symbol() -> &'static str {
+        "dim"
+    }
+}
+
+/// The pattern for a diminished triad.
+///
+/// This pattern defines the intervals that make up a diminished triad:
+/// ```text
+/// Root + Minor 3rd + Diminished 5th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiminishedTriadPattern;
+
+impl ChordPattern for DiminishedTriadPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MINOR_THIRD, DIMINISHED_FIFTH];
+
+    type ChordTyp = DiminishedTriadType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diminished_triad_chord() {
+        let chord = DiminishedTriadPattern::apply(B3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], B3);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], F4);
+
+        assert_eq!(chord.root(), B3);
+        assert_eq!(chord.name(), "diminished");
+        assert_eq!(chord.symbol(), "dim");
+        assert_eq!(chord.to_string(), "B3 diminished");
+    }
+
+    #[test]
+    fn test_diminished_triad_interval_class_vector() {
+        // Two minor thirds and the tritone between the root and the fifth
+        assert_eq!(
+            DiminishedTriadPattern::interval_class_vector(),
+            [0, 0, 2, 0, 0, 1]
+        );
+    }
+}
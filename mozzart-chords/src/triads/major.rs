@@ -0,0 +1,85 @@
+//! Major triad implementation.
+//!
+//! The major triad is the most common chord in Western music. It stacks a
+//! minor third on top of a major third, and sounds stable and bright.
+//!
+//! # Chord Structure
+//!
+//! The major triad built on C is made of the following notes:
+//! ```text
+//! C E G
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The major triad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 3rd (4) + Perfect 5th (7)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for major triads.
+///
+/// This type is used to distinguish major triads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorTriadType;
+
+impl ChordType for MajorTriadType {
+    fn name() -> &'static str {
+        "major"
+    }
+
+    fn symbol() -> &'static str {
+        ""
+    }
+}
+
+/// The pattern for a major triad.
+///
+/// This pattern defines the intervals that make up a major triad:
+/// ```text
+/// Root + Major 3rd + Perfect 5th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MajorTriadPattern;
+
+impl ChordPattern for MajorTriadPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH];
+
+    type ChordTyp = MajorTriadType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::{Letter, SpelledPitch};
+
+    #[test]
+    fn test_major_triad_chord() {
+        let chord = MajorTriadPattern::apply(C4);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+
+        assert_eq!(chord.root(), C4);
+        assert_eq!(chord.name(), "major");
+        assert_eq!(chord.symbol(), "");
+        assert_eq!(chord.to_string(), "C4 major");
+    }
+
+    #[test]
+    fn test_major_triad_spelling() {
+        let d_flat = SpelledPitch::new(DFLAT4, Letter::D).unwrap();
+        let names: Vec<_> = MajorTriadPattern::spell(d_flat)
+            .iter()
+            .map(|pitch| pitch.to_string())
+            .collect();
+        assert_eq!(names, ["Db4", "F4", "Ab4"]);
+    }
+}
@@ -0,0 +1,84 @@
+//! Minor triad implementation.
+//!
+//! The minor triad stacks a major third on top of a minor third. It is the
+//! mirror image of the major triad, and sounds darker and more melancholic.
+//!
+//! # Chord Structure
+//!
+//! The minor triad built on C is made of the following notes:
+//! ```text
+//! C Eb G
+//! ```
+//!
+//! # Interval Pattern
+//!
+//! The minor triad is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 3rd (3) + Perfect 5th (7)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{ChordPattern, ChordType, Interval};
+
+/// A marker type for minor triads.
+///
+/// This type is used to distinguish minor triads from other chord types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorTriadType;
+
+impl ChordType for MinorTriadType {
+    fn name() -> &'static str {
+        "minor"
+    }
+
+    fn symbol() -> &'static str {
+        "m"
+    }
+}
+
+/// The pattern for a minor triad.
+///
+/// This pattern defines the intervals that make up a minor triad:
+/// ```text
+/// Root + Minor 3rd + Perfect 5th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinorTriadPattern;
+
+impl ChordPattern for MinorTriadPattern {
+    type Pattern = [Interval; 3];
+    const PATTERN: Self::Pattern = [PERFECT_UNISON, MINOR_THIRD, PERFECT_FIFTH];
+
+    type ChordTyp = MinorTriadType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MajorTriadPattern;
+    use mozzart_core::PitchClassSet;
+
+    #[test]
+    fn test_minor_triad_chord() {
+        let chord = MinorTriadPattern::apply(A3);
+
+        let pitches = chord.pitches();
+        assert_eq!(pitches.len(), 3);
+        assert_eq!(pitches[0], A3);
+        assert_eq!(pitches[1], C4);
+        assert_eq!(pitches[2], E4);
+
+        assert_eq!(chord.root(), A3);
+        assert_eq!(chord.name(), "minor");
+        assert_eq!(chord.symbol(), "m");
+        assert_eq!(chord.to_string(), "A3 minor");
+    }
+
+    #[test]
+    fn test_minor_triad_inverts_major_triad() {
+        let minor: PitchClassSet = MinorTriadPattern::apply(C4).pitches().iter().collect();
+        let major: PitchClassSet = MajorTriadPattern::apply(C4).pitches().iter().collect();
+        assert_ne!(minor, major);
+        assert_eq!(minor.prime_form(), major.prime_form());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod augmented;
+pub mod diminished;
+pub mod major;
+pub mod minor;
+
+pub use augmented::*;
+pub use diminished::*;
+pub use major::*;
+pub use minor::*;
@@ -0,0 +1,131 @@
+//! Acoustic properties of pitch pairs.
+//!
+//! This module provides functionality for reasoning about two pitches sounding
+//! together, including:
+//! - Difference tones
+//! - Beat frequencies between coinciding partials
+//!
+//! # Difference Tones
+//!
+//! When two tones sound together, the ear perceives a faint third tone whose
+//! frequency is the difference of the two frequencies. For a just major third
+//! the difference tone lands two octaves below the lower note:
+//!
+//! ```text
+//! C4 (261.63 Hz) + E4 (327.03 Hz, just) -> 65.41 Hz (C2)
+//! ```
+//!
+//! # Beats
+//!
+//! An interval whose frequency ratio is close to, but not exactly, a simple ratio
+//! p/q has partials that almost coincide: the q-th partial of the higher tone and
+//! the p-th partial of the lower tone. Their slight mismatch is heard as beating,
+//! at a rate equal to the difference of the two partial frequencies. Justly tuned
+//! intervals do not beat:
+//!
+//! ```text
+//! Major third C4-E4 (5/4)
+//! Equal temperament:  4 x 329.63 - 5 x 261.63 = 10.38 beats per second
+//! Just intonation:    4 x 327.03 - 5 x 261.63 =  0.00 beats per second
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{TuningSystem, beat_frequency, difference_tone};
+//! use mozzart_core::constants::*;
+//!
+//! let beats = beat_frequency(C4, E4, TuningSystem::EqualTemperament);
+//! assert!((beats - 10.38).abs() < 0.01);
+//! assert!(beat_frequency(C4, E4, TuningSystem::JustIntonation).abs() < 1e-9);
+//!
+//! let tone = difference_tone(C4, E4, TuningSystem::JustIntonation);
+//! assert!((tone - C2.frequency()).abs() < 1e-9);
+//! ```
+
+use crate::tuning::just_ratio;
+use crate::{Interval, Pitch, TuningSystem};
+
+/// Returns the frequency in Hz of the difference tone of two pitches.
+///
+/// The pitches are tuned relative to each other in the given tuning system,
+/// as described in [`TuningSystem::frequencies`].
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{TuningSystem, difference_tone};
+/// use mozzart_core::constants::*;
+///
+/// // A just fifth produces a difference tone an octave below the lower note
+/// let tone = difference_tone(A4, E5, TuningSystem::JustIntonation);
+/// assert_eq!(tone, 220.0);
+/// ```
+pub fn difference_tone(a: Pitch, b: Pitch, tuning: TuningSystem) -> f64 {
+    let (a, b) = tuning.frequencies(a, b);
+    (a - b).abs()
+}
+
+/// Returns the beat frequency in Hz of two pitches.
+///
+/// The beat frequency is the difference between the nearly coinciding partials of
+/// the two pitches, taken from the just ratio of the interval between them. The
+/// pitches are tuned relative to each other in the given tuning system, as
+/// described in [`TuningSystem::frequencies`].
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{TuningSystem, beat_frequency};
+/// use mozzart_core::constants::*;
+///
+/// // An equal-tempered fifth beats slowly
+/// let beats = beat_frequency(A3, E4, TuningSystem::EqualTemperament);
+/// assert!((beats - 0.74).abs() < 0.01);
+///
+/// // A Pythagorean major third beats faster than an equal-tempered one
+/// let pythagorean = beat_frequency(C4, E4, TuningSystem::Pythagorean);
+/// let equal = beat_frequency(C4, E4, TuningSystem::EqualTemperament);
+/// assert!(pythagorean > equal);
+/// ```
+pub fn beat_frequency(a: Pitch, b: Pitch, tuning: TuningSystem) -> f64 {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    let (low_frequency, high_frequency) = tuning.frequencies(low, high);
+    let (numerator, denominator) = just_ratio(Interval::new(high.semitones() - low.semitones()));
+    (denominator as f64 * high_frequency - numerator as f64 * low_frequency).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_difference_tone() {
+        assert_eq!(difference_tone(A4, A4, TuningSystem::EqualTemperament), 0.0);
+        assert_eq!(
+            difference_tone(A4, A5, TuningSystem::EqualTemperament),
+            440.0
+        );
+        assert_eq!(
+            difference_tone(A5, A4, TuningSystem::EqualTemperament),
+            difference_tone(A4, A5, TuningSystem::EqualTemperament)
+        );
+
+        let tone = difference_tone(C4, G4, TuningSystem::JustIntonation);
+        assert!((tone - C3.frequency()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_beat_frequency() {
+        for tuning in [TuningSystem::JustIntonation, TuningSystem::EqualTemperament] {
+            assert!(beat_frequency(C4, C5, tuning).abs() < 1e-9);
+        }
+        assert!(beat_frequency(C4, G4, TuningSystem::Pythagorean).abs() < 1e-9);
+        assert!(beat_frequency(C4, G5, TuningSystem::JustIntonation).abs() < 1e-9);
+
+        let beats = beat_frequency(C4, E4, TuningSystem::Pythagorean);
+        assert!((beats - C4.frequency() / 16.0).abs() < 1e-9);
+        assert_eq!(beats, beat_frequency(E4, C4, TuningSystem::Pythagorean));
+    }
+}
@@ -0,0 +1,502 @@
+//! Type-erased patterns.
+//!
+//! This module provides functionality for working with patterns chosen at runtime,
+//! including:
+//! - Type-erased scale patterns
+//! - Type-erased chord patterns
+//! - Applying type-erased patterns to root pitches
+//! - Type-erased scales
+//!
+//! # Static and Dynamic Patterns
+//!
+//! [`ScalePattern`] and [`ChordPattern`] describe each pattern as its own type, so
+//! patterns of different types cannot be stored in the same collection. The boxed
+//! patterns capture the name and intervals of a pattern type as plain data:
+//!
+//! ```text
+//! Static:   MajorScalePattern::apply(C4)          one type per pattern
+//! Dynamic:  BoxedScalePattern::new::<P>()         one type for every pattern
+//!               .apply(C4)
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{BoxedScalePattern, Interval, ScalePattern, ScaleType};
+//! use mozzart_core::constants::*;
+//!
+//! struct MajorPentatonicScaleType;
+//! impl ScaleType for MajorPentatonicScaleType {
+//!     fn name() -> &'static str {
+//!         "major pentatonic"
+//!     }
+//! }
+//!
+//! struct MajorPentatonicScalePattern;
+//! impl ScalePattern for MajorPentatonicScalePattern {
+//!     type Pattern = [Interval; 5];
+//!     const PATTERN: Self::Pattern =
+//!         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+//!     type ScaleTyp = MajorPentatonicScaleType;
+//! }
+//!
+//! struct WholeToneScaleType;
+//! impl ScaleType for WholeToneScaleType {
+//!     fn name() -> &'static str {
+//!         "whole tone"
+//!     }
+//! }
+//!
+//! struct WholeToneScalePattern;
+//! impl ScalePattern for WholeToneScalePattern {
+//!     type Pattern = [Interval; 6];
+//!     const PATTERN: Self::Pattern = [
+//!         PERFECT_UNISON,
+//!         MAJOR_SECOND,
+//!         MAJOR_THIRD,
+//!         AUGMENTED_FOURTH,
+//!         AUGMENTED_FIFTH,
+//!         MINOR_SEVENTH,
+//!     ];
+//!     type ScaleTyp = WholeToneScaleType;
+//! }
+//!
+//! let patterns = [
+//!     BoxedScalePattern::new::<MajorPentatonicScalePattern>(),
+//!     BoxedScalePattern::new::<WholeToneScalePattern>(),
+//! ];
+//!
+//! let names: Vec<_> = patterns.iter().map(|pattern| pattern.name()).collect();
+//! assert_eq!(names, ["major pentatonic", "whole tone"]);
+//! assert_eq!(patterns[1].apply(C4), [C4, D4, E4, FSHARP4, GSHARP4, ASHARP4]);
+//! ```
+//!
+//! Built scales are erased the same way, by converting a [`Scale`] into a
+//! [`DynScale`]:
+//!
+//! ```rust
+//! use mozzart_core::{DynScale, Scale, ScaleType};
+//! use mozzart_core::constants::*;
+//!
+//! struct MajorScaleType;
+//! impl ScaleType for MajorScaleType {
+//!     fn name() -> &'static str {
+//!         "major"
+//!     }
+//! }
+//!
+//! struct BluesScaleType;
+//! impl ScaleType for BluesScaleType {
+//!     fn name() -> &'static str {
+//!         "blues"
+//!     }
+//! }
+//!
+//! let scales: Vec<DynScale> = vec![
+//!     Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]).into(),
+//!     Scale::<BluesScaleType>::new(vec![A3, C4, D4, DSHARP4, E4, G4]).into(),
+//! ];
+//! let names: Vec<_> = scales.iter().map(|scale| scale.to_string()).collect();
+//! assert_eq!(names, ["C4 major", "A3 blues"]);
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{ChordPattern, Interval, Pitch, Scale, ScalePattern, ScaleType};
+
+/// A scale pattern whose type is only known at runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{BoxedScalePattern, Interval, ScalePattern, ScaleType};
+/// use mozzart_core::constants::*;
+///
+/// struct MajorPentatonicScaleType;
+/// impl ScaleType for MajorPentatonicScaleType {
+///     fn name() -> &'static str {
+///         "major pentatonic"
+///     }
+/// }
+///
+/// struct MajorPentatonicScalePattern;
+/// impl ScalePattern for MajorPentatonicScalePattern {
+///     type Pattern = [Interval; 5];
+///     const PATTERN: Self::Pattern =
+///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+///     type ScaleTyp = MajorPentatonicScaleType;
+/// }
+///
+/// let pattern = BoxedScalePattern::new::<MajorPentatonicScalePattern>();
+/// assert_eq!(pattern.name(), "major pentatonic");
+/// assert_eq!(pattern.len(), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoxedScalePattern {
+    name: &'static str,
+    intervals: Box<[Interval]>,
+}
+
+/// A chord pattern whose type is only known at runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{BoxedChordPattern, ChordPattern, ChordType, Interval};
+/// use mozzart_core::constants::*;
+///
+/// struct MajorTriadType;
+/// impl ChordType for MajorTriadType {
+///     fn name() -> &'static str {
+///         "major"
+///     }
+/// }
+///
+/// struct MajorTriadPattern;
+/// impl ChordPattern for MajorTriadPattern {
+///     type Pattern = [Interval; 3];
+///     const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH];
+///     type ChordTyp = MajorTriadType;
+/// }
+///
+/// let pattern = BoxedChordPattern::new::<MajorTriadPattern>();
+/// assert_eq!(pattern.name(), "major");
+/// assert_eq!(pattern.len(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoxedChordPattern {
+    name: &'static str,
+    intervals: Box<[Interval]>,
+}
+
+impl BoxedScalePattern {
+    /// Creates a new boxed pattern from a scale pattern type.
+    #[inline]
+    pub fn new<P: ScalePattern>() -> Self {
+        Self {
+            name: P::name(),
+            intervals: P::intervals().into_boxed_slice(),
+        }
+    }
+
+    /// Creates a new boxed pattern from a name and the intervals of the pattern,
+    /// for scales without a pattern type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::BoxedScalePattern;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let pattern = BoxedScalePattern::from_intervals(
+    ///     "major pentatonic",
+    ///     [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH],
+    /// );
+    /// assert_eq!(pattern.name(), "major pentatonic");
+    /// assert_eq!(pattern.apply(C4), [C4, D4, E4, G4, A4]);
+    /// ```
+    pub fn from_intervals<I>(name: &'static str, intervals: I) -> Self
+    where
+        I: IntoIterator<Item = Interval>,
+    {
+        Self {
+            name,
+            intervals: intervals.into_iter().collect(),
+        }
+    }
+
+    /// Returns the name of the scale type.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the intervals of the scale pattern.
+    #[inline]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Returns the number of intervals in the scale pattern.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns whether the scale pattern has no intervals.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Applies the scale pattern to a root pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{BoxedScalePattern, Interval, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// struct MajorPentatonicScalePattern;
+    /// impl ScalePattern for MajorPentatonicScalePattern {
+    ///     type Pattern = [Interval; 5];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+    ///     type ScaleTyp = MajorPentatonicScaleType;
+    /// }
+    ///
+    /// let pattern = BoxedScalePattern::new::<MajorPentatonicScalePattern>();
+    /// assert_eq!(pattern.apply(G3), [G3, A3, B3, D4, E4]);
+    /// ```
+    #[inline]
+    pub fn apply(&self, root: Pitch) -> Vec<Pitch> {
+        root.apply_pattern(self.intervals.iter().copied())
+    }
+}
+
+impl BoxedChordPattern {
+    /// Creates a new boxed pattern from a chord pattern type.
+    #[inline]
+    pub fn new<P: ChordPattern>() -> Self {
+        Self {
+            name: P::name(),
+            intervals: P::intervals().into_boxed_slice(),
+        }
+    }
+
+    /// Returns the name of the chord type.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the intervals of the chord pattern.
+    #[inline]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Returns the number of intervals in the chord pattern.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns whether the chord pattern has no intervals.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Applies the chord pattern to a root pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{BoxedChordPattern, ChordPattern, ChordType, Interval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MinorTriadType;
+    /// impl ChordType for MinorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "minor"
+    ///     }
+    /// }
+    ///
+    /// struct MinorTriadPattern;
+    /// impl ChordPattern for MinorTriadPattern {
+    ///     type Pattern = [Interval; 3];
+    ///     const PATTERN: Self::Pattern = [PERFECT_UNISON, MINOR_THIRD, PERFECT_FIFTH];
+    ///     type ChordTyp = MinorTriadType;
+    /// }
+    ///
+    /// let pattern = BoxedChordPattern::new::<MinorTriadPattern>();
+    /// assert_eq!(pattern.apply(A3), [A3, C4, E4]);
+    /// ```
+    #[inline]
+    pub fn apply(&self, root: Pitch) -> Vec<Pitch> {
+        root.apply_pattern(self.intervals.iter().copied())
+    }
+}
+
+/// A scale whose type is only known at runtime.
+///
+/// A `DynScale` holds the name of its scale type and its pitches, and is created
+/// from any [`Scale`]. Unlike scale type names, its name may also be built at
+/// runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{DynScale, Scale, ScaleType};
+/// use mozzart_core::constants::*;
+///
+/// struct DorianScaleType;
+/// impl ScaleType for DorianScaleType {
+///     fn name() -> &'static str {
+///         "dorian"
+///     }
+/// }
+///
+/// let scale = DynScale::from(Scale::<DorianScaleType>::new(vec![D4, E4, F4, G4, A4, B4, C5]));
+/// assert_eq!(scale.name(), "dorian");
+/// assert_eq!(scale.root(), Some(D4));
+/// assert_eq!(scale.len(), 7);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynScale {
+    name: Cow<'static, str>,
+    pitches: Vec<Pitch>,
+}
+
+impl DynScale {
+    /// Creates a new scale from the name of its type and its pitches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::DynScale;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let scale = DynScale::new("whole tone", vec![C4, D4, E4, FSHARP4, GSHARP4, ASHARP4]);
+    /// assert_eq!(scale.to_string(), "C4 whole tone");
+    ///
+    /// let scale = DynScale::new(format!("mode {} of blues", 2), vec![C4, DSHARP4, E4]);
+    /// assert_eq!(scale.name(), "mode 2 of blues");
+    /// ```
+    #[inline]
+    pub fn new<N>(name: N, pitches: Vec<Pitch>) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        Self {
+            name: name.into(),
+            pitches,
+        }
+    }
+
+    /// Returns the name of the scale type.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the pitches of the scale.
+    #[inline]
+    pub fn pitches(&self) -> &[Pitch] {
+        &self.pitches
+    }
+
+    /// Returns the root pitch of the scale, or `None` if the scale is empty.
+    #[inline]
+    pub fn root(&self) -> Option<Pitch> {
+        self.pitches.first().copied()
+    }
+
+    /// Returns the number of pitches in the scale.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pitches.len()
+    }
+
+    /// Returns whether the scale has no pitches.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pitches.is_empty()
+    }
+}
+
+impl<S: ScaleType> From<Scale<S>> for DynScale {
+    fn from(scale: Scale<S>) -> Self {
+        Self::new(scale.name(), scale.into_iter().collect())
+    }
+}
+
+impl fmt::Display for DynScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.root() {
+            Some(root) => write!(f, "{} {}", root, self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::{ChordType, ScaleType};
+
+    struct MyScaleType;
+    impl ScaleType for MyScaleType {
+        fn name() -> &'static str {
+            "my scale"
+        }
+    }
+
+    struct MyScalePattern;
+    impl ScalePattern for MyScalePattern {
+        type Pattern = [Interval; 3];
+        const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_SECOND, PERFECT_FOURTH];
+        type ScaleTyp = MyScaleType;
+    }
+
+    struct MyChordType;
+    impl ChordType for MyChordType {
+        fn name() -> &'static str {
+            "my chord"
+        }
+    }
+
+    struct MyChordPattern;
+    impl ChordPattern for MyChordPattern {
+        type Pattern = [Interval; 2];
+        const PATTERN: Self::Pattern = [MINOR_THIRD, DIMINISHED_FIFTH];
+        type ChordTyp = MyChordType;
+    }
+
+    #[test]
+    fn test_boxed_scale_pattern() {
+        let pattern = BoxedScalePattern::new::<MyScalePattern>();
+        assert_eq!(pattern.name(), MyScalePattern::name());
+        assert_eq!(pattern.intervals(), MyScalePattern::intervals());
+        assert_eq!(pattern.apply(D4), MyScalePattern::apply(D4).pitches());
+        assert!(!pattern.is_empty());
+        assert_eq!(
+            BoxedScalePattern::from_intervals("my scale", MyScalePattern::PATTERN),
+            pattern
+        );
+    }
+
+    #[test]
+    fn test_boxed_chord_pattern() {
+        let pattern = BoxedChordPattern::new::<MyChordPattern>();
+        assert_eq!(pattern.name(), "my chord");
+        assert_eq!(pattern.intervals(), [MINOR_THIRD, DIMINISHED_FIFTH]);
+        assert_eq!(pattern.apply(B3), [D4, F4]);
+        assert_eq!(pattern.len(), 2);
+    }
+
+    #[test]
+    fn test_dyn_scale() {
+        let scale = MyScalePattern::apply(D4);
+        let display = scale.to_string();
+        let erased = DynScale::from(scale);
+        assert_eq!(erased.name(), "my scale");
+        assert_eq!(erased.pitches(), [D4, E4, G4]);
+        assert_eq!(erased.to_string(), display);
+
+        let empty = DynScale::new("empty", vec![]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.root(), None);
+        assert_eq!(empty.to_string(), "empty");
+    }
+}
@@ -0,0 +1,120 @@
+//! Scale categories.
+//!
+//! This module provides functionality for grouping scales by their number of
+//! pitches, including:
+//! - Scale categories, from pentatonic to octatonic
+//! - The category of a scale pattern
+//!
+//! # Categories
+//!
+//! Scales are grouped by the number of pitches in an octave:
+//!
+//! ```text
+//! Category     Pitches   Examples
+//! Pentatonic   5         pentatonic major, hirajoshi
+//! Hexatonic    6         blues, whole tone
+//! Heptatonic   7         major, dorian, harmonic minor
+//! Octatonic    8         diminished, bebop
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::ScaleCategory;
+//!
+//! assert_eq!(ScaleCategory::from_size(7), Some(ScaleCategory::Heptatonic));
+//! assert_eq!(ScaleCategory::Hexatonic.size(), 6);
+//! assert_eq!(ScaleCategory::Octatonic.to_string(), "octatonic");
+//! ```
+
+use std::fmt;
+
+/// Represents a category of scales with the same number of pitches.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScaleCategory;
+///
+/// assert_eq!(ScaleCategory::Pentatonic.name(), "pentatonic");
+/// assert!(ScaleCategory::Pentatonic < ScaleCategory::Heptatonic);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScaleCategory {
+    /// Scales of five pitches.
+    Pentatonic,
+    /// Scales of six pitches.
+    Hexatonic,
+    /// Scales of seven pitches.
+    Heptatonic,
+    /// Scales of eight pitches.
+    Octatonic,
+}
+
+impl ScaleCategory {
+    /// Returns the category of scales with the given number of pitches, or `None`
+    /// if no category has that number of pitches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScaleCategory;
+    ///
+    /// assert_eq!(ScaleCategory::from_size(5), Some(ScaleCategory::Pentatonic));
+    /// assert_eq!(ScaleCategory::from_size(12), None);
+    /// ```
+    pub const fn from_size(size: usize) -> Option<ScaleCategory> {
+        match size {
+            5 => Some(ScaleCategory::Pentatonic),
+            6 => Some(ScaleCategory::Hexatonic),
+            7 => Some(ScaleCategory::Heptatonic),
+            8 => Some(ScaleCategory::Octatonic),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of pitches of the scales in the category.
+    pub const fn size(&self) -> usize {
+        match self {
+            ScaleCategory::Pentatonic => 5,
+            ScaleCategory::Hexatonic => 6,
+            ScaleCategory::Heptatonic => 7,
+            ScaleCategory::Octatonic => 8,
+        }
+    }
+
+    /// Returns the name of the category.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ScaleCategory::Pentatonic => "pentatonic",
+            ScaleCategory::Hexatonic => "hexatonic",
+            ScaleCategory::Heptatonic => "heptatonic",
+            ScaleCategory::Octatonic => "octatonic",
+        }
+    }
+}
+
+impl fmt::Display for ScaleCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_size_roundtrip() {
+        for category in [
+            ScaleCategory::Pentatonic,
+            ScaleCategory::Hexatonic,
+            ScaleCategory::Heptatonic,
+            ScaleCategory::Octatonic,
+        ] {
+            assert_eq!(ScaleCategory::from_size(category.size()), Some(category));
+        }
+        assert_eq!(ScaleCategory::from_size(0), None);
+        assert_eq!(ScaleCategory::from_size(4), None);
+    }
+}
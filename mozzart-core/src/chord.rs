@@ -35,6 +35,30 @@
 //! assert_eq!(perfect_fifth, G4);
 //! ```
 //!
+//! Applying a chord pattern to a root:
+//! ```rust
+//! use mozzart_core::{ChordPattern, ChordType, Interval};
+//! use mozzart_core::constants::*;
+//!
+//! struct MajorTriadType;
+//! impl ChordType for MajorTriadType {
+//!     fn name() -> &'static str {
+//!         "major"
+//!     }
+//! }
+//!
+//! struct MajorTriadPattern;
+//! impl ChordPattern for MajorTriadPattern {
+//!     type Pattern = [Interval; 3];
+//!     const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH];
+//!     type ChordTyp = MajorTriadType;
+//! }
+//!
+//! let c_major = MajorTriadPattern::apply(C4);
+//! assert_eq!(c_major.pitches(), [C4, E4, G4]);
+//! assert_eq!(c_major.to_string(), "C4 major");
+//! ```
+//!
 //! # Musical Concepts
 //!
 //! ## Chord Types
@@ -53,12 +77,18 @@
 //! Root (0) + Minor Third (3) + Perfect Fifth (7)
 //! ```
 
-use crate::Interval;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use crate::constants::C;
+use crate::{Interval, Pitch, PitchClassSet, SpelledPitch, spell_chord};
 
-/// A marker trait for chord types.
+/// A trait representing a type of chord.
 ///
 /// This trait is used to distinguish between different types of chords
-/// (e.g., major, minor, diminished, augmented) at the type level.
+/// (e.g., major, minor, diminished, augmented) at the type level, and names
+/// them at runtime.
 ///
 /// # Examples
 ///
@@ -66,12 +96,59 @@ use crate::Interval;
 /// use mozzart_core::ChordType;
 ///
 /// struct Major;
-/// impl ChordType for Major {}
+/// impl ChordType for Major {
+///     fn name() -> &'static str {
+///         "major"
+///     }
+/// }
 ///
 /// struct Minor;
-/// impl ChordType for Minor {}
+/// impl ChordType for Minor {
+///     fn name() -> &'static str {
+///         "minor"
+///     }
+/// }
 /// ```
-pub trait ChordType {}
+pub trait ChordType {
+    fn name() -> &'static str;
+
+    /// Returns the symbol of the chord type, written after the root in chord
+    /// symbols such as `Cm7` or `G7sus4`.
+    ///
+    /// Chord types use their name as their symbol unless they override this
+    /// method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ChordType;
+    ///
+    /// struct MinorSixthType;
+    /// impl ChordType for MinorSixthType {
+    ///     fn name() -> &'static str {
+    ///         "minor sixth"
+    ///     }
+    ///
+    ///     fn symbol() -> &'static str {
+    ///         "m6"
+    ///     }
+    /// }
+    ///
+    /// struct ClusterType;
+    /// impl ChordType for ClusterType {
+    ///     fn name() -> &'static str {
+    ///         "cluster"
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(MinorSixthType::symbol(), "m6");
+    /// assert_eq!(ClusterType::symbol(), "cluster");
+    /// ```
+    #[inline]
+    fn symbol() -> &'static str {
+        Self::name()
+    }
+}
 
 /// A trait for defining chord patterns.
 ///
@@ -87,7 +164,11 @@ pub trait ChordType {}
 /// use mozzart_core::constants::*;
 ///
 /// struct MajorTriadType;
-/// impl ChordType for MajorTriadType {}
+/// impl ChordType for MajorTriadType {
+///     fn name() -> &'static str {
+///         "major"
+///     }
+/// }
 ///
 /// struct MajorTriadPattern;
 /// impl ChordPattern for MajorTriadPattern {
@@ -110,4 +191,401 @@ pub trait ChordPattern {
     /// This associates the pattern with a specific chord type
     /// (e.g., major, minor, diminished).
     type ChordTyp: ChordType;
+
+    /// Returns the name of the chord type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{ChordPattern, ChordType, Interval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorTriadType;
+    /// impl ChordType for MajorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct MajorTriadPattern;
+    /// impl ChordPattern for MajorTriadPattern {
+    ///     type Pattern = [Interval; 3];
+    ///     const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH];
+    ///     type ChordTyp = MajorTriadType;
+    /// }
+    ///
+    /// assert_eq!(MajorTriadPattern::name(), "major");
+    /// ```
+    #[inline]
+    fn name() -> &'static str {
+        Self::ChordTyp::name()
+    }
+
+    /// Returns the symbol of the chord type.
+    #[inline]
+    fn symbol() -> &'static str {
+        Self::ChordTyp::symbol()
+    }
+
+    /// Returns the intervals of the chord pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{ChordPattern, ChordType, Interval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorTriadType;
+    /// impl ChordType for MajorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct MajorTriadPattern;
+    /// impl ChordPattern for MajorTriadPattern {
+    ///     type Pattern = [Interval; 3];
+    ///     const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH];
+    ///     type ChordTyp = MajorTriadType;
+    /// }
+    ///
+    /// assert_eq!(
+    ///     MajorTriadPattern::intervals(),
+    ///     [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH]
+    /// );
+    /// ```
+    #[inline]
+    fn intervals() -> Vec<Interval> {
+        Self::PATTERN.into_iter().collect()
+    }
+
+    /// Returns the number of intervals in the chord pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{ChordPattern, ChordType, Interval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorTriadType;
+    /// impl ChordType for MajorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct MajorTriadPattern;
+    /// impl ChordPattern for MajorTriadPattern {
+    ///     type Pattern = [Interval; 3];
+    ///     const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH];
+    ///     type ChordTyp = MajorTriadType;
+    /// }
+    ///
+    /// assert_eq!(MajorTriadPattern::len(), 3);
+    /// ```
+    #[inline]
+    fn len() -> usize {
+        Self::PATTERN.into_iter().count()
+    }
+
+    /// Applies the chord pattern to a root pitch, creating a new chord.
+    ///
+    /// The pitches of the chord are the root transposed by each interval of the
+    /// pattern, so patterns list the unison to include the root itself. The
+    /// chord keeps its root either way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{ChordPattern, ChordType, Interval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MinorTriadType;
+    /// impl ChordType for MinorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "minor"
+    ///     }
+    /// }
+    ///
+    /// struct MinorTriadPattern;
+    /// impl ChordPattern for MinorTriadPattern {
+    ///     type Pattern = [Interval; 3];
+    ///     const PATTERN: Self::Pattern = [PERFECT_UNISON, MINOR_THIRD, PERFECT_FIFTH];
+    ///     type ChordTyp = MinorTriadType;
+    /// }
+    ///
+    /// let a_minor = MinorTriadPattern::apply(A3);
+    /// assert_eq!(a_minor.pitches(), [A3, C4, E4]);
+    /// assert_eq!(a_minor.root(), A3);
+    /// assert_eq!(a_minor.name(), "minor");
+    /// ```
+    #[inline]
+    fn apply(root: Pitch) -> Chord<Self::ChordTyp> {
+        let pitches = root.apply_pattern(Self::PATTERN);
+        Chord::<Self::ChordTyp>::new(root, pitches)
+    }
+
+    /// Spells the members of the chord built on the given root.
+    ///
+    /// Each member is named after its chord degree, so the third of E major is
+    /// spelled G# rather than Ab. See [`spell_chord`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{ChordPattern, ChordType, Interval, Letter, SpelledPitch};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorTriadType;
+    /// impl ChordType for MajorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct MajorTriadPattern;
+    /// impl ChordPattern for MajorTriadPattern {
+    ///     type Pattern = [Interval; 3];
+    ///     const PATTERN: Self::Pattern = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH];
+    ///     type ChordTyp = MajorTriadType;
+    /// }
+    ///
+    /// let d_flat = SpelledPitch::new(DFLAT4, Letter::D).unwrap();
+    /// let chord = MajorTriadPattern::spell(d_flat);
+    /// let names: Vec<_> = chord.iter().map(|pitch| pitch.to_string()).collect();
+    /// assert_eq!(names, ["Db4", "F4", "Ab4"]);
+    /// ```
+    #[inline]
+    fn spell(root: SpelledPitch) -> Vec<SpelledPitch> {
+        spell_chord(root, Self::PATTERN)
+    }
+
+    /// Returns the interval-class vector of the chord.
+    ///
+    /// The root is always counted, whether or not the pattern lists a unison.
+    /// See [`PitchClassSet::interval_class_vector`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{ChordPattern, ChordType, Interval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct DominantSeventhType;
+    /// impl ChordType for DominantSeventhType {
+    ///     fn name() -> &'static str {
+    ///         "dominant seventh"
+    ///     }
+    /// }
+    ///
+    /// struct DominantSeventhPattern;
+    /// impl ChordPattern for DominantSeventhPattern {
+    ///     type Pattern = [Interval; 3];
+    ///     const PATTERN: Self::Pattern = [MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH];
+    ///     type ChordTyp = DominantSeventhType;
+    /// }
+    ///
+    /// assert_eq!(DominantSeventhPattern::interval_class_vector(), [0, 1, 2, 1, 1, 1]);
+    /// ```
+    fn interval_class_vector() -> [u8; 6] {
+        let mut set: PitchClassSet = C.apply_pattern(Self::PATTERN).into_iter().collect();
+        set.insert(C);
+        set.interval_class_vector()
+    }
+}
+
+/// A musical chord of a specific type.
+///
+/// A chord holds its root and its pitches, and is associated with a chord type
+/// at the type level, much like a [`Scale`](crate::Scale). The root is kept
+/// apart from the pitches, so that chords leaving out their root still know it.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Chord, ChordType};
+/// use mozzart_core::constants::*;
+///
+/// struct DominantSeventhType;
+/// impl ChordType for DominantSeventhType {
+///     fn name() -> &'static str {
+///         "dominant seventh"
+///     }
+/// }
+///
+/// let g7 = Chord::<DominantSeventhType>::new(G3, vec![G3, B3, D4, F4]);
+/// assert_eq!(g7.root(), G3);
+/// assert_eq!(g7.len(), 4);
+/// assert_eq!(g7.to_string(), "G3 dominant seventh");
+/// ```
+pub struct Chord<C: ChordType> {
+    /// The root of the chord.
+    root: Pitch,
+    /// The pitches that make up the chord.
+    pitches: Vec<Pitch>,
+    /// A phantom data marker to associate the chord with its type.
+    typ: PhantomData<C>,
+}
+
+impl<C: ChordType> Chord<C> {
+    /// Creates a new chord from its root and its pitches.
+    ///
+    /// The pitches do not need to include the root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Chord, ChordType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorTriadType;
+    /// impl ChordType for MajorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let f_major = Chord::<MajorTriadType>::new(F4, vec![F4, A4, C5]);
+    /// assert_eq!(f_major.pitches()[2], C5);
+    ///
+    /// let rootless = Chord::<MajorTriadType>::new(F4, vec![A4, C5]);
+    /// assert_eq!(rootless.root(), F4);
+    /// ```
+    #[inline]
+    pub const fn new(root: Pitch, pitches: Vec<Pitch>) -> Self {
+        Self {
+            root,
+            pitches,
+            typ: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the pitches of the chord.
+    #[inline]
+    pub fn pitches(&self) -> &[Pitch] {
+        &self.pitches
+    }
+
+    /// Returns the number of pitches in the chord.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pitches.len()
+    }
+
+    /// Returns whether the chord has no pitches.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pitches.is_empty()
+    }
+
+    /// Returns the root pitch of the chord.
+    #[inline]
+    pub fn root(&self) -> Pitch {
+        self.root
+    }
+
+    /// Returns the name of the chord type.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        C::name()
+    }
+
+    /// Returns the symbol of the chord type.
+    #[inline]
+    pub fn symbol(&self) -> &'static str {
+        C::symbol()
+    }
+}
+
+impl<C: ChordType> fmt::Debug for Chord<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chord")
+            .field("name", &self.name())
+            .field("root", &self.root)
+            .field("pitches", &self.pitches)
+            .finish()
+    }
+}
+
+/// Compares chords of the same type by their roots and their pitches, in order.
+impl<C: ChordType> PartialEq for Chord<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root && self.pitches == other.pitches
+    }
+}
+
+impl<C: ChordType> Eq for Chord<C> {}
+
+impl<C: ChordType> Hash for Chord<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.root.hash(state);
+        self.pitches.hash(state);
+    }
+}
+
+impl<C: ChordType> fmt::Display for Chord<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.root(), self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    struct MyChordType;
+    impl ChordType for MyChordType {
+        fn name() -> &'static str {
+            "my chord"
+        }
+    }
+
+    struct MyChordPattern;
+    impl ChordPattern for MyChordPattern {
+        type Pattern = [Interval; 2];
+        const PATTERN: Self::Pattern = [MINOR_THIRD, DIMINISHED_FIFTH];
+        type ChordTyp = MyChordType;
+    }
+
+    #[test]
+    fn test_chord_pattern_introspection() {
+        assert_eq!(MyChordPattern::name(), "my chord");
+        assert_eq!(MyChordPattern::symbol(), "my chord");
+        assert_eq!(MyChordPattern::intervals(), [MINOR_THIRD, DIMINISHED_FIFTH]);
+        assert_eq!(MyChordPattern::len(), 2);
+    }
+
+    struct MyTriadPattern;
+    impl ChordPattern for MyTriadPattern {
+        type Pattern = [Interval; 3];
+        const PATTERN: Self::Pattern = [PERFECT_UNISON, MINOR_THIRD, DIMINISHED_FIFTH];
+        type ChordTyp = MyChordType;
+    }
+
+    #[test]
+    fn test_chord_apply() {
+        let chord = MyTriadPattern::apply(B3);
+        assert_eq!(chord.pitches(), [B3, D4, F4]);
+        assert_eq!(chord.root(), B3);
+        assert_eq!(chord.name(), "my chord");
+        assert_eq!(chord.to_string(), "B3 my chord");
+        assert_eq!(chord.len(), 3);
+        assert!(!chord.is_empty());
+
+        assert_eq!(chord, Chord::new(B3, vec![B3, D4, F4]));
+        assert_ne!(chord, MyTriadPattern::apply(B4));
+
+        // Patterns without a unison leave out the root, but keep it as the root
+        let rootless = MyChordPattern::apply(B3);
+        assert_eq!(rootless.pitches(), [D4, F4]);
+        assert_eq!(rootless.root(), B3);
+        assert_eq!(rootless.to_string(), "B3 my chord");
+        assert_ne!(rootless, Chord::new(D4, vec![D4, F4]));
+    }
+
+    #[test]
+    fn test_interval_class_vector() {
+        assert_eq!(MyChordPattern::interval_class_vector(), [0, 0, 2, 0, 0, 1]);
+    }
 }
@@ -0,0 +1,290 @@
+//! Scale degree names.
+//!
+//! This module provides functionality for naming the degrees of a scale, including:
+//! - Scale degree names, from the tonic to the leading tone or subtonic
+//! - The degree of a pitch in a scale
+//! - The pitch of a degree in a scale
+//!
+//! # Degrees
+//!
+//! The degrees of a seven-note scale are named after their function relative to
+//! the tonic. The seventh degree is the leading tone when it lies a semitone below
+//! the tonic, and the subtonic when it lies a whole tone below:
+//!
+//! ```text
+//! Degree   Name          C major   C natural minor
+//! 1        Tonic         C         C
+//! 2        Supertonic    D         D
+//! 3        Mediant       E         Eb
+//! 4        Subdominant   F         F
+//! 5        Dominant      G         G
+//! 6        Submediant    A         Ab
+//! 7        Leading tone  B         -
+//! 7        Subtonic      -         Bb
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{Scale, ScaleDegree, ScaleType};
+//! use mozzart_core::constants::*;
+//!
+//! struct MajorScaleType;
+//! impl ScaleType for MajorScaleType {
+//!     fn name() -> &'static str {
+//!         "major"
+//!     }
+//! }
+//!
+//! let g_major = Scale::<MajorScaleType>::new(vec![G4, A4, B4, C5, D5, E5, FSHARP5]);
+//! assert_eq!(g_major.degree_of(D3), Some(ScaleDegree::Dominant));
+//! assert_eq!(g_major.pitch_of(ScaleDegree::LeadingTone), Some(FSHARP5));
+//! assert_eq!(g_major.pitch_of(ScaleDegree::Subtonic), None);
+//! ```
+
+use std::fmt;
+
+use crate::constants::{MAJOR_SEVENTH, MINOR_SEVENTH};
+use crate::{Interval, Pitch, Scale, ScaleType};
+
+/// The number of pitches of a scale with named degrees.
+const DEGREES: usize = 7;
+
+/// Represents the name of a degree of a seven-note scale.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScaleDegree;
+///
+/// assert_eq!(ScaleDegree::Submediant.number(), 6);
+/// assert_eq!(ScaleDegree::LeadingTone.to_string(), "leading tone");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScaleDegree {
+    /// The first degree, the root of the scale.
+    Tonic,
+    /// The second degree.
+    Supertonic,
+    /// The third degree.
+    Mediant,
+    /// The fourth degree.
+    Subdominant,
+    /// The fifth degree.
+    Dominant,
+    /// The sixth degree.
+    Submediant,
+    /// The seventh degree, a semitone below the tonic.
+    LeadingTone,
+    /// The seventh degree, a whole tone below the tonic.
+    Subtonic,
+}
+
+impl ScaleDegree {
+    /// Returns the number of the degree, counted from 1 for the tonic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScaleDegree;
+    ///
+    /// assert_eq!(ScaleDegree::Tonic.number(), 1);
+    /// assert_eq!(ScaleDegree::LeadingTone.number(), 7);
+    /// assert_eq!(ScaleDegree::Subtonic.number(), 7);
+    /// ```
+    pub const fn number(&self) -> u8 {
+        match self {
+            ScaleDegree::Tonic => 1,
+            ScaleDegree::Supertonic => 2,
+            ScaleDegree::Mediant => 3,
+            ScaleDegree::Subdominant => 4,
+            ScaleDegree::Dominant => 5,
+            ScaleDegree::Submediant => 6,
+            ScaleDegree::LeadingTone | ScaleDegree::Subtonic => 7,
+        }
+    }
+
+    /// Returns the name of the degree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScaleDegree;
+    ///
+    /// assert_eq!(ScaleDegree::Subdominant.name(), "subdominant");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ScaleDegree::Tonic => "tonic",
+            ScaleDegree::Supertonic => "supertonic",
+            ScaleDegree::Mediant => "mediant",
+            ScaleDegree::Subdominant => "subdominant",
+            ScaleDegree::Dominant => "dominant",
+            ScaleDegree::Submediant => "submediant",
+            ScaleDegree::LeadingTone => "leading tone",
+            ScaleDegree::Subtonic => "subtonic",
+        }
+    }
+
+    /// Returns the degree at a position of a seven-note scale, counted from 0 for
+    /// the tonic, given the interval of the seventh degree above the tonic.
+    const fn at(index: usize, seventh: Interval) -> Option<ScaleDegree> {
+        match (index, seventh) {
+            (0, _) => Some(ScaleDegree::Tonic),
+            (1, _) => Some(ScaleDegree::Supertonic),
+            (2, _) => Some(ScaleDegree::Mediant),
+            (3, _) => Some(ScaleDegree::Subdominant),
+            (4, _) => Some(ScaleDegree::Dominant),
+            (5, _) => Some(ScaleDegree::Submediant),
+            (6, MAJOR_SEVENTH) => Some(ScaleDegree::LeadingTone),
+            (6, MINOR_SEVENTH) => Some(ScaleDegree::Subtonic),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ScaleDegree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl<S: ScaleType> Scale<S> {
+    /// Returns the name of the degree of a pitch in the scale, whatever the
+    /// octave of the pitch.
+    ///
+    /// Returns `None` if the pitch class is not in the scale, if the scale does
+    /// not have seven pitches, or for a seventh degree that is neither a leading
+    /// tone nor a subtonic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleDegree, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MinorScaleType;
+    /// impl ScaleType for MinorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "minor"
+    ///     }
+    /// }
+    ///
+    /// let a_minor = Scale::<MinorScaleType>::new(vec![A3, B3, C4, D4, E4, F4, G4]);
+    /// assert_eq!(a_minor.degree_of(C5), Some(ScaleDegree::Mediant));
+    /// assert_eq!(a_minor.degree_of(G4), Some(ScaleDegree::Subtonic));
+    /// assert_eq!(a_minor.degree_of(GSHARP4), None);
+    /// ```
+    pub fn degree_of(&self, pitch: Pitch) -> Option<ScaleDegree> {
+        let index = self
+            .pitches()
+            .iter()
+            .position(|member| member.canonical() == pitch.canonical())?;
+        self.seventh()
+            .and_then(|seventh| ScaleDegree::at(index, seventh))
+    }
+
+    /// Returns the pitch of a named degree of the scale.
+    ///
+    /// Returns `None` if the scale does not have seven pitches, or if its seventh
+    /// degree does not match a leading tone or subtonic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleDegree, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct HarmonicMinorScaleType;
+    /// impl ScaleType for HarmonicMinorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "harmonic minor"
+    ///     }
+    /// }
+    ///
+    /// let a_minor = Scale::<HarmonicMinorScaleType>::new(vec![A3, B3, C4, D4, E4, F4, GSHARP4]);
+    /// assert_eq!(a_minor.pitch_of(ScaleDegree::Dominant), Some(E4));
+    /// assert_eq!(a_minor.pitch_of(ScaleDegree::LeadingTone), Some(GSHARP4));
+    /// assert_eq!(a_minor.pitch_of(ScaleDegree::Subtonic), None);
+    /// ```
+    pub fn pitch_of(&self, degree: ScaleDegree) -> Option<Pitch> {
+        let index = degree.number() as usize - 1;
+        let seventh = self.seventh()?;
+        (ScaleDegree::at(index, seventh) == Some(degree)).then(|| self.pitches()[index])
+    }
+
+    /// Returns the interval from the tonic up to the seventh degree, or `None` if
+    /// the scale does not have seven pitches.
+    fn seventh(&self) -> Option<Interval> {
+        if self.len() != DEGREES {
+            return None;
+        }
+
+        let (tonic, seventh) = (self.root().canonical(), self.pitches()[6].canonical());
+        let octave = crate::constants::SEMITONES_PER_OCTAVE;
+        Some(Interval::new(
+            (seventh.semitones() + octave - tonic.semitones()) % octave,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    struct MyScaleType;
+    impl ScaleType for MyScaleType {
+        fn name() -> &'static str {
+            "my scale"
+        }
+    }
+
+    #[test]
+    fn test_degrees_of_major_scale() {
+        let scale = Scale::<MyScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+        let degrees: Vec<_> = scale
+            .pitches()
+            .iter()
+            .map(|&pitch| scale.degree_of(pitch).unwrap())
+            .collect();
+        assert_eq!(
+            degrees,
+            [
+                ScaleDegree::Tonic,
+                ScaleDegree::Supertonic,
+                ScaleDegree::Mediant,
+                ScaleDegree::Subdominant,
+                ScaleDegree::Dominant,
+                ScaleDegree::Submediant,
+                ScaleDegree::LeadingTone,
+            ]
+        );
+        for degree in degrees {
+            assert_eq!(
+                scale.degree_of(scale.pitch_of(degree).unwrap()),
+                Some(degree)
+            );
+        }
+    }
+
+    #[test]
+    fn test_unnamed_degrees() {
+        // A pentatonic scale has no named degrees
+        let scale = Scale::<MyScaleType>::new(vec![C4, D4, E4, G4, A4]);
+        assert_eq!(scale.degree_of(C4), None);
+        assert_eq!(scale.pitch_of(ScaleDegree::Tonic), None);
+
+        // Nor does a locrian bb7 scale, whose seventh is a major sixth above the tonic
+        let scale = Scale::<MyScaleType>::new(vec![C4, CSHARP4, DSHARP4, F4, FSHARP4, GSHARP4, A4]);
+        assert_eq!(scale.degree_of(A4), None);
+        assert_eq!(scale.degree_of(F4), Some(ScaleDegree::Subdominant));
+    }
+
+    #[test]
+    fn test_seventh_across_the_octave() {
+        let scale = Scale::<MyScaleType>::new(vec![G4, A4, B4, C5, D5, E5, F5]);
+        assert_eq!(scale.pitch_of(ScaleDegree::Subtonic), Some(F5));
+        assert_eq!(scale.pitch_of(ScaleDegree::LeadingTone), None);
+    }
+}
@@ -0,0 +1,281 @@
+//! Compact binary encoding of pitch sequences.
+//!
+//! This module provides functionality for storing pitch sequences compactly, including:
+//! - Encoding a pitch sequence into bytes
+//! - Appending encoded sequences to a shared buffer
+//! - Decoding one or many sequences back into pitches
+//!
+//! # Format
+//!
+//! A sequence is written as its length followed by the difference of each pitch
+//! from the previous one (the first pitch is taken relative to C-1, MIDI 0).
+//! Every number is stored as a variable-length integer (LEB128), and differences
+//! are zigzag-mapped so that small steps in either direction stay small:
+//!
+//! ```text
+//! Difference:  0  -1  +1  -2  +2  ...  -64  +64
+//! Zigzag:      0   1   2   3   4  ...  127  128
+//! Bytes:       1   1   1   1   1  ...    1    2
+//! ```
+//!
+//! Melodies move mostly by small steps, so most pitches take a single byte.
+//! Encoded sequences can be concatenated into one buffer and read back one at a
+//! time with [`decode_prefix`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::encode;
+//! use mozzart_core::constants::*;
+//!
+//! let melody = [C4, D4, E4, C4, G4];
+//! let bytes = encode::encode(&melody);
+//! assert_eq!(bytes.len(), 6);
+//! assert_eq!(encode::decode(&bytes).unwrap(), melody);
+//! ```
+
+use std::fmt;
+
+use crate::Pitch;
+use crate::pitch::MIDI_MAX;
+
+/// An error returned when decoding a pitch sequence fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended in the middle of a sequence.
+    UnexpectedEnd,
+    /// A variable-length integer was too long to be valid.
+    InvalidVarint,
+    /// A decoded pitch was outside the MIDI range.
+    OutOfRange,
+    /// The input continued after the end of the sequence.
+    TrailingBytes,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            DecodeError::UnexpectedEnd => "unexpected end of input",
+            DecodeError::InvalidVarint => "invalid variable-length integer",
+            DecodeError::OutOfRange => "pitch out of range",
+            DecodeError::TrailingBytes => "trailing bytes after the sequence",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Encodes a pitch sequence into a new byte vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::encode;
+/// use mozzart_core::constants::*;
+///
+/// let bytes = encode::encode(&[C4, CSHARP4, C4]);
+/// assert_eq!(bytes, [3, 120, 2, 1]);
+/// ```
+pub fn encode(pitches: &[Pitch]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(pitches.len() + 2);
+    encode_into(&mut bytes, pitches);
+    bytes
+}
+
+/// Appends the encoding of a pitch sequence to `bytes`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::encode;
+/// use mozzart_core::constants::*;
+///
+/// let mut corpus = Vec::new();
+/// encode::encode_into(&mut corpus, &[C4, E4, G4]);
+/// encode::encode_into(&mut corpus, &[A3, C4, E4]);
+///
+/// let (first, rest) = encode::decode_prefix(&corpus).unwrap();
+/// let (second, rest) = encode::decode_prefix(rest).unwrap();
+/// assert_eq!(first, [C4, E4, G4]);
+/// assert_eq!(second, [A3, C4, E4]);
+/// assert!(rest.is_empty());
+/// ```
+pub fn encode_into(bytes: &mut Vec<u8>, pitches: &[Pitch]) {
+    write_varint(bytes, pitches.len() as u64);
+
+    let mut previous = 0i16;
+    for pitch in pitches {
+        let current = pitch.semitones() as i16;
+        write_varint(bytes, zigzag(current - previous));
+        previous = current;
+    }
+}
+
+/// Decodes a single pitch sequence, requiring the input to contain nothing else.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::encode::{self, DecodeError};
+/// use mozzart_core::constants::*;
+///
+/// assert_eq!(encode::decode(&[2, 120, 8]), Ok(vec![C4, E4]));
+/// assert_eq!(encode::decode(&[2, 120]), Err(DecodeError::UnexpectedEnd));
+/// ```
+pub fn decode(bytes: &[u8]) -> Result<Vec<Pitch>, DecodeError> {
+    let (pitches, rest) = decode_prefix(bytes)?;
+    if !rest.is_empty() {
+        return Err(DecodeError::TrailingBytes);
+    }
+    Ok(pitches)
+}
+
+/// Decodes the pitch sequence at the start of `bytes`, returning it together
+/// with the remaining input.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::encode;
+/// use mozzart_core::constants::*;
+///
+/// let (pitches, rest) = encode::decode_prefix(&[1, 120, 42]).unwrap();
+/// assert_eq!(pitches, [C4]);
+/// assert_eq!(rest, [42]);
+/// ```
+pub fn decode_prefix(bytes: &[u8]) -> Result<(Vec<Pitch>, &[u8]), DecodeError> {
+    let (len, mut rest) = read_varint(bytes)?;
+
+    // Every pitch takes at least one byte, which bounds the allocation.
+    let mut pitches = Vec::with_capacity((len as usize).min(rest.len()));
+    let mut previous = 0i64;
+    for _ in 0..len {
+        let (delta, tail) = read_varint(rest)?;
+        rest = tail;
+
+        let current = previous
+            .checked_add(unzigzag(delta))
+            .ok_or(DecodeError::OutOfRange)?;
+        let semitones = u8::try_from(current)
+            .ok()
+            .filter(|semitones| *semitones <= MIDI_MAX)
+            .ok_or(DecodeError::OutOfRange)?;
+        pitches.push(Pitch::new(semitones));
+        previous = current;
+    }
+
+    Ok((pitches, rest))
+}
+
+/// Maps a signed difference onto an unsigned number, interleaving negative
+/// and positive values.
+#[inline]
+fn zigzag(value: i16) -> u64 {
+    ((value << 1) ^ (value >> 15)) as u16 as u64
+}
+
+/// Reverses [`zigzag`].
+#[inline]
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Appends `value` to `bytes` as an unsigned LEB128 integer.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 integer from the start of `bytes`.
+fn read_varint(bytes: &[u8]) -> Result<(u64, &[u8]), DecodeError> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        if shift >= u64::BITS {
+            return Err(DecodeError::InvalidVarint);
+        }
+
+        // The tenth byte holds the top bit of a u64, and nothing above it.
+        let bits = (byte & 0x7f) as u64;
+        if bits >> (u64::BITS - shift).min(7) != 0 {
+            return Err(DecodeError::InvalidVarint);
+        }
+
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value, &bytes[i + 1..]));
+        }
+    }
+    Err(DecodeError::UnexpectedEnd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_zigzag() {
+        for value in [0, 1, -1, 2, -2, 64, -64, 255, -255] {
+            assert_eq!(unzigzag(zigzag(value)), value as i64);
+        }
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let sequences: [&[Pitch]; 4] = [&[], &[C4], &[C4, G4, C5, G4, C4, C, G9, C], &O4.pitches()];
+        for pitches in sequences {
+            assert_eq!(decode(&encode(pitches)).unwrap(), pitches);
+        }
+
+        let all: Vec<_> = (0..=MIDI_MAX).rev().map(Pitch::new).collect();
+        assert_eq!(decode(&encode(&all)).unwrap(), all);
+    }
+
+    #[test]
+    fn test_stepwise_melody_is_one_byte_per_pitch() {
+        let melody = [C4, D4, E4, F4, G4, A4, B4, C5, B4, A4, G4, F4, E4, D4, C4];
+        assert_eq!(encode(&melody).len(), melody.len() + 1);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(decode(&[]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(&[1, 0x80]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(&[0, 0]), Err(DecodeError::TrailingBytes));
+        assert_eq!(decode(&[1, 1]), Err(DecodeError::OutOfRange));
+        assert_eq!(decode(&[1, 0x80, 0x04]), Err(DecodeError::OutOfRange));
+        assert_eq!(decode(&[0xff; 11]), Err(DecodeError::InvalidVarint));
+    }
+
+    #[test]
+    fn test_decode_above_midi_range() {
+        assert_eq!(decode(&[1, 0xfe, 0x01]), Ok(vec![Pitch::new(MIDI_MAX)]));
+        assert_eq!(decode(&[1, 0x80, 0x02]), Err(DecodeError::OutOfRange));
+        assert_eq!(decode(&[1, 0x90, 0x03]), Err(DecodeError::OutOfRange));
+    }
+
+    #[test]
+    fn test_decode_overflowing_delta() {
+        // The second delta is i64::MAX, which overflows when added to the first pitch
+        let bytes = [
+            2, 0x02, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+        ];
+        assert_eq!(decode(&bytes), Err(DecodeError::OutOfRange));
+    }
+
+    #[test]
+    fn test_read_varint_tenth_byte() {
+        let max = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        assert_eq!(read_varint(&max), Ok((u64::MAX, &[][..])));
+
+        let overflowing = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        assert_eq!(read_varint(&overflowing), Err(DecodeError::InvalidVarint));
+    }
+}
@@ -0,0 +1,345 @@
+//! Forte set-class names.
+//!
+//! This module provides the standard labelling of set classes introduced by Allen
+//! Forte in The Structure of Atonal Music (1973). Every set class of three to nine
+//! pitch classes is named by its cardinality and an ordinal number, with a `Z`
+//! marking set classes that share their interval-class vector with another one:
+//!
+//! ```text
+//! Forte name   Prime form               Example
+//! 3-11         (0, 3, 7)                Major and minor triads
+//! 4-27         (0, 2, 5, 8)             Dominant and half-diminished seventh chords
+//! 4-Z15        (0, 1, 4, 6)             All-interval tetrachord
+//! 7-35         (0, 1, 3, 5, 6, 8, 10)   Diatonic scale
+//! ```
+//!
+//! Complementary set classes share the same ordinal number, so 7-35 is the
+//! complement of 5-35. The prime forms follow Forte's packing, as computed by
+//! [`PitchClassSet::prime_form`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::PitchClassSet;
+//! use mozzart_core::constants::*;
+//!
+//! let dominant: PitchClassSet = [G3, B3, D4, F4].into_iter().collect();
+//! assert_eq!(dominant.forte_name(), Some("4-27"));
+//!
+//! let major: PitchClassSet = [C4, D4, E4, F4, G4, A4, B4].into_iter().collect();
+//! assert_eq!(major.forte_name(), Some("7-35"));
+//! ```
+
+use crate::{Pitch, PitchClassSet};
+
+/// Forte names and prime forms of the set classes of three to nine pitch classes.
+const FORTE_SET_CLASSES: [(&str, &[u8]); 208] = [
+    ("3-1", &[0, 1, 2]),
+    ("3-2", &[0, 1, 3]),
+    ("3-3", &[0, 1, 4]),
+    ("3-4", &[0, 1, 5]),
+    ("3-5", &[0, 1, 6]),
+    ("3-6", &[0, 2, 4]),
+    ("3-7", &[0, 2, 5]),
+    ("3-8", &[0, 2, 6]),
+    ("3-9", &[0, 2, 7]),
+    ("3-10", &[0, 3, 6]),
+    ("3-11", &[0, 3, 7]),
+    ("3-12", &[0, 4, 8]),
+    ("4-1", &[0, 1, 2, 3]),
+    ("4-2", &[0, 1, 2, 4]),
+    ("4-3", &[0, 1, 3, 4]),
+    ("4-4", &[0, 1, 2, 5]),
+    ("4-5", &[0, 1, 2, 6]),
+    ("4-6", &[0, 1, 2, 7]),
+    ("4-7", &[0, 1, 4, 5]),
+    ("4-8", &[0, 1, 5, 6]),
+    ("4-9", &[0, 1, 6, 7]),
+    ("4-10", &[0, 2, 3, 5]),
+    ("4-11", &[0, 1, 3, 5]),
+    ("4-12", &[0, 2, 3, 6]),
+    ("4-13", &[0, 1, 3, 6]),
+    ("4-14", &[0, 2, 3, 7]),
+    ("4-Z15", &[0, 1, 4, 6]),
+    ("4-16", &[0, 1, 5, 7]),
+    ("4-17", &[0, 3, 4, 7]),
+    ("4-18", &[0, 1, 4, 7]),
+    ("4-19", &[0, 1, 4, 8]),
+    ("4-20", &[0, 1, 5, 8]),
+    ("4-21", &[0, 2, 4, 6]),
+    ("4-22", &[0, 2, 4, 7]),
+    ("4-23", &[0, 2, 5, 7]),
+    ("4-24", &[0, 2, 4, 8]),
+    ("4-25", &[0, 2, 6, 8]),
+    ("4-26", &[0, 3, 5, 8]),
+    ("4-27", &[0, 2, 5, 8]),
+    ("4-28", &[0, 3, 6, 9]),
+    ("4-Z29", &[0, 1, 3, 7]),
+    ("5-1", &[0, 1, 2, 3, 4]),
+    ("5-2", &[0, 1, 2, 3, 5]),
+    ("5-3", &[0, 1, 2, 4, 5]),
+    ("5-4", &[0, 1, 2, 3, 6]),
+    ("5-5", &[0, 1, 2, 3, 7]),
+    ("5-6", &[0, 1, 2, 5, 6]),
+    ("5-7", &[0, 1, 2, 6, 7]),
+    ("5-8", &[0, 2, 3, 4, 6]),
+    ("5-9", &[0, 1, 2, 4, 6]),
+    ("5-10", &[0, 1, 3, 4, 6]),
+    ("5-11", &[0, 2, 3, 4, 7]),
+    ("5-Z12", &[0, 1, 3, 5, 6]),
+    ("5-13", &[0, 1, 2, 4, 8]),
+    ("5-14", &[0, 1, 2, 5, 7]),
+    ("5-15", &[0, 1, 2, 6, 8]),
+    ("5-16", &[0, 1, 3, 4, 7]),
+    ("5-Z17", &[0, 1, 3, 4, 8]),
+    ("5-Z18", &[0, 1, 4, 5, 7]),
+    ("5-19", &[0, 1, 3, 6, 7]),
+    ("5-20", &[0, 1, 3, 7, 8]),
+    ("5-21", &[0, 1, 4, 5, 8]),
+    ("5-22", &[0, 1, 4, 7, 8]),
+    ("5-23", &[0, 2, 3, 5, 7]),
+    ("5-24", &[0, 1, 3, 5, 7]),
+    ("5-25", &[0, 2, 3, 5, 8]),
+    ("5-26", &[0, 2, 4, 5, 8]),
+    ("5-27", &[0, 1, 3, 5, 8]),
+    ("5-28", &[0, 2, 3, 6, 8]),
+    ("5-29", &[0, 1, 3, 6, 8]),
+    ("5-30", &[0, 1, 4, 6, 8]),
+    ("5-31", &[0, 1, 3, 6, 9]),
+    ("5-32", &[0, 1, 4, 6, 9]),
+    ("5-33", &[0, 2, 4, 6, 8]),
+    ("5-34", &[0, 2, 4, 6, 9]),
+    ("5-35", &[0, 2, 4, 7, 9]),
+    ("5-Z36", &[0, 1, 2, 4, 7]),
+    ("5-Z37", &[0, 3, 4, 5, 8]),
+    ("5-Z38", &[0, 1, 2, 5, 8]),
+    ("6-1", &[0, 1, 2, 3, 4, 5]),
+    ("6-2", &[0, 1, 2, 3, 4, 6]),
+    ("6-Z3", &[0, 1, 2, 3, 5, 6]),
+    ("6-Z4", &[0, 1, 2, 4, 5, 6]),
+    ("6-5", &[0, 1, 2, 3, 6, 7]),
+    ("6-Z6", &[0, 1, 2, 5, 6, 7]),
+    ("6-7", &[0, 1, 2, 6, 7, 8]),
+    ("6-8", &[0, 2, 3, 4, 5, 7]),
+    ("6-9", &[0, 1, 2, 3, 5, 7]),
+    ("6-Z10", &[0, 1, 3, 4, 5, 7]),
+    ("6-Z11", &[0, 1, 2, 4, 5, 7]),
+    ("6-Z12", &[0, 1, 2, 4, 6, 7]),
+    ("6-Z13", &[0, 1, 3, 4, 6, 7]),
+    ("6-14", &[0, 1, 3, 4, 5, 8]),
+    ("6-15", &[0, 1, 2, 4, 5, 8]),
+    ("6-16", &[0, 1, 4, 5, 6, 8]),
+    ("6-Z17", &[0, 1, 2, 4, 7, 8]),
+    ("6-18", &[0, 1, 2, 5, 7, 8]),
+    ("6-Z19", &[0, 1, 3, 4, 7, 8]),
+    ("6-20", &[0, 1, 4, 5, 8, 9]),
+    ("6-21", &[0, 2, 3, 4, 6, 8]),
+    ("6-22", &[0, 1, 2, 4, 6, 8]),
+    ("6-Z23", &[0, 2, 3, 5, 6, 8]),
+    ("6-Z24", &[0, 1, 3, 4, 6, 8]),
+    ("6-Z25", &[0, 1, 3, 5, 6, 8]),
+    ("6-Z26", &[0, 1, 3, 5, 7, 8]),
+    ("6-27", &[0, 1, 3, 4, 6, 9]),
+    ("6-Z28", &[0, 1, 3, 5, 6, 9]),
+    ("6-Z29", &[0, 1, 3, 6, 8, 9]),
+    ("6-30", &[0, 1, 3, 6, 7, 9]),
+    ("6-31", &[0, 1, 3, 5, 8, 9]),
+    ("6-32", &[0, 2, 4, 5, 7, 9]),
+    ("6-33", &[0, 2, 3, 5, 7, 9]),
+    ("6-34", &[0, 1, 3, 5, 7, 9]),
+    ("6-35", &[0, 2, 4, 6, 8, 10]),
+    ("6-Z36", &[0, 1, 2, 3, 4, 7]),
+    ("6-Z37", &[0, 1, 2, 3, 4, 8]),
+    ("6-Z38", &[0, 1, 2, 3, 7, 8]),
+    ("6-Z39", &[0, 2, 3, 4, 5, 8]),
+    ("6-Z40", &[0, 1, 2, 3, 5, 8]),
+    ("6-Z41", &[0, 1, 2, 3, 6, 8]),
+    ("6-Z42", &[0, 1, 2, 3, 6, 9]),
+    ("6-Z43", &[0, 1, 2, 5, 6, 8]),
+    ("6-Z44", &[0, 1, 2, 5, 6, 9]),
+    ("6-Z45", &[0, 2, 3, 4, 6, 9]),
+    ("6-Z46", &[0, 1, 2, 4, 6, 9]),
+    ("6-Z47", &[0, 1, 2, 4, 7, 9]),
+    ("6-Z48", &[0, 1, 2, 5, 7, 9]),
+    ("6-Z49", &[0, 1, 3, 4, 7, 9]),
+    ("6-Z50", &[0, 1, 4, 6, 7, 9]),
+    ("7-1", &[0, 1, 2, 3, 4, 5, 6]),
+    ("7-2", &[0, 1, 2, 3, 4, 5, 7]),
+    ("7-3", &[0, 1, 2, 3, 4, 5, 8]),
+    ("7-4", &[0, 1, 2, 3, 4, 6, 7]),
+    ("7-5", &[0, 1, 2, 3, 5, 6, 7]),
+    ("7-6", &[0, 1, 2, 3, 4, 7, 8]),
+    ("7-7", &[0, 1, 2, 3, 6, 7, 8]),
+    ("7-8", &[0, 2, 3, 4, 5, 6, 8]),
+    ("7-9", &[0, 1, 2, 3, 4, 6, 8]),
+    ("7-10", &[0, 1, 2, 3, 4, 6, 9]),
+    ("7-11", &[0, 1, 3, 4, 5, 6, 8]),
+    ("7-Z12", &[0, 1, 2, 3, 4, 7, 9]),
+    ("7-13", &[0, 1, 2, 4, 5, 6, 8]),
+    ("7-14", &[0, 1, 2, 3, 5, 7, 8]),
+    ("7-15", &[0, 1, 2, 4, 6, 7, 8]),
+    ("7-16", &[0, 1, 2, 3, 5, 6, 9]),
+    ("7-Z17", &[0, 1, 2, 4, 5, 6, 9]),
+    ("7-Z18", &[0, 1, 2, 3, 5, 8, 9]),
+    ("7-19", &[0, 1, 2, 3, 6, 7, 9]),
+    ("7-20", &[0, 1, 2, 4, 7, 8, 9]),
+    ("7-21", &[0, 1, 2, 4, 5, 8, 9]),
+    ("7-22", &[0, 1, 2, 5, 6, 8, 9]),
+    ("7-23", &[0, 2, 3, 4, 5, 7, 9]),
+    ("7-24", &[0, 1, 2, 3, 5, 7, 9]),
+    ("7-25", &[0, 2, 3, 4, 6, 7, 9]),
+    ("7-26", &[0, 1, 3, 4, 5, 7, 9]),
+    ("7-27", &[0, 1, 2, 4, 5, 7, 9]),
+    ("7-28", &[0, 1, 3, 5, 6, 7, 9]),
+    ("7-29", &[0, 1, 2, 4, 6, 7, 9]),
+    ("7-30", &[0, 1, 2, 4, 6, 8, 9]),
+    ("7-31", &[0, 1, 3, 4, 6, 7, 9]),
+    ("7-32", &[0, 1, 3, 4, 6, 8, 9]),
+    ("7-33", &[0, 1, 2, 4, 6, 8, 10]),
+    ("7-34", &[0, 1, 3, 4, 6, 8, 10]),
+    ("7-35", &[0, 1, 3, 5, 6, 8, 10]),
+    ("7-Z36", &[0, 1, 2, 3, 5, 6, 8]),
+    ("7-Z37", &[0, 1, 3, 4, 5, 7, 8]),
+    ("7-Z38", &[0, 1, 2, 4, 5, 7, 8]),
+    ("8-1", &[0, 1, 2, 3, 4, 5, 6, 7]),
+    ("8-2", &[0, 1, 2, 3, 4, 5, 6, 8]),
+    ("8-3", &[0, 1, 2, 3, 4, 5, 6, 9]),
+    ("8-4", &[0, 1, 2, 3, 4, 5, 7, 8]),
+    ("8-5", &[0, 1, 2, 3, 4, 6, 7, 8]),
+    ("8-6", &[0, 1, 2, 3, 5, 6, 7, 8]),
+    ("8-7", &[0, 1, 2, 3, 4, 5, 8, 9]),
+    ("8-8", &[0, 1, 2, 3, 4, 7, 8, 9]),
+    ("8-9", &[0, 1, 2, 3, 6, 7, 8, 9]),
+    ("8-10", &[0, 2, 3, 4, 5, 6, 7, 9]),
+    ("8-11", &[0, 1, 2, 3, 4, 5, 7, 9]),
+    ("8-12", &[0, 1, 3, 4, 5, 6, 7, 9]),
+    ("8-13", &[0, 1, 2, 3, 4, 6, 7, 9]),
+    ("8-14", &[0, 1, 2, 4, 5, 6, 7, 9]),
+    ("8-Z15", &[0, 1, 2, 3, 4, 6, 8, 9]),
+    ("8-16", &[0, 1, 2, 3, 5, 7, 8, 9]),
+    ("8-17", &[0, 1, 3, 4, 5, 6, 8, 9]),
+    ("8-18", &[0, 1, 2, 3, 5, 6, 8, 9]),
+    ("8-19", &[0, 1, 2, 4, 5, 6, 8, 9]),
+    ("8-20", &[0, 1, 2, 4, 5, 7, 8, 9]),
+    ("8-21", &[0, 1, 2, 3, 4, 6, 8, 10]),
+    ("8-22", &[0, 1, 2, 3, 5, 6, 8, 10]),
+    ("8-23", &[0, 1, 2, 3, 5, 7, 8, 10]),
+    ("8-24", &[0, 1, 2, 4, 5, 6, 8, 10]),
+    ("8-25", &[0, 1, 2, 4, 6, 7, 8, 10]),
+    ("8-26", &[0, 1, 2, 4, 5, 7, 9, 10]),
+    ("8-27", &[0, 1, 2, 4, 5, 7, 8, 10]),
+    ("8-28", &[0, 1, 3, 4, 6, 7, 9, 10]),
+    ("8-Z29", &[0, 1, 2, 3, 5, 6, 7, 9]),
+    ("9-1", &[0, 1, 2, 3, 4, 5, 6, 7, 8]),
+    ("9-2", &[0, 1, 2, 3, 4, 5, 6, 7, 9]),
+    ("9-3", &[0, 1, 2, 3, 4, 5, 6, 8, 9]),
+    ("9-4", &[0, 1, 2, 3, 4, 5, 7, 8, 9]),
+    ("9-5", &[0, 1, 2, 3, 4, 6, 7, 8, 9]),
+    ("9-6", &[0, 1, 2, 3, 4, 5, 6, 8, 10]),
+    ("9-7", &[0, 1, 2, 3, 4, 5, 7, 8, 10]),
+    ("9-8", &[0, 1, 2, 3, 4, 6, 7, 8, 10]),
+    ("9-9", &[0, 1, 2, 3, 5, 6, 7, 8, 10]),
+    ("9-10", &[0, 1, 2, 3, 4, 6, 7, 9, 10]),
+    ("9-11", &[0, 1, 2, 3, 5, 6, 7, 9, 10]),
+    ("9-12", &[0, 1, 2, 4, 5, 6, 8, 9, 10]),
+];
+
+impl PitchClassSet {
+    /// Returns the Forte name of the set class of this set.
+    ///
+    /// Forte names exist only for sets of three to nine pitch classes, so smaller
+    /// and larger sets return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let triad: PitchClassSet = [A3, C4, E4].into_iter().collect();
+    /// assert_eq!(triad.forte_name(), Some("3-11"));
+    ///
+    /// let all_interval: PitchClassSet = [C4, CSHARP4, E4, FSHARP4].into_iter().collect();
+    /// assert_eq!(all_interval.forte_name(), Some("4-Z15"));
+    ///
+    /// let dyad: PitchClassSet = [C4, G4].into_iter().collect();
+    /// assert_eq!(dyad.forte_name(), None);
+    /// ```
+    pub fn forte_name(&self) -> Option<&'static str> {
+        let prime = self.prime_form();
+        FORTE_SET_CLASSES
+            .iter()
+            .find(|(_, classes)| {
+                classes.len() == prime.len()
+                    && classes
+                        .iter()
+                        .all(|&class| prime.contains(Pitch::new(class)))
+            })
+            .map(|(name, _)| *name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_table_holds_prime_forms() {
+        for (name, classes) in FORTE_SET_CLASSES {
+            let set: PitchClassSet = classes.iter().map(|&class| Pitch::new(class)).collect();
+            assert_eq!(set.len(), classes.len(), "{}", name);
+            assert_eq!(set.prime_form(), set, "{}", name);
+            assert!(name.starts_with(&format!("{}-", classes.len())), "{}", name);
+            assert_eq!(set.forte_name(), Some(name));
+        }
+    }
+
+    #[test]
+    fn test_z_names_match_z_relation() {
+        let sets: Vec<_> = FORTE_SET_CLASSES
+            .iter()
+            .map(|(name, classes)| {
+                let set: PitchClassSet = classes.iter().map(|&class| Pitch::new(class)).collect();
+                (*name, set)
+            })
+            .collect();
+
+        for (name, set) in &sets {
+            let related = sets.iter().any(|(_, other)| set.is_z_related(other));
+            assert_eq!(name.contains('Z'), related, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_forte_name() {
+        let set = |pitches: &[Pitch]| pitches.iter().collect::<PitchClassSet>();
+        assert_eq!(set(&[C, E, GSHARP]).forte_name(), Some("3-12"));
+        assert_eq!(set(&[B, D, F, GSHARP]).forte_name(), Some("4-28"));
+        assert_eq!(set(&[C, D, E, G, A]).forte_name(), Some("5-35"));
+        assert_eq!(
+            set(&[C, D, E, FSHARP, GSHARP, ASHARP]).forte_name(),
+            Some("6-35")
+        );
+        assert_eq!(
+            set(&[C, CSHARP, DSHARP, E, FSHARP, G, A, ASHARP]).forte_name(),
+            Some("8-28")
+        );
+
+        // Forte's packing differs from Rahn's for 5-20 and its complement
+        assert_eq!(
+            set(&[C, CSHARP, F, FSHARP, GSHARP]).forte_name(),
+            Some("5-20")
+        );
+        assert_eq!(
+            set(&[C, CSHARP, D, E, G, GSHARP, A]).forte_name(),
+            Some("7-20")
+        );
+
+        assert_eq!(PitchClassSet::new().forte_name(), None);
+        assert_eq!(
+            O4.pitches().iter().collect::<PitchClassSet>().forte_name(),
+            None
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Harmonic series utilities.
+//!
+//! This module provides functionality for working with the harmonic series, including:
+//! - Partial frequencies of a fundamental pitch
+//! - Nearest equal-tempered pitch for each partial
+//! - Deviation of each partial from equal temperament in cents
+//!
+//! # Harmonic Series
+//!
+//! A pitched sound is made of a fundamental frequency and its partials, whose
+//! frequencies are whole-number multiples of the fundamental. The lower partials
+//! outline the octave, fifth and major third, which is why they are the basis of
+//! just intonation:
+//!
+//! ```text
+//! Partial  Ratio  Nearest pitch (from C2)  Deviation
+//! 1        1/1    C2                         0.00 cents
+//! 2        2/1    C3                         0.00 cents
+//! 3        3/1    G3                        +1.96 cents
+//! 4        4/1    C4                         0.00 cents
+//! 5        5/1    E4                       -13.69 cents
+//! 6        6/1    G4                        +1.96 cents
+//! 7        7/1    A#4                      -31.17 cents
+//! 8        8/1    C5                         0.00 cents
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::harmonic_series;
+//! use mozzart_core::constants::*;
+//!
+//! let partials = harmonic_series(A2, 4);
+//! assert_eq!(partials.len(), 4);
+//!
+//! let (frequency, pitch, cents) = partials[2];
+//! assert_eq!(frequency, 330.0);
+//! assert_eq!(pitch, E4);
+//! assert!((cents - 1.96).abs() < 0.01);
+//! ```
+
+use crate::Pitch;
+
+/// Returns the first `n` partials of the harmonic series built on `fundamental`.
+///
+/// Each partial is returned as a tuple of:
+/// - its frequency in Hz
+/// - the nearest equal-tempered pitch
+/// - the deviation of the partial from that pitch in cents
+///
+/// The fundamental itself is the first partial. Partials whose nearest pitch
+/// lies above the MIDI range (G9) are not returned, so the result may contain
+/// fewer than `n` entries.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::harmonic_series;
+/// use mozzart_core::constants::*;
+///
+/// let partials = harmonic_series(C2, 8);
+/// let pitches: Vec<_> = partials.iter().map(|(_, pitch, _)| *pitch).collect();
+/// assert_eq!(pitches, [C2, C3, G3, C4, E4, G4, ASHARP4, C5]);
+/// ```
+pub fn harmonic_series(fundamental: Pitch, n: usize) -> Vec<(f64, Pitch, f64)> {
+    let frequency = fundamental.frequency();
+    (1..=n)
+        .map_while(|partial| {
+            let partial_frequency = frequency * partial as f64;
+            Pitch::from_frequency(partial_frequency)
+                .map(|(pitch, cents)| (partial_frequency, pitch, cents))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_harmonic_series() {
+        let partials = harmonic_series(A2, 7);
+        assert_eq!(partials.len(), 7);
+
+        let expected = [
+            (110.0, A2, 0.0),
+            (220.0, A3, 0.0),
+            (330.0, E4, 1.96),
+            (440.0, A4, 0.0),
+            (550.0, CSHARP5, -13.69),
+            (660.0, E5, 1.96),
+            (770.0, G5, -31.17),
+        ];
+        for ((frequency, pitch, cents), (ef, ep, ec)) in partials.into_iter().zip(expected) {
+            assert!((frequency - ef).abs() < 1e-9);
+            assert_eq!(pitch, ep);
+            assert!((cents - ec).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_harmonic_series_stops_at_midi_range() {
+        let partials = harmonic_series(C8, 8);
+        assert_eq!(partials.len(), 3);
+        assert_eq!(partials[1].1, C9);
+        assert_eq!(partials[2].1, G9);
+
+        assert!(harmonic_series(C4, 0).is_empty());
+    }
+}
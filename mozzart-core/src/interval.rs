@@ -185,6 +185,34 @@
 //! let major_sixth = Interval::new(major_third + perfect_fourth);
 //! assert_eq!(major_sixth.semitones(), 9); // Major sixth
 //! ```
+//!
+//! ## Melodic Direction
+//! ```rust
+//! use mozzart_core::Interval;
+//! use mozzart_core::constants::*;
+//!
+//! // The interval between two pitches keeps the direction of the motion
+//! let leap = Interval::between(G4, C4);
+//! assert!(leap.is_descending());
+//! assert_eq!(leap.magnitude(), PERFECT_FIFTH);
+//! ```
+//!
+//! ## Interval Names
+//! ```rust
+//! use mozzart_core::Interval;
+//! use mozzart_core::constants::*;
+//!
+//! // Intervals read and write their usual abbreviations
+//! let interval: Interval = "M7".parse().unwrap();
+//! assert_eq!(interval, MAJOR_SEVENTH);
+//! assert_eq!(MINOR_THIRD.to_string(), "m3");
+//! ```
+
+use std::fmt;
+use std::ops::{Mul, Neg};
+use std::str::FromStr;
+
+use crate::{ParseIntervalError, Pitch, SpelledInterval};
 
 /// Represents a musical interval.
 ///
@@ -209,7 +237,26 @@
 /// // Use predefined interval constants
 /// assert_eq!(PERFECT_FIFTH.semitones(), 7);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Intervals are ordered by the number of semitones they span. Enharmonically
+/// equivalent intervals, such as the augmented fourth and the diminished fifth,
+/// are equal, and compound intervals are larger than every simple interval.
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+/// use mozzart_core::Interval;
+/// use mozzart_core::constants::*;
+///
+/// assert!(MINOR_THIRD < MAJOR_THIRD);
+/// assert!(MAJOR_SEVENTH < MINOR_NINTH);
+///
+/// let intervals: BTreeSet<Interval> =
+///     [PERFECT_FIFTH, AUGMENTED_FOURTH, DIMINISHED_FIFTH, MAJOR_SECOND].into_iter().collect();
+/// assert!(intervals.into_iter().eq([MAJOR_SECOND, TRITONE, PERFECT_FIFTH]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Interval(u8);
 
 impl Interval {
@@ -250,6 +297,265 @@ impl Interval {
     pub const fn semitones(&self) -> u8 {
         self.0
     }
+
+    /// Returns the directed interval from one pitch to another.
+    ///
+    /// The interval is ascending when `to` is higher than `from`, and descending
+    /// when it is lower.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{DirectedInterval, Interval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(Interval::between(C4, G4), DirectedInterval::up(PERFECT_FIFTH));
+    /// assert_eq!(Interval::between(G4, C4), DirectedInterval::down(PERFECT_FIFTH));
+    /// assert_eq!(Interval::between(C4, C4), DirectedInterval::up(PERFECT_UNISON));
+    /// ```
+    #[inline]
+    pub const fn between(from: Pitch, to: Pitch) -> DirectedInterval {
+        DirectedInterval(to.semitones() as i16 - from.semitones() as i16)
+    }
+
+    /// Returns the interval spanned by stacking this interval `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stacked interval spans more than 255 semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Interval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// // Three whole tones make a tritone
+    /// assert_eq!(MAJOR_SECOND.times(3), TRITONE);
+    /// // Two fourths make a minor seventh
+    /// assert_eq!(PERFECT_FOURTH.times(2), MINOR_SEVENTH);
+    /// assert_eq!(PERFECT_FIFTH.times(0), PERFECT_UNISON);
+    /// ```
+    #[inline]
+    pub const fn times(&self, n: u8) -> Interval {
+        match self.checked_times(n) {
+            Some(interval) => interval,
+            None => panic!("stacked interval spans more than 255 semitones"),
+        }
+    }
+
+    /// Returns the interval spanned by stacking this interval `n` times, or
+    /// `None` if it would span more than 255 semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Interval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(MINOR_THIRD.checked_times(4), Some(PERFECT_OCTAVE));
+    /// assert_eq!(PERFECT_OCTAVE.checked_times(22), None);
+    /// ```
+    #[inline]
+    pub const fn checked_times(&self, n: u8) -> Option<Interval> {
+        match self.semitones().checked_mul(n) {
+            Some(semitones) => Some(Interval(semitones)),
+            None => None,
+        }
+    }
+}
+
+impl Mul<u8> for Interval {
+    type Output = Interval;
+
+    /// Stacks the interval `n` times. See [`Interval::times`].
+    #[inline]
+    fn mul(self, n: u8) -> Self::Output {
+        self.times(n)
+    }
+}
+
+/// Represents a musical interval with a direction.
+///
+/// A directed interval is the signed distance in semitones from one pitch to
+/// another: positive when the motion is ascending and negative when it is
+/// descending. Its magnitude is an [`Interval`].
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{DirectedInterval, Interval};
+/// use mozzart_core::constants::*;
+///
+/// let melody = [C4, E4, D4, G3];
+/// let motion: Vec<_> = melody
+///     .windows(2)
+///     .map(|pair| Interval::between(pair[0], pair[1]))
+///     .collect();
+///
+/// assert_eq!(
+///     motion,
+///     [
+///         DirectedInterval::up(MAJOR_THIRD),
+///         DirectedInterval::down(MAJOR_SECOND),
+///         DirectedInterval::down(PERFECT_FIFTH),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirectedInterval(i16);
+
+impl DirectedInterval {
+    /// Create a new directed interval from a signed number of semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::DirectedInterval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(DirectedInterval::new(-3), DirectedInterval::down(MINOR_THIRD));
+    /// ```
+    #[inline]
+    pub const fn new(semitones: i16) -> Self {
+        Self(semitones)
+    }
+
+    /// Create a new ascending directed interval.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::DirectedInterval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(DirectedInterval::up(PERFECT_FIFTH).semitones(), 7);
+    /// ```
+    #[inline]
+    pub const fn up(interval: Interval) -> Self {
+        Self(interval.semitones() as i16)
+    }
+
+    /// Create a new descending directed interval.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::DirectedInterval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(DirectedInterval::down(PERFECT_FIFTH).semitones(), -7);
+    /// ```
+    #[inline]
+    pub const fn down(interval: Interval) -> Self {
+        Self(-(interval.semitones() as i16))
+    }
+
+    /// Returns the signed number of semitones in this interval.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Interval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(Interval::between(A4, C4).semitones(), -9);
+    /// ```
+    #[inline]
+    pub const fn semitones(&self) -> i16 {
+        self.0
+    }
+
+    /// Returns the size of this interval, regardless of its direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::DirectedInterval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(DirectedInterval::down(MAJOR_SIXTH).magnitude(), MAJOR_SIXTH);
+    /// ```
+    #[inline]
+    pub const fn magnitude(&self) -> Interval {
+        Interval(self.0.unsigned_abs() as u8)
+    }
+
+    /// Returns whether this interval moves upwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::DirectedInterval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert!(DirectedInterval::up(MINOR_SECOND).is_ascending());
+    /// assert!(!DirectedInterval::up(PERFECT_UNISON).is_ascending());
+    /// ```
+    #[inline]
+    pub const fn is_ascending(&self) -> bool {
+        self.0 > 0
+    }
+
+    /// Returns whether this interval moves downwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::DirectedInterval;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert!(DirectedInterval::down(MINOR_SECOND).is_descending());
+    /// assert!(!DirectedInterval::down(PERFECT_UNISON).is_descending());
+    /// ```
+    #[inline]
+    pub const fn is_descending(&self) -> bool {
+        self.0 < 0
+    }
+}
+
+impl From<Interval> for DirectedInterval {
+    /// Converts an interval into an ascending directed interval.
+    #[inline]
+    fn from(interval: Interval) -> Self {
+        Self::up(interval)
+    }
+}
+
+impl From<DirectedInterval> for Interval {
+    /// Converts a directed interval into its magnitude.
+    #[inline]
+    fn from(interval: DirectedInterval) -> Self {
+        interval.magnitude()
+    }
+}
+
+impl fmt::Display for Interval {
+    /// Formats the interval with its usual abbreviation, such as "P5" or "m3".
+    ///
+    /// See [`SpelledInterval::from_interval`] for how each size is named.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        SpelledInterval::from_interval(*self).fmt(f)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    /// Parses an interval abbreviation, such as "P5", "A4" or "m9".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<SpelledInterval>().map(Interval::from)
+    }
+}
+
+impl Neg for DirectedInterval {
+    type Output = Self;
+
+    /// Reverses the direction of the interval.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
 }
 
 /// Constants for common musical intervals.
@@ -257,7 +563,8 @@ impl Interval {
 /// This module provides constants for all standard intervals:
 /// - Perfect intervals: unison, fourth, fifth, octave
 /// - Major/minor intervals: second, third, sixth, seventh
-/// - Diminished intervals: fifth
+/// - Augmented and diminished intervals, such as the augmented fourth
+/// - Compound intervals, from the ninth to the double octave
 ///
 /// # Examples
 ///
@@ -275,6 +582,14 @@ impl Interval {
 /// assert_eq!(MINOR_THIRD.semitones(), 3);
 /// assert_eq!(MAJOR_SIXTH.semitones(), 9);
 /// assert_eq!(MINOR_SEVENTH.semitones(), 10);
+///
+/// // Augmented and diminished intervals
+/// assert_eq!(AUGMENTED_FOURTH, DIMINISHED_FIFTH);
+/// assert_eq!(DIMINISHED_SEVENTH.semitones(), 9);
+///
+/// // Compound intervals
+/// assert_eq!(MAJOR_NINTH.semitones(), 14);
+/// assert_eq!(MAJOR_THIRTEENTH.semitones(), 21);
 /// ```
 pub mod constants {
     use super::Interval;
@@ -305,10 +620,55 @@ pub mod constants {
     pub const MAJOR_SEVENTH: Interval = Interval(11);
     /// Perfect octave (12 semitones)
     pub const PERFECT_OCTAVE: Interval = Interval(12);
+
+    /// Augmented unison (1 semitone)
+    pub const AUGMENTED_UNISON: Interval = Interval(1);
+    /// Augmented second (3 semitones)
+    pub const AUGMENTED_SECOND: Interval = Interval(3);
+    /// Diminished fourth (4 semitones)
+    pub const DIMINISHED_FOURTH: Interval = Interval(4);
+    /// Augmented fourth (6 semitones)
+    pub const AUGMENTED_FOURTH: Interval = Interval(6);
+    /// Tritone (6 semitones)
+    pub const TRITONE: Interval = Interval(6);
+    /// Augmented fifth (8 semitones)
+    pub const AUGMENTED_FIFTH: Interval = Interval(8);
+    /// Augmented sixth (10 semitones)
+    pub const AUGMENTED_SIXTH: Interval = Interval(10);
+    /// Diminished seventh (9 semitones)
+    pub const DIMINISHED_SEVENTH: Interval = Interval(9);
+
+    /// Minor ninth (13 semitones)
+    pub const MINOR_NINTH: Interval = Interval(13);
+    /// Major ninth (14 semitones)
+    pub const MAJOR_NINTH: Interval = Interval(14);
+    /// Augmented ninth (15 semitones)
+    pub const AUGMENTED_NINTH: Interval = Interval(15);
+    /// Minor tenth (15 semitones)
+    pub const MINOR_TENTH: Interval = Interval(15);
+    /// Major tenth (16 semitones)
+    pub const MAJOR_TENTH: Interval = Interval(16);
+    /// Perfect eleventh (17 semitones)
+    pub const PERFECT_ELEVENTH: Interval = Interval(17);
+    /// Augmented eleventh (18 semitones)
+    pub const AUGMENTED_ELEVENTH: Interval = Interval(18);
+    /// Perfect twelfth (19 semitones)
+    pub const PERFECT_TWELFTH: Interval = Interval(19);
+    /// Minor thirteenth (20 semitones)
+    pub const MINOR_THIRTEENTH: Interval = Interval(20);
+    /// Major thirteenth (21 semitones)
+    pub const MAJOR_THIRTEENTH: Interval = Interval(21);
+    /// Minor fourteenth (22 semitones)
+    pub const MINOR_FOURTEENTH: Interval = Interval(22);
+    /// Major fourteenth (23 semitones)
+    pub const MAJOR_FOURTEENTH: Interval = Interval(23);
+    /// Double octave (24 semitones)
+    pub const DOUBLE_OCTAVE: Interval = Interval(24);
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::interval::constants::*;
     use crate::pitch::constants::*;
 
@@ -326,4 +686,108 @@ mod tests {
         assert_eq!(root.transpose(MAJOR_THIRD), E4);
         assert_eq!(root.transpose(PERFECT_OCTAVE), C5);
     }
+
+    #[test]
+    fn test_interval_between() {
+        assert_eq!(Interval::between(C4, E4).semitones(), 4);
+        assert_eq!(Interval::between(E4, C4).semitones(), -4);
+        assert_eq!(Interval::between(C, G9).semitones(), 127);
+        assert_eq!(Interval::between(G9, C).magnitude().semitones(), 127);
+    }
+
+    #[test]
+    fn test_directed_interval_conversions() {
+        let down = DirectedInterval::down(MINOR_SEVENTH);
+        assert_eq!(Interval::from(down), MINOR_SEVENTH);
+        assert_eq!(DirectedInterval::from(MINOR_SEVENTH), -down);
+        assert_eq!(-(-down), down);
+        assert!(!DirectedInterval::new(0).is_ascending());
+        assert!(!DirectedInterval::new(0).is_descending());
+    }
+
+    #[test]
+    fn test_interval_times() {
+        assert_eq!(MAJOR_THIRD.times(3), PERFECT_OCTAVE);
+        assert_eq!(PERFECT_FIFTH * 2, MAJOR_NINTH);
+        assert_eq!(PERFECT_UNISON.times(u8::MAX), PERFECT_UNISON);
+        assert_eq!(Interval::new(85).checked_times(3), Some(Interval::new(255)));
+        assert_eq!(Interval::new(128).checked_times(2), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interval_times_overflow() {
+        let _ = PERFECT_OCTAVE * 22;
+    }
+
+    #[test]
+    fn test_interval_ordering() {
+        let mut intervals = vec![
+            MAJOR_NINTH,
+            PERFECT_UNISON,
+            AUGMENTED_FOURTH,
+            MINOR_SECOND,
+            DIMINISHED_FIFTH,
+        ];
+        intervals.sort();
+        intervals.dedup();
+        assert_eq!(
+            intervals,
+            [PERFECT_UNISON, MINOR_SECOND, TRITONE, MAJOR_NINTH]
+        );
+        assert_eq!(MAJOR_THIRD.max(MINOR_THIRD), MAJOR_THIRD);
+
+        let counts = [PERFECT_FIFTH, PERFECT_FOURTH, PERFECT_FIFTH]
+            .into_iter()
+            .fold(std::collections::HashMap::new(), |mut counts, interval| {
+                *counts.entry(interval).or_insert(0) += 1;
+                counts
+            });
+        assert_eq!(counts[&PERFECT_FIFTH], 2);
+    }
+
+    #[test]
+    fn test_extended_constants() {
+        for (name, interval) in [
+            ("A1", AUGMENTED_UNISON),
+            ("A2", AUGMENTED_SECOND),
+            ("d4", DIMINISHED_FOURTH),
+            ("A4", AUGMENTED_FOURTH),
+            ("A5", AUGMENTED_FIFTH),
+            ("A6", AUGMENTED_SIXTH),
+            ("d7", DIMINISHED_SEVENTH),
+            ("m9", MINOR_NINTH),
+            ("M9", MAJOR_NINTH),
+            ("A9", AUGMENTED_NINTH),
+            ("m10", MINOR_TENTH),
+            ("M10", MAJOR_TENTH),
+            ("P11", PERFECT_ELEVENTH),
+            ("A11", AUGMENTED_ELEVENTH),
+            ("P12", PERFECT_TWELFTH),
+            ("m13", MINOR_THIRTEENTH),
+            ("M13", MAJOR_THIRTEENTH),
+            ("m14", MINOR_FOURTEENTH),
+            ("M14", MAJOR_FOURTEENTH),
+            ("P15", DOUBLE_OCTAVE),
+        ] {
+            assert_eq!(name.parse(), Ok(interval), "{name}");
+        }
+        assert_eq!(TRITONE, AUGMENTED_FOURTH);
+    }
+
+    #[test]
+    fn test_interval_display_and_parse() {
+        assert_eq!(PERFECT_UNISON.to_string(), "P1");
+        assert_eq!(DIMINISHED_FIFTH.to_string(), "d5");
+        assert_eq!(PERFECT_OCTAVE.to_string(), "P8");
+        assert_eq!(MAJOR_NINTH.to_string(), "M9");
+
+        assert_eq!("A4".parse(), Ok(DIMINISHED_FIFTH));
+        assert_eq!("d5".parse(), Ok(DIMINISHED_FIFTH));
+        assert_eq!("m9".parse(), Ok(MINOR_NINTH));
+        assert_eq!(
+            "P3".parse::<Interval>(),
+            Err(ParseIntervalError::InvalidInterval)
+        );
+    }
 }
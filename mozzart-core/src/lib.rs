@@ -2,17 +2,54 @@
 //!
 //! This crate provides fundamental music theory concepts and structures.
 
+mod acoustics;
+mod boxed;
+mod category;
 mod chord;
+mod degree;
+pub mod encode;
+mod forte;
+mod harmonics;
 mod interval;
+#[cfg(feature = "locale")]
+mod locale;
 mod octave;
+mod pcset;
 mod pitch;
+mod practice;
+mod quality;
 mod scale;
+mod spelling;
+mod stack;
+mod steps;
+mod tones;
+mod transpose;
+mod tuning;
+mod venn;
 
-pub use chord::{ChordPattern, ChordType};
-pub use interval::Interval;
-pub use octave::Octave;
-pub use pitch::Pitch;
+pub use acoustics::{beat_frequency, difference_tone};
+pub use boxed::{BoxedChordPattern, BoxedScalePattern, DynScale};
+pub use category::ScaleCategory;
+pub use chord::{Chord, ChordPattern, ChordType};
+pub use degree::ScaleDegree;
+pub use harmonics::harmonic_series;
+pub use interval::{DirectedInterval, Interval};
+#[cfg(feature = "locale")]
+pub use locale::{Locale, LocalizedNameDisplay, LocalizedPitchDisplay};
+pub use octave::{Octave, OctaveConvention};
+pub use pcset::PitchClassSet;
+pub use pitch::{ParsePitchError, Pitch, PitchDisplay};
+pub use quality::{IntervalQuality, ParseIntervalError, SpelledInterval};
 pub use scale::{Scale, ScalePattern, ScaleType};
+pub use spelling::{Accidental, Letter, SpelledPitch, spell_chord};
+pub use stack::Stack;
+pub use steps::{ParseStepsError, ScaleBuilder};
+pub use transpose::{
+    IterBy, PitchIteratorExt, Transposed, fold_into_range, fold_into_range_ordered, transpose_all,
+    transpose_slice_mut,
+};
+pub use tuning::TuningSystem;
+pub use venn::{Venn, VennRegion};
 
 pub mod constants {
     pub use crate::interval::constants::*;
@@ -0,0 +1,737 @@
+//! Localized names.
+//!
+//! This module provides functionality for naming musical objects in other
+//! languages, including:
+//! - Localized note names, such as Do Re Mi or the German H
+//! - Localized names of the scale and chord types shipped with Mozzart
+//! - Localized display of scales and chords
+//!
+//! It is only available with the `locale` feature.
+//!
+//! # Note Names
+//!
+//! English and German name notes after letters, with the German B written H and
+//! accidentals written as suffixes. French and Italian use the fixed-do syllables:
+//!
+//! ```text
+//! English   C    C#     Db     E    Eb    Bb    B
+//! German    C    Cis    Des    E    Es    B     H
+//! French    Do   Do#    Réb    Mi   Mib   Sib   Si
+//! Italian   Do   Do#    Reb    Mi   Mib   Sib   Si
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{Letter, Locale, SpelledPitch};
+//! use mozzart_core::constants::*;
+//!
+//! let b_flat = SpelledPitch::new(BFLAT3, Letter::B).unwrap();
+//! assert_eq!(b_flat.display_in(Locale::English).to_string(), "Bb3");
+//! assert_eq!(b_flat.display_in(Locale::German).to_string(), "B3");
+//! assert_eq!(b_flat.display_in(Locale::Italian).to_string(), "Sib3");
+//!
+//! assert_eq!(Locale::German.translate("major"), "Dur");
+//! assert_eq!(Locale::French.translate("harmonic minor"), "mineur harmonique");
+//! ```
+//!
+//! Scales and chords are displayed with their root and the name of their type:
+//!
+//! ```rust
+//! use mozzart_core::{Locale, Scale, ScaleType};
+//! use mozzart_core::constants::*;
+//!
+//! struct DorianScaleType;
+//! impl ScaleType for DorianScaleType {
+//!     fn name() -> &'static str {
+//!         "dorian"
+//!     }
+//! }
+//!
+//! let d_dorian = Scale::<DorianScaleType>::new(vec![D4, E4, F4, G4, A4, B4, C5]);
+//! assert_eq!(d_dorian.display_in(Locale::German).to_string(), "D4 Dorisch");
+//! assert_eq!(d_dorian.display_in(Locale::Italian).to_string(), "Re4 dorico");
+//! ```
+
+use std::fmt;
+
+use crate::{Accidental, Chord, ChordType, Letter, Pitch, Scale, ScaleType, SpelledPitch};
+
+/// Scale and chord names, in English, German, French and Italian.
+const WORDS: [[&str; 4]; 91] = [
+    ["major", "Dur", "majeur", "maggiore"],
+    ["minor", "Moll", "mineur", "minore"],
+    [
+        "natural minor",
+        "natürliches Moll",
+        "mineur naturel",
+        "minore naturale",
+    ],
+    [
+        "harmonic minor",
+        "harmonisches Moll",
+        "mineur harmonique",
+        "minore armonica",
+    ],
+    [
+        "melodic minor",
+        "melodisches Moll",
+        "mineur mélodique",
+        "minore melodica",
+    ],
+    [
+        "harmonic major",
+        "harmonisches Dur",
+        "majeur harmonique",
+        "maggiore armonica",
+    ],
+    [
+        "pentatonic major",
+        "Dur-Pentatonik",
+        "pentatonique majeure",
+        "pentatonica maggiore",
+    ],
+    [
+        "pentatonic minor",
+        "Moll-Pentatonik",
+        "pentatonique mineure",
+        "pentatonica minore",
+    ],
+    ["blues", "Blues", "blues", "blues"],
+    ["whole tone", "Ganzton", "par tons", "esatonale"],
+    ["chromatic", "chromatisch", "chromatique", "cromatica"],
+    ["diminished", "vermindert", "diminué", "diminuito"],
+    ["augmented", "übermäßig", "augmenté", "aumentato"],
+    [
+        "dominant seventh",
+        "Dominantseptakkord",
+        "septième de dominante",
+        "settima di dominante",
+    ],
+    ["suspended", "Vorhalt", "suspendu", "sospeso"],
+    [
+        "half diminished",
+        "halbvermindert",
+        "demi-diminué",
+        "semidiminuito",
+    ],
+    ["ionian", "Ionisch", "ionien", "ionico"],
+    ["dorian", "Dorisch", "dorien", "dorico"],
+    ["phrygian", "Phrygisch", "phrygien", "frigio"],
+    ["lydian", "Lydisch", "lydien", "lidio"],
+    ["mixolydian", "Mixolydisch", "mixolydien", "misolidio"],
+    ["aeolian", "Äolisch", "éolien", "eolio"],
+    ["locrian", "Lokrisch", "locrien", "locrio"],
+    ["dorian b2", "Dorisch b2", "dorien b2", "dorico b2"],
+    ["phrygian #6", "Phrygisch #6", "phrygien #6", "frigio #6"],
+    [
+        "lydian augmented",
+        "Lydisch übermäßig",
+        "lydien augmenté",
+        "lidio aumentato",
+    ],
+    [
+        "lydian dominant",
+        "Lydisch dominant",
+        "lydien dominant",
+        "lidio dominante",
+    ],
+    ["acoustic", "Akustisch", "acoustique", "acustica"],
+    [
+        "mixolydian b6",
+        "Mixolydisch b6",
+        "mixolydien b6",
+        "misolidio b6",
+    ],
+    [
+        "aeolian dominant",
+        "Äolisch dominant",
+        "éolien dominant",
+        "eolio dominante",
+    ],
+    ["locrian #2", "Lokrisch #2", "locrien #2", "locrio #2"],
+    [
+        "half-diminished",
+        "halbvermindert",
+        "demi-diminué",
+        "semidiminuito",
+    ],
+    ["altered", "Alteriert", "altéré", "alterata"],
+    [
+        "super locrian",
+        "Superlokrisch",
+        "super-locrien",
+        "superlocrio",
+    ],
+    ["locrian #6", "Lokrisch #6", "locrien #6", "locrio #6"],
+    ["ionian #5", "Ionisch #5", "ionien #5", "ionico #5"],
+    [
+        "ionian augmented",
+        "Ionisch übermäßig",
+        "ionien augmenté",
+        "ionico aumentato",
+    ],
+    ["dorian #4", "Dorisch #4", "dorien #4", "dorico #4"],
+    [
+        "ukrainian dorian",
+        "Ukrainisch-Dorisch",
+        "dorien ukrainien",
+        "dorico ucraino",
+    ],
+    [
+        "phrygian dominant",
+        "Phrygisch dominant",
+        "phrygien dominant",
+        "frigio dominante",
+    ],
+    [
+        "spanish phrygian",
+        "Spanisch-Phrygisch",
+        "phrygien espagnol",
+        "frigio spagnolo",
+    ],
+    ["lydian #2", "Lydisch #2", "lydien #2", "lidio #2"],
+    [
+        "ultralocrian",
+        "Ultralokrisch",
+        "ultralocrien",
+        "ultralocrio",
+    ],
+    [
+        "altered diminished",
+        "Alteriert vermindert",
+        "altéré diminué",
+        "alterata diminuita",
+    ],
+    [
+        "half-whole diminished",
+        "Halbton-Ganzton-vermindert",
+        "diminué demi-ton-ton",
+        "diminuita semitono-tono",
+    ],
+    [
+        "dominant diminished",
+        "Dominant-vermindert",
+        "diminué de dominante",
+        "diminuita di dominante",
+    ],
+    [
+        "whole-half diminished",
+        "Ganzton-Halbton-vermindert",
+        "diminué ton-demi-ton",
+        "diminuita tono-semitono",
+    ],
+    ["dorian b5", "Dorisch b5", "dorien b5", "dorico b5"],
+    ["phrygian b4", "Phrygisch b4", "phrygien b4", "frigio b4"],
+    ["lydian b3", "Lydisch b3", "lydien b3", "lidio b3"],
+    [
+        "mixolydian b2",
+        "Mixolydisch b2",
+        "mixolydien b2",
+        "misolidio b2",
+    ],
+    [
+        "lydian augmented #2",
+        "Lydisch übermäßig #2",
+        "lydien augmenté #2",
+        "lidio aumentato #2",
+    ],
+    ["locrian bb7", "Lokrisch bb7", "locrien bb7", "locrio bb7"],
+    [
+        "bebop dominant",
+        "Bebop-Dominant",
+        "bebop de dominante",
+        "bebop di dominante",
+    ],
+    ["bebop major", "Bebop-Dur", "bebop majeur", "bebop maggiore"],
+    [
+        "bebop melodic minor",
+        "Bebop-Melodisch-Moll",
+        "bebop mineur mélodique",
+        "bebop minore melodica",
+    ],
+    ["prometheus", "Prometheus", "Prométhée", "Prometeo"],
+    ["mystic", "Mystisch", "mystique", "mistica"],
+    ["blues major", "Dur-Blues", "blues majeur", "blues maggiore"],
+    ["major blues", "Dur-Blues", "blues majeur", "blues maggiore"],
+    ["hirajoshi", "Hirajoshi", "hirajoshi", "hirajoshi"],
+    ["in-sen", "In-Sen", "in-sen", "in-sen"],
+    ["yo", "Yo", "yo", "yo"],
+    ["egyptian", "Ägyptisch", "égyptien", "egiziana"],
+    [
+        "suspended pentatonic",
+        "Suspendierte Pentatonik",
+        "pentatonique suspendue",
+        "pentatonica sospesa",
+    ],
+    [
+        "dominant ninth",
+        "Dominantnonenakkord",
+        "neuvième de dominante",
+        "nona di dominante",
+    ],
+    [
+        "major ninth",
+        "Dur-Nonenakkord",
+        "neuvième majeure",
+        "nona maggiore",
+    ],
+    [
+        "minor ninth",
+        "Moll-Nonenakkord",
+        "neuvième mineure",
+        "nona minore",
+    ],
+    [
+        "dominant eleventh",
+        "Dominant-Undezimakkord",
+        "onzième de dominante",
+        "undicesima di dominante",
+    ],
+    [
+        "major eleventh",
+        "Dur-Undezimakkord",
+        "onzième majeure",
+        "undicesima maggiore",
+    ],
+    [
+        "minor eleventh",
+        "Moll-Undezimakkord",
+        "onzième mineure",
+        "undicesima minore",
+    ],
+    [
+        "dominant thirteenth",
+        "Dominant-Tredezimakkord",
+        "treizième de dominante",
+        "tredicesima di dominante",
+    ],
+    [
+        "major thirteenth",
+        "Dur-Tredezimakkord",
+        "treizième majeure",
+        "tredicesima maggiore",
+    ],
+    [
+        "minor thirteenth",
+        "Moll-Tredezimakkord",
+        "treizième mineure",
+        "tredicesima minore",
+    ],
+    [
+        "dominant seventh flat ninth",
+        "Dominantseptakkord mit kleiner None",
+        "septième de dominante neuvième mineure",
+        "settima di dominante con nona minore",
+    ],
+    [
+        "dominant seventh sharp ninth",
+        "Dominantseptakkord mit übermäßiger None",
+        "septième de dominante neuvième augmentée",
+        "settima di dominante con nona aumentata",
+    ],
+    [
+        "dominant seventh sharp eleventh",
+        "Dominantseptakkord mit übermäßiger Undezime",
+        "septième de dominante onzième augmentée",
+        "settima di dominante con undicesima aumentata",
+    ],
+    [
+        "dominant seventh flat thirteenth",
+        "Dominantseptakkord mit kleiner Tredezime",
+        "septième de dominante treizième mineure",
+        "settima di dominante con tredicesima minore",
+    ],
+    [
+        "altered dominant",
+        "alterierter Dominantakkord",
+        "dominante altérée",
+        "dominante alterata",
+    ],
+    [
+        "suspended second",
+        "Sekundvorhalt",
+        "suspendu de seconde",
+        "sospeso di seconda",
+    ],
+    [
+        "suspended fourth",
+        "Quartvorhalt",
+        "suspendu de quarte",
+        "sospeso di quarta",
+    ],
+    [
+        "dominant seventh suspended fourth",
+        "Dominantseptakkord mit Quartvorhalt",
+        "septième de dominante suspendue de quarte",
+        "settima di dominante sospesa di quarta",
+    ],
+    [
+        "added ninth",
+        "Dur mit None",
+        "neuvième ajoutée",
+        "nona aggiunta",
+    ],
+    [
+        "major sixth",
+        "Dur mit Sexte",
+        "sixte majeure",
+        "sesta maggiore",
+    ],
+    [
+        "minor sixth",
+        "Moll mit Sexte",
+        "sixte mineure",
+        "sesta minore",
+    ],
+    [
+        "six nine",
+        "Sexte-None-Akkord",
+        "sixte-neuvième",
+        "sesta e nona",
+    ],
+    ["power", "Powerchord", "power chord", "power chord"],
+    [
+        "quartal triad",
+        "Quarten-Dreiklang",
+        "triade par quartes",
+        "triade per quarte",
+    ],
+    [
+        "quartal tetrad",
+        "Quarten-Vierklang",
+        "tétrade par quartes",
+        "tetrade per quarte",
+    ],
+    [
+        "quintal triad",
+        "Quinten-Dreiklang",
+        "triade par quintes",
+        "triade per quinte",
+    ],
+    [
+        "quintal tetrad",
+        "Quinten-Vierklang",
+        "tétrade par quintes",
+        "tetrade per quinte",
+    ],
+];
+
+/// Represents a language for musical names.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::Locale;
+///
+/// assert_eq!(Locale::Italian.translate("minor"), "minore");
+/// assert_eq!(Locale::default(), Locale::English);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// English names, with letters and `#`/`b` accidentals.
+    #[default]
+    English,
+    /// German names, with H for B natural and `is`/`es` suffixes.
+    German,
+    /// French names, with the fixed-do syllables.
+    French,
+    /// Italian names, with the fixed-do syllables.
+    Italian,
+}
+
+impl Locale {
+    /// Returns the name of a natural note in this locale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Letter, Locale};
+    ///
+    /// assert_eq!(Locale::German.letter_name(Letter::B), "H");
+    /// assert_eq!(Locale::French.letter_name(Letter::D), "Ré");
+    /// assert_eq!(Locale::Italian.letter_name(Letter::G), "Sol");
+    /// ```
+    pub const fn letter_name(&self, letter: Letter) -> &'static str {
+        let names = match self {
+            Locale::English => ["C", "D", "E", "F", "G", "A", "B"],
+            Locale::German => ["C", "D", "E", "F", "G", "A", "H"],
+            Locale::French => ["Do", "Ré", "Mi", "Fa", "Sol", "La", "Si"],
+            Locale::Italian => ["Do", "Re", "Mi", "Fa", "Sol", "La", "Si"],
+        };
+        names[letter.index() as usize]
+    }
+
+    /// Returns the translation of the English name of a scale or chord type, or
+    /// the name itself if there is no translation.
+    ///
+    /// Every scale and chord type name and alias shipped with Mozzart has a
+    /// translation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Locale;
+    ///
+    /// assert_eq!(Locale::German.translate("natural minor"), "natürliches Moll");
+    /// assert_eq!(Locale::English.translate("natural minor"), "natural minor");
+    /// assert_eq!(Locale::German.translate("dorian"), "Dorisch");
+    /// assert_eq!(Locale::German.translate("cluster"), "cluster");
+    /// ```
+    pub fn translate<'a>(&self, word: &'a str) -> &'a str {
+        WORDS
+            .iter()
+            .find(|words| words[0] == word)
+            .map_or(word, |words| words[*self as usize])
+    }
+
+    /// Writes the name of a spelled note, without its octave, in this locale.
+    fn write_note(&self, f: &mut fmt::Formatter<'_>, pitch: SpelledPitch) -> fmt::Result {
+        let (letter, accidental) = (pitch.letter(), pitch.accidental());
+        if *self != Locale::German {
+            write!(f, "{}", self.letter_name(letter))?;
+            return fmt::Display::fmt(&accidental, f);
+        }
+
+        // German flats drop the "e" of the suffix after a vowel, and B flat is B
+        let name = match (letter, accidental) {
+            (Letter::B, Accidental::Flat) => return write!(f, "B"),
+            (Letter::B, Accidental::DoubleFlat) => return write!(f, "Heses"),
+            (Letter::E | Letter::A, Accidental::Flat) => "s",
+            (Letter::E | Letter::A, Accidental::DoubleFlat) => "ses",
+            (_, Accidental::DoubleFlat) => "eses",
+            (_, Accidental::Flat) => "es",
+            (_, Accidental::Natural) => "",
+            (_, Accidental::Sharp) => "is",
+            (_, Accidental::DoubleSharp) => "isis",
+        };
+        write!(f, "{}{}", self.letter_name(letter), name)
+    }
+}
+
+impl SpelledPitch {
+    /// Returns an object that displays this pitch with the note names of the
+    /// given locale.
+    ///
+    /// The octave number follows scientific pitch notation, as for the
+    /// [`fmt::Display`] implementation of [`SpelledPitch`]. English, French and
+    /// Italian names use Unicode accidentals with the alternate flag (`{:#}`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Letter, Locale, SpelledPitch};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let f_sharp = SpelledPitch::new(FSHARP4, Letter::F).unwrap();
+    /// assert_eq!(f_sharp.display_in(Locale::German).to_string(), "Fis4");
+    /// assert_eq!(format!("{:#}", f_sharp.display_in(Locale::French)), "Fa♯4");
+    /// ```
+    #[inline]
+    pub const fn display_in(self, locale: Locale) -> LocalizedPitchDisplay {
+        LocalizedPitchDisplay {
+            pitch: self,
+            locale,
+        }
+    }
+}
+
+/// Helper struct for displaying a spelled pitch in a given locale.
+///
+/// This struct is created by [`SpelledPitch::display_in`].
+#[derive(Debug, Clone, Copy)]
+pub struct LocalizedPitchDisplay {
+    pitch: SpelledPitch,
+    locale: Locale,
+}
+
+impl fmt::Display for LocalizedPitchDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.locale.write_note(f, self.pitch)?;
+        if !self.pitch.pitch().is_canonical() {
+            write!(f, "{}", self.pitch.octave_number())?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: ScaleType> Scale<S> {
+    /// Returns an object that displays this scale, its root and the name of its
+    /// type, in the given locale.
+    ///
+    /// The root is spelled with its default spelling, as by [`Pitch::spelled`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale has no pitches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Locale, Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let b_major = Scale::<MajorScaleType>::new(vec![B3, CSHARP4, DSHARP4, E4, FSHARP4, GSHARP4, ASHARP4]);
+    /// assert_eq!(b_major.display_in(Locale::German).to_string(), "H3 Dur");
+    /// assert_eq!(b_major.display_in(Locale::French).to_string(), "Si3 majeur");
+    /// ```
+    #[inline]
+    pub fn display_in(&self, locale: Locale) -> LocalizedNameDisplay {
+        LocalizedNameDisplay {
+            root: self.root(),
+            name: self.name(),
+            locale,
+        }
+    }
+}
+
+impl<C: ChordType> Chord<C> {
+    /// Returns an object that displays this chord, its root and the name of its
+    /// type, in the given locale.
+    ///
+    /// The root is spelled with its default spelling, as by [`Pitch::spelled`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Chord, ChordType, Locale};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MinorTriadType;
+    /// impl ChordType for MinorTriadType {
+    ///     fn name() -> &'static str {
+    ///         "minor"
+    ///     }
+    /// }
+    ///
+    /// let a_minor = Chord::<MinorTriadType>::new(A3, vec![A3, C4, E4]);
+    /// assert_eq!(a_minor.display_in(Locale::German).to_string(), "A3 Moll");
+    /// assert_eq!(a_minor.display_in(Locale::Italian).to_string(), "La3 minore");
+    /// ```
+    #[inline]
+    pub fn display_in(&self, locale: Locale) -> LocalizedNameDisplay {
+        LocalizedNameDisplay {
+            root: self.root(),
+            name: self.name(),
+            locale,
+        }
+    }
+}
+
+/// Helper struct for displaying a scale or a chord in a given locale.
+///
+/// This struct is created by [`Scale::display_in`] and [`Chord::display_in`].
+#[derive(Debug, Clone, Copy)]
+pub struct LocalizedNameDisplay {
+    root: Pitch,
+    name: &'static str,
+    locale: Locale,
+}
+
+impl fmt::Display for LocalizedNameDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let root = self.root.spelled();
+        write!(
+            f,
+            "{} {}",
+            root.display_in(self.locale),
+            self.locale.translate(self.name)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pitch;
+    use crate::constants::*;
+
+    fn name(pitch: Pitch, letter: Letter, locale: Locale) -> String {
+        let spelled = SpelledPitch::new(pitch, letter).unwrap();
+        spelled.display_in(locale).to_string()
+    }
+
+    #[test]
+    fn test_english_matches_display() {
+        for pitch in Pitch::all() {
+            let spelled = pitch.spelled();
+            assert_eq!(
+                spelled.display_in(Locale::English).to_string(),
+                spelled.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_german_names() {
+        assert_eq!(name(C4, Letter::C, Locale::German), "C4");
+        assert_eq!(name(CSHARP4, Letter::C, Locale::German), "Cis4");
+        assert_eq!(name(DFLAT4, Letter::D, Locale::German), "Des4");
+        assert_eq!(name(EFLAT4, Letter::E, Locale::German), "Es4");
+        assert_eq!(name(AFLAT4, Letter::A, Locale::German), "As4");
+        assert_eq!(name(BFLAT4, Letter::B, Locale::German), "B4");
+        assert_eq!(name(B4, Letter::B, Locale::German), "H4");
+        assert_eq!(name(D4, Letter::C, Locale::German), "Cisis4");
+        assert_eq!(name(C4, Letter::D, Locale::German), "Deses4");
+        assert_eq!(name(A4, Letter::B, Locale::German), "Heses4");
+        assert_eq!(name(FSHARP, Letter::G, Locale::German), "Ges");
+    }
+
+    #[test]
+    fn test_solfege_names() {
+        assert_eq!(name(G4, Letter::G, Locale::Italian), "Sol4");
+        assert_eq!(name(EFLAT4, Letter::E, Locale::French), "Mib4");
+        assert_eq!(name(DFLAT4, Letter::D, Locale::French), "Réb4");
+        assert_eq!(name(C4, Letter::B, Locale::Italian), "Si#3");
+    }
+
+    #[test]
+    fn test_translate() {
+        for words in WORDS {
+            assert_eq!(Locale::English.translate(words[0]), words[0]);
+            assert_eq!(Locale::Italian.translate(words[0]), words[3]);
+        }
+        assert_eq!(Locale::French.translate("unknown"), "unknown");
+    }
+
+    #[test]
+    fn test_display_in() {
+        struct MyScaleType;
+        impl ScaleType for MyScaleType {
+            fn name() -> &'static str {
+                "whole tone"
+            }
+        }
+
+        struct MyChordType;
+        impl ChordType for MyChordType {
+            fn name() -> &'static str {
+                "dominant ninth"
+            }
+        }
+
+        let scale = Scale::<MyScaleType>::new(vec![ASHARP3, C4, D4, E4, FSHARP4, GSHARP4]);
+        assert_eq!(
+            scale.display_in(Locale::English).to_string(),
+            scale.to_string()
+        );
+        assert_eq!(scale.display_in(Locale::German).to_string(), "Ais3 Ganzton");
+
+        let chord = Chord::<MyChordType>::new(G3, vec![G3, B3, D4, F4, A4]);
+        assert_eq!(
+            chord.display_in(Locale::English).to_string(),
+            chord.to_string()
+        );
+        assert_eq!(
+            chord.display_in(Locale::French).to_string(),
+            "Sol3 neuvième de dominante"
+        );
+    }
+}
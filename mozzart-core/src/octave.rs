@@ -4,6 +4,8 @@
 //! - Octave representation and validation
 //! - Octave constants for all supported octaves
 //! - Octave range operations
+//! - The pitches of each octave
+//! - The frequency range of each octave
 //!
 //! # Octave System
 //!
@@ -66,8 +68,8 @@
 
 use std::fmt;
 
-use crate::Pitch;
 use crate::constants::*;
+use crate::{Pitch, TuningSystem};
 
 /// Represents a musical octave.
 ///
@@ -110,6 +112,23 @@ impl Octave {
         Self(value)
     }
 
+    /// Returns an iterator over every supported octave, from OC up to O9.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Octave;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(Octave::all().len(), 11);
+    /// assert_eq!(Octave::all().next(), Some(OC));
+    /// assert_eq!(Octave::all().last(), Some(O9));
+    /// ```
+    #[inline]
+    pub fn all() -> impl DoubleEndedIterator<Item = Octave> + ExactSizeIterator {
+        OCTAVES.into_iter()
+    }
+
     /// Returns the value of this octave.
     ///
     /// # Examples
@@ -185,6 +204,8 @@ impl Octave {
 
     /// Returns an array of pitches for the given octave.
     ///
+    /// The pitches are the ones of the matching `PITCHES` constant, from C to B.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -195,10 +216,147 @@ impl Octave {
     /// let pitches = O4.pitches();
     /// assert_eq!(pitches[0], C4);
     /// assert_eq!(pitches[11], B4);
+    /// assert_eq!(OC.pitches(), PITCHES);
+    /// ```
+    #[inline]
+    pub const fn pitches(self) -> [Pitch; crate::constants::SEMITONES_PER_OCTAVE as usize] {
+        match self.0 {
+            -1 => PITCHES,
+            0 => PITCHES0,
+            1 => PITCHES1,
+            2 => PITCHES2,
+            3 => PITCHES3,
+            4 => PITCHES4,
+            5 => PITCHES5,
+            6 => PITCHES6,
+            7 => PITCHES7,
+            8 => PITCHES8,
+            _ => PITCHES9,
+        }
+    }
+
+    /// Returns whether the given pitch is in this octave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Octave;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert!(O4.contains(C4));
+    /// assert!(O4.contains(B4));
+    /// assert!(!O4.contains(C5));
+    /// assert!(OC.contains(C));
+    /// ```
+    #[inline]
+    pub const fn contains(&self, pitch: Pitch) -> bool {
+        pitch.octave().0 == self.0
+    }
+
+    /// Returns the frequency range of this octave in Hz in the given tuning
+    /// system, from its C (inclusive) to the C of the next octave (exclusive).
+    ///
+    /// A4 is tuned to the concert pitch and C4 a major sixth below it, so the
+    /// bounds of the octaves depend on the size of the major sixth in the tuning.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Octave, TuningSystem};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let (low, high) = O4.frequency_range(TuningSystem::JustIntonation);
+    /// assert_eq!((low, high), (264.0, 528.0));
+    ///
+    /// let (low, high) = O4.frequency_range(TuningSystem::EqualTemperament);
+    /// assert!((low - C4.frequency()).abs() < 1e-9);
+    /// assert!((high - C5.frequency()).abs() < 1e-9);
+    /// ```
+    pub fn frequency_range(&self, tuning: TuningSystem) -> (f64, f64) {
+        let c4 = CONCERT_PITCH / tuning.ratio(MAJOR_SIXTH);
+        let low = c4 * 2f64.powi(self.0 as i32 - O4.0 as i32);
+        (low, low * 2.0)
+    }
+}
+
+/// Represents a convention for numbering octaves.
+///
+/// Different DAWs and manufacturers number octaves differently. The pitch itself
+/// is always stored as its MIDI note number; the convention only changes the
+/// octave number used when displaying and parsing pitch names.
+///
+/// ```text
+/// MIDI note   Scientific   Yamaha
+/// 0           C-1          C-2
+/// 60          C4           C3
+/// 127         G9           G8
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::OctaveConvention;
+/// use mozzart_core::constants::*;
+///
+/// assert_eq!(OctaveConvention::Scientific.octave_number(O4), 4);
+/// assert_eq!(OctaveConvention::Yamaha.octave_number(O4), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OctaveConvention {
+    /// Scientific pitch notation, where middle C (MIDI 60) is C4.
+    ///
+    /// This is the convention used by Roland and by the rest of this crate.
+    #[default]
+    Scientific,
+    /// The Yamaha convention, where middle C (MIDI 60) is C3.
+    Yamaha,
+}
+
+impl OctaveConvention {
+    /// Returns the number this convention uses for the given octave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::OctaveConvention;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(OctaveConvention::Scientific.octave_number(OC), -1);
+    /// assert_eq!(OctaveConvention::Yamaha.octave_number(OC), -2);
+    /// ```
+    #[inline]
+    pub const fn octave_number(&self, octave: Octave) -> i8 {
+        octave.value() - self.offset()
+    }
+
+    /// Returns the octave this convention denotes with the given number,
+    /// or `None` if the number is outside the supported octaves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::OctaveConvention;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(OctaveConvention::Scientific.octave(4), Some(O4));
+    /// assert_eq!(OctaveConvention::Yamaha.octave(3), Some(O4));
+    /// assert_eq!(OctaveConvention::Yamaha.octave(9), None);
     /// ```
     #[inline]
-    pub fn pitches(self) -> [Pitch; crate::constants::SEMITONES_PER_OCTAVE as usize] {
-        PITCHES.map(|pitch| self.to_pitch(pitch))
+    pub const fn octave(&self, number: i8) -> Option<Octave> {
+        match number.checked_add(self.offset()) {
+            Some(value) if value >= OC.0 && value <= O9.0 => Some(Octave(value)),
+            _ => None,
+        }
+    }
+
+    /// Returns how many octaves this convention numbers below scientific notation.
+    #[inline]
+    const fn offset(&self) -> i8 {
+        match self {
+            OctaveConvention::Scientific => 0,
+            OctaveConvention::Yamaha => 1,
+        }
     }
 }
 
@@ -255,7 +413,7 @@ mod tests {
 
     #[test]
     fn test_octaves() {
-        for (i, octave) in constants::OCTAVES.iter().enumerate() {
+        for (i, octave) in Octave::all().enumerate() {
             assert_eq!(octave.0, i as i8 - 1);
             if i == 0 {
                 assert!(octave.is_canonical());
@@ -265,6 +423,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_octave_convention() {
+        for octave in Octave::all() {
+            for convention in [OctaveConvention::Scientific, OctaveConvention::Yamaha] {
+                let number = convention.octave_number(octave);
+                assert_eq!(convention.octave(number), Some(octave));
+            }
+        }
+
+        assert_eq!(OctaveConvention::Scientific.octave(-2), None);
+        assert_eq!(OctaveConvention::Scientific.octave(10), None);
+        assert_eq!(OctaveConvention::Yamaha.octave(-2), Some(OC));
+        assert_eq!(OctaveConvention::Yamaha.octave(i8::MAX), None);
+    }
+
+    #[test]
+    fn test_all_pitches_by_octave() {
+        let by_octave: Vec<_> = Octave::all()
+            .flat_map(|octave| octave.pitches())
+            .take_while(|pitch| *pitch <= G9)
+            .collect();
+        assert!(by_octave.into_iter().eq(Pitch::all()));
+        assert!(Pitch::all().all(|pitch| pitch.octave().contains(pitch)));
+    }
+
     #[test]
     fn test_update_octave() {
         let pitch = O4.update_octave(C1);
@@ -282,5 +465,43 @@ mod tests {
         let pitches = O4.pitches();
         assert_eq!(pitches[0], C4);
         assert_eq!(pitches[11], B4);
+
+        for octave in Octave::all() {
+            let expected = PITCHES.map(|pitch| octave.to_pitch(pitch));
+            assert_eq!(octave.pitches(), expected);
+        }
+    }
+
+    #[test]
+    fn test_frequency_range() {
+        for tuning in [
+            TuningSystem::EqualTemperament,
+            TuningSystem::JustIntonation,
+            TuningSystem::Pythagorean,
+        ] {
+            // Octaves are adjacent and each spans a doubling of frequency
+            for pair in constants::OCTAVES.windows(2) {
+                let (low, high) = pair[0].frequency_range(tuning);
+                assert_eq!(high, low * 2.0);
+                assert_eq!(pair[1].frequency_range(tuning).0, high);
+            }
+        }
+
+        let (low, high) = O4.frequency_range(TuningSystem::Pythagorean);
+        assert_eq!(low, 440.0 * 16.0 / 27.0);
+        assert!(low < A4.frequency() && A4.frequency() < high);
+        assert_eq!(OC.frequency_range(TuningSystem::JustIntonation).0, 8.25);
+    }
+
+    #[test]
+    fn test_contains() {
+        for octave in Octave::all() {
+            for pitch in octave.pitches() {
+                assert!(octave.contains(pitch));
+            }
+        }
+        assert!(!O3.contains(C4));
+        assert!(O9.contains(G9));
+        assert!(!O8.contains(G9));
     }
 }
@@ -0,0 +1,769 @@
+//! Pitch-class sets.
+//!
+//! This module provides functionality for working with pitch-class sets, including:
+//! - Pitch-class set representation and manipulation
+//! - Transposition and inversion of sets
+//! - Normal form and prime form computation
+//! - Interval-class vectors
+//! - Transpositional and inversional symmetry
+//! - Common tones between sets
+//! - Z-relation between sets
+//! - Subset and superset queries
+//!
+//! # Pitch Classes
+//!
+//! A pitch-class set is an unordered collection of pitch classes, the canonical
+//! pitches C through B. Pitches in any octave are reduced to their pitch class when
+//! added to a set. In set theory pitch classes are usually written as integers:
+//!
+//! ```text
+//! C  C#  D  D#  E  F  F#  G  G#  A  A#  B
+//! 0  1   2  3   4  5  6   7  8   9  10  11
+//! ```
+//!
+//! # Normal Form and Prime Form
+//!
+//! The normal form of a set is the ordering of its pitch classes that spans the
+//! smallest interval from first to last. Ties are broken by the smallest interval
+//! from the first pitch class to the second, then to the third, and so on, following
+//! Forte. The prime form is the normal form of the set or of its inversion,
+//! whichever is more compact, transposed to start on C. Sets sharing a prime form
+//! belong to the same set class:
+//!
+//! ```text
+//! Set          Normal form   Inversion     Prime form
+//! {E, G, C}    [C, E, G]     [F, G#, C]    (0, 3, 7)
+//! {D, F, A}    [D, F, A]     [D#, G, A#]   (0, 3, 7)
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::PitchClassSet;
+//! use mozzart_core::constants::*;
+//!
+//! let major: PitchClassSet = [C4, E4, G4].into_iter().collect();
+//! let minor: PitchClassSet = [A3, C4, E4].into_iter().collect();
+//!
+//! assert_eq!(major.normal_form(), [C, E, G]);
+//! assert_eq!(minor.normal_form(), [A, C, E]);
+//!
+//! // Major and minor triads are inversions of each other
+//! assert_eq!(major.prime_form(), minor.prime_form());
+//! assert_eq!(major.prime_form().pitches(), [C, DSHARP, G]);
+//! ```
+
+use std::borrow::Borrow;
+
+use crate::{Interval, Pitch};
+
+/// The number of pitch classes.
+const SEMITONES: u8 = crate::constants::SEMITONES_PER_OCTAVE;
+
+/// Represents a set of pitch classes.
+///
+/// Pitches are reduced to their canonical form when they are added, so a set holds
+/// at most twelve pitch classes.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::PitchClassSet;
+/// use mozzart_core::constants::*;
+///
+/// let set: PitchClassSet = [C4, G4, C5].into_iter().collect();
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(G2));
+/// assert_eq!(set.pitches(), [C, G]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PitchClassSet(u16);
+
+impl PitchClassSet {
+    /// Creates a new empty pitch-class set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    ///
+    /// let set = PitchClassSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Adds the pitch class of a pitch to this set.
+    ///
+    /// Returns whether the pitch class was newly added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let mut set = PitchClassSet::new();
+    /// assert!(set.insert(E4));
+    /// assert!(!set.insert(E5));
+    /// assert_eq!(set.pitches(), [E]);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, pitch: Pitch) -> bool {
+        let contains = self.contains(pitch);
+        self.0 |= Self::bit(pitch);
+        !contains
+    }
+
+    /// Returns whether this set contains the pitch class of a pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let set: PitchClassSet = [C4, E4, G4].into_iter().collect();
+    /// assert!(set.contains(E));
+    /// assert!(set.contains(E2));
+    /// assert!(!set.contains(F4));
+    /// ```
+    #[inline]
+    pub const fn contains(&self, pitch: Pitch) -> bool {
+        self.0 & Self::bit(pitch) != 0
+    }
+
+    /// Returns the number of pitch classes in this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let set: PitchClassSet = [C4, E4, G4, C5].into_iter().collect();
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns whether this set contains no pitch classes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert!(PitchClassSet::new().is_empty());
+    /// assert!(!PitchClassSet::from_iter([C4]).is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the pitch classes of this set in ascending order from C.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let set: PitchClassSet = [G4, B4, D5, F5].into_iter().collect();
+    /// assert_eq!(set.pitches(), [D, F, G, B]);
+    /// ```
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.classes().map(Pitch::new).collect()
+    }
+
+    /// Returns this set with every pitch class transposed by an interval.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let set: PitchClassSet = [G4, B4, D5].into_iter().collect();
+    /// assert_eq!(set.transpose(PERFECT_FOURTH).pitches(), [C, E, G]);
+    /// ```
+    pub fn transpose(&self, interval: Interval) -> Self {
+        self.classes()
+            .map(|class| Pitch::new((class + interval.semitones() % SEMITONES) % SEMITONES))
+            .collect()
+    }
+
+    /// Returns the inversion of this set around C.
+    ///
+    /// Every pitch class n is mapped to 12 - n, so C stays C, C# becomes B,
+    /// D becomes A#, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let set: PitchClassSet = [C4, E4, G4].into_iter().collect();
+    /// assert_eq!(set.invert().pitches(), [C, F, GSHARP]);
+    /// ```
+    pub fn invert(&self) -> Self {
+        self.classes()
+            .map(|class| Pitch::new((SEMITONES - class) % SEMITONES))
+            .collect()
+    }
+
+    /// Returns the normal form of this set.
+    ///
+    /// The normal form is the rotation of the ascending pitch classes spanning the
+    /// smallest interval. Ties are broken by the smallest interval from the first
+    /// pitch class to the second, then to the third, and so on. Sets whose rotations
+    /// remain tied start on the lowest pitch class.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let dominant: PitchClassSet = [G3, B3, D4, F4].into_iter().collect();
+    /// assert_eq!(dominant.normal_form(), [B, D, F, G]);
+    ///
+    /// // A diminished seventh chord is symmetrical
+    /// let diminished: PitchClassSet = [B3, D4, F4, GSHARP4].into_iter().collect();
+    /// assert_eq!(diminished.normal_form(), [D, F, GSHARP, B]);
+    /// ```
+    pub fn normal_form(&self) -> Vec<Pitch> {
+        let classes: Vec<u8> = self.classes().collect();
+        let start = Self::most_packed(&classes).map_or(0, |(start, _)| start);
+        classes[start..]
+            .iter()
+            .chain(&classes[..start])
+            .map(|&class| Pitch::new(class))
+            .collect()
+    }
+
+    /// Returns the prime form of this set.
+    ///
+    /// The prime form is the more packed normal form of this set and of its
+    /// inversion, transposed to start on C. Its pitch classes, listed in
+    /// ascending order, are the prime form in integer notation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// // The dominant seventh chord belongs to set class (0, 2, 5, 8)
+    /// let dominant: PitchClassSet = [G3, B3, D4, F4].into_iter().collect();
+    /// assert_eq!(dominant.prime_form().pitches(), [C, D, F, GSHARP]);
+    /// ```
+    pub fn prime_form(&self) -> Self {
+        let classes: Vec<u8> = self.classes().collect();
+        let inverted: Vec<u8> = self.invert().classes().collect();
+
+        let packed = match (Self::most_packed(&classes), Self::most_packed(&inverted)) {
+            (Some((_, original)), Some((_, inversion))) => original.min(inversion),
+            _ => return Self::new(),
+        };
+
+        // The packing holds the span first, followed by the remaining intervals
+        // from the first pitch class.
+        let mut set = Self(1);
+        for &interval in &packed {
+            set.0 |= 1 << interval;
+        }
+        set
+    }
+
+    /// Returns the interval-class vector of this set.
+    ///
+    /// The vector counts the intervals between every pair of pitch classes in the
+    /// set, by interval class. An interval and its inversion belong to the same
+    /// interval class, so the six entries count minor seconds or major sevenths,
+    /// major seconds or minor sevenths, minor thirds or major sixths, major thirds
+    /// or minor sixths, perfect fourths or fifths, and tritones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let triad: PitchClassSet = [C4, E4, G4].into_iter().collect();
+    /// assert_eq!(triad.interval_class_vector(), [0, 0, 1, 1, 1, 0]);
+    ///
+    /// let major: PitchClassSet = [C4, D4, E4, F4, G4, A4, B4].into_iter().collect();
+    /// assert_eq!(major.interval_class_vector(), [2, 5, 4, 3, 6, 1]);
+    /// ```
+    pub fn interval_class_vector(&self) -> [u8; 6] {
+        let classes: Vec<u8> = self.classes().collect();
+        let mut vector = [0; 6];
+        for (i, &low) in classes.iter().enumerate() {
+            for &high in &classes[i + 1..] {
+                let interval = high - low;
+                let class = interval.min(SEMITONES - interval);
+                vector[class as usize - 1] += 1;
+            }
+        }
+        vector
+    }
+
+    /// Returns the intervals transposing this set onto itself, in ascending order.
+    ///
+    /// Every set maps onto itself under the perfect unison. Sets with further
+    /// transpositional symmetries, such as the whole-tone scale and the
+    /// diminished scale, are Messiaen's modes of limited transposition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let augmented: PitchClassSet = [C4, E4, GSHARP4].into_iter().collect();
+    /// assert_eq!(
+    ///     augmented.transpositional_symmetries(),
+    ///     [PERFECT_UNISON, MAJOR_THIRD, MINOR_SIXTH]
+    /// );
+    ///
+    /// let major: PitchClassSet = [C4, E4, G4].into_iter().collect();
+    /// assert_eq!(major.transpositional_symmetries(), [PERFECT_UNISON]);
+    /// ```
+    pub fn transpositional_symmetries(&self) -> Vec<Interval> {
+        (0..SEMITONES)
+            .map(Interval::new)
+            .filter(|&interval| self.transpose(interval) == *self)
+            .collect()
+    }
+
+    /// Returns the inversions mapping this set onto itself, in ascending order.
+    ///
+    /// Each inversion is identified by the interval n of the operation TnI, which
+    /// inverts the set around C and then transposes it by n, mapping every pitch
+    /// class x to n - x.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// // The major scale is symmetrical around D
+    /// let major: PitchClassSet = [C4, D4, E4, F4, G4, A4, B4].into_iter().collect();
+    /// assert_eq!(major.inversional_symmetries(), [MAJOR_THIRD]);
+    ///
+    /// let dominant: PitchClassSet = [G3, B3, D4, F4].into_iter().collect();
+    /// assert!(dominant.inversional_symmetries().is_empty());
+    /// ```
+    pub fn inversional_symmetries(&self) -> Vec<Interval> {
+        let inversion = self.invert();
+        (0..SEMITONES)
+            .map(Interval::new)
+            .filter(|&interval| inversion.transpose(interval) == *self)
+            .collect()
+    }
+
+    /// Returns the pitch classes shared by this set and another one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c_major: PitchClassSet = [C4, E4, G4].into_iter().collect();
+    /// let e_minor: PitchClassSet = [E4, G4, B4].into_iter().collect();
+    /// assert_eq!(c_major.common_tones(&e_minor).pitches(), [E, G]);
+    /// ```
+    #[inline]
+    pub const fn common_tones(&self, other: &PitchClassSet) -> PitchClassSet {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the pitch classes shared by this set and its transposition by an
+    /// interval.
+    ///
+    /// The shared pitch classes are those of this set that are also reached by
+    /// transposing it, so the result is a subset of this set. Apart from the
+    /// unison and the tritone, the number of common tones equals the entry of the
+    /// interval class in the [interval-class vector](Self::interval_class_vector).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// // Modulating a major scale up a fifth keeps six of its seven notes
+    /// let c_major: PitchClassSet = [C4, D4, E4, F4, G4, A4, B4].into_iter().collect();
+    /// let common = c_major.common_tones_under_transposition(PERFECT_FIFTH);
+    /// assert_eq!(common.pitches(), [C, D, E, G, A, B]);
+    ///
+    /// // A tritone away, only two notes remain
+    /// let common = c_major.common_tones_under_transposition(DIMINISHED_FIFTH);
+    /// assert_eq!(common.pitches(), [F, B]);
+    /// ```
+    pub fn common_tones_under_transposition(&self, interval: Interval) -> PitchClassSet {
+        self.common_tones(&self.transpose(interval))
+    }
+
+    /// Returns whether this set is Z-related to another set.
+    ///
+    /// Two sets are Z-related when they share their interval-class vector without
+    /// belonging to the same set class, that is without sharing their prime form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// // The all-interval tetrachords 4-Z15 and 4-Z29
+    /// let z15: PitchClassSet = [C4, CSHARP4, E4, FSHARP4].into_iter().collect();
+    /// let z29: PitchClassSet = [C4, CSHARP4, DSHARP4, G4].into_iter().collect();
+    /// assert!(z15.is_z_related(&z29));
+    ///
+    /// // A set is not Z-related to its own transpositions
+    /// assert!(!z15.is_z_related(&z15.transpose(MAJOR_SECOND)));
+    /// ```
+    pub fn is_z_related(&self, other: &PitchClassSet) -> bool {
+        self.len() == other.len()
+            && self.interval_class_vector() == other.interval_class_vector()
+            && self.prime_form() != other.prime_form()
+    }
+
+    /// Returns the voice-leading distance from this set to another set of the same
+    /// size, or `None` if the sets have different sizes.
+    ///
+    /// The distance is the smallest total number of semitones the pitch classes of
+    /// this set have to move, each up or down, to become the other set. Shared
+    /// pitch classes may move too, when that makes the total smaller.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c_major: PitchClassSet = [C4, D4, E4, F4, G4, A4, B4].into_iter().collect();
+    /// let g_major: PitchClassSet = [G4, A4, B4, C5, D5, E5, FSHARP5].into_iter().collect();
+    /// let c_minor: PitchClassSet = [C4, D4, DSHARP4, F4, G4, GSHARP4, ASHARP4].into_iter().collect();
+    ///
+    /// // F moves up to F#
+    /// assert_eq!(c_major.voice_leading_distance(&g_major), Some(1));
+    /// // E, A and B move down a semitone
+    /// assert_eq!(c_major.voice_leading_distance(&c_minor), Some(3));
+    ///
+    /// let c_triad: PitchClassSet = [C4, E4, G4].into_iter().collect();
+    /// assert_eq!(c_major.voice_leading_distance(&c_triad), None);
+    /// ```
+    pub fn voice_leading_distance(&self, other: &PitchClassSet) -> Option<u32> {
+        if self.len() != other.len() {
+            return None;
+        }
+
+        // A smallest voice leading never crosses voices, so it maps the ascending
+        // pitch classes of this set onto a rotation of those of the other set.
+        let from: Vec<u8> = self.classes().collect();
+        let to: Vec<u8> = other.classes().collect();
+        let n = from.len();
+        (0..n.max(1))
+            .map(|rotation| {
+                from.iter()
+                    .enumerate()
+                    .map(|(i, &a)| {
+                        let up = (to[(i + rotation) % n] + SEMITONES - a) % SEMITONES;
+                        up.min(SEMITONES - up) as u32
+                    })
+                    .sum()
+            })
+            .min()
+    }
+
+    /// Returns whether every pitch class of this set is in another set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let g_major: PitchClassSet = [G4, A4, B4, C5, D5, E5, FSHARP5].into_iter().collect();
+    /// let c_major_seventh: PitchClassSet = [C4, E4, G4, B4].into_iter().collect();
+    /// let c_dominant_seventh: PitchClassSet = [C4, E4, G4, ASHARP4].into_iter().collect();
+    ///
+    /// assert!(c_major_seventh.is_subset(&g_major));
+    /// assert!(!c_dominant_seventh.is_subset(&g_major));
+    /// ```
+    #[inline]
+    pub const fn is_subset(&self, other: &PitchClassSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns whether every pitch class of another set is in this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchClassSet;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c_major: PitchClassSet = [C4, D4, E4, F4, G4, A4, B4].into_iter().collect();
+    /// let d_minor: PitchClassSet = [D4, F4, A4].into_iter().collect();
+    /// assert!(c_major.is_superset(&d_minor));
+    /// assert!(!d_minor.is_superset(&c_major));
+    /// ```
+    #[inline]
+    pub const fn is_superset(&self, other: &PitchClassSet) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns the bit representing the pitch class of a pitch.
+    #[inline]
+    const fn bit(pitch: Pitch) -> u16 {
+        1 << pitch.canonical().semitones()
+    }
+
+    /// Returns the pitch classes of this set as integers, in ascending order.
+    fn classes(&self) -> impl Iterator<Item = u8> + use<> {
+        let bits = self.0;
+        (0..SEMITONES).filter(move |class| bits & (1 << class) != 0)
+    }
+
+    /// Returns the rotation of the ascending pitch classes that is most packed to
+    /// the left, as its starting index and its packing.
+    ///
+    /// The packing lists the intervals from the first pitch class of the rotation
+    /// to the last, then to the second, third, and so on, so that comparing
+    /// packings orders rotations as the normal form requires.
+    fn most_packed(classes: &[u8]) -> Option<(usize, Vec<u8>)> {
+        let n = classes.len();
+        (0..n)
+            .map(|start| {
+                let first = classes[start];
+                let interval =
+                    |i: usize| (classes[(start + i) % n] + SEMITONES - first) % SEMITONES;
+                let packing = std::iter::once(interval(n - 1))
+                    .chain((1..n - 1).map(interval))
+                    .collect::<Vec<_>>();
+                (start, packing)
+            })
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+    }
+}
+
+impl<P: Borrow<Pitch>> FromIterator<P> for PitchClassSet {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for pitch in iter {
+            set.insert(*pitch.borrow());
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn set(pitches: &[Pitch]) -> PitchClassSet {
+        pitches.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut set = PitchClassSet::new();
+        assert!(set.insert(C));
+        assert!(set.insert(B9.canonical()));
+        assert!(!set.insert(C4));
+        assert!(set.contains(C9));
+        assert!(set.contains(B));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.pitches(), [C, B]);
+    }
+
+    #[test]
+    fn test_transpose_and_invert() {
+        let triad = set(&[C4, E4, G4]);
+        assert_eq!(triad.transpose(PERFECT_FIFTH), set(&[G, B, D]));
+        assert_eq!(triad.transpose(PERFECT_OCTAVE), triad);
+        assert_eq!(triad.transpose(Interval::new(19)), set(&[G, B, D]));
+        assert_eq!(
+            set(&[C, E, B]).transpose(Interval::new(250)),
+            set(&[D, A, ASHARP])
+        );
+        assert_eq!(
+            set(&[C, E, B]).common_tones_under_transposition(Interval::new(251)),
+            set(&[B])
+        );
+        assert_eq!(triad.invert().invert(), triad);
+        assert_eq!(set(&[CSHARP, D]).invert(), set(&[B, ASHARP]));
+    }
+
+    #[test]
+    fn test_normal_form() {
+        assert!(PitchClassSet::new().normal_form().is_empty());
+        assert_eq!(set(&[FSHARP]).normal_form(), [FSHARP]);
+        assert_eq!(set(&[G, C, E]).normal_form(), [C, E, G]);
+        assert_eq!(set(&[B, C, E, G]).normal_form(), [B, C, E, G]);
+        assert_eq!(set(&[C, E, GSHARP]).normal_form(), [C, E, GSHARP]);
+        assert_eq!(
+            set(&O4.pitches()).normal_form(),
+            set(&O4.pitches()).pitches()
+        );
+    }
+
+    #[test]
+    fn test_interval_class_vector() {
+        assert_eq!(PitchClassSet::new().interval_class_vector(), [0; 6]);
+        assert_eq!(
+            set(&[C, FSHARP]).interval_class_vector(),
+            [0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(set(&[C, CSHARP, E, FSHARP]).interval_class_vector(), [1; 6]);
+        assert_eq!(
+            set(&O4.pitches()).interval_class_vector(),
+            [12, 12, 12, 12, 12, 6]
+        );
+
+        let pentatonic = set(&[C, D, E, G, A]);
+        assert_eq!(pentatonic.interval_class_vector(), [0, 3, 2, 1, 4, 0]);
+        assert_eq!(
+            pentatonic
+                .transpose(MINOR_THIRD)
+                .invert()
+                .interval_class_vector(),
+            pentatonic.interval_class_vector()
+        );
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let whole_tone = set(&[C, D, E, FSHARP, GSHARP, ASHARP]);
+        let even: Vec<_> = (0..12).step_by(2).map(Interval::new).collect();
+        assert_eq!(whole_tone.transpositional_symmetries(), even);
+        assert_eq!(whole_tone.inversional_symmetries(), even);
+
+        let diminished = set(&[C, CSHARP, DSHARP, E, FSHARP, G, A, ASHARP]);
+        assert_eq!(
+            diminished.transpositional_symmetries(),
+            [PERFECT_UNISON, MINOR_THIRD, DIMINISHED_FIFTH, MAJOR_SIXTH]
+        );
+        assert_eq!(
+            diminished.inversional_symmetries(),
+            [MINOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH]
+        );
+
+        assert_eq!(PitchClassSet::new().transpositional_symmetries().len(), 12);
+        assert_eq!(set(&[D]).inversional_symmetries(), [MAJOR_THIRD]);
+    }
+
+    #[test]
+    fn test_common_tones() {
+        let triad = set(&[C, E, G]);
+        assert_eq!(triad.common_tones(&triad), triad);
+        assert!(triad.common_tones(&set(&[D, F, A])).is_empty());
+        assert!(triad.common_tones(&PitchClassSet::new()).is_empty());
+        assert_eq!(triad.common_tones(&set(&[A, C, E])), set(&[C, E]));
+    }
+
+    #[test]
+    fn test_common_tones_under_transposition() {
+        let diatonic = set(&[C, D, E, F, G, A, B]);
+        assert_eq!(
+            diatonic.common_tones_under_transposition(PERFECT_UNISON),
+            diatonic
+        );
+
+        let vector = diatonic.interval_class_vector();
+        for semitones in 1..12u8 {
+            let common = diatonic.common_tones_under_transposition(Interval::new(semitones));
+            let class = semitones.min(12 - semitones) as usize;
+            let expected = if class == 6 {
+                2 * vector[5]
+            } else {
+                vector[class - 1]
+            };
+            assert_eq!(common.len(), expected as usize);
+        }
+    }
+
+    #[test]
+    fn test_is_z_related() {
+        // 5-Z12 and 5-Z36
+        let z12 = set(&[C, CSHARP, DSHARP, F, FSHARP]);
+        let z36 = set(&[C, CSHARP, D, E, G]);
+        assert!(z12.is_z_related(&z36));
+        assert!(z36.is_z_related(&z12));
+        assert!(!z12.is_z_related(&z12.invert()));
+
+        let triad = set(&[C, E, G]);
+        assert!(!triad.is_z_related(&set(&[C, D, E])));
+        assert!(!PitchClassSet::new().is_z_related(&set(&[C])));
+    }
+
+    #[test]
+    fn test_voice_leading_distance() {
+        let set = |pitches: &[Pitch]| pitches.iter().collect::<PitchClassSet>();
+
+        let c_major = set(&[C4, E4, G4]);
+        assert_eq!(c_major.voice_leading_distance(&c_major), Some(0));
+        assert_eq!(c_major.voice_leading_distance(&set(&[C4, E4, A4])), Some(2));
+        // B C: moving across the octave costs a single semitone
+        assert_eq!(
+            set(&[B3, D4]).voice_leading_distance(&set(&[C4, D4])),
+            Some(1)
+        );
+        // The distance is symmetric
+        let f_minor = set(&[F4, GSHARP4, C5]);
+        assert_eq!(
+            c_major.voice_leading_distance(&f_minor),
+            f_minor.voice_leading_distance(&c_major)
+        );
+        assert_eq!(c_major.voice_leading_distance(&f_minor), Some(2));
+        assert_eq!(
+            PitchClassSet::new().voice_leading_distance(&PitchClassSet::new()),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_subset_and_superset() {
+        let empty = PitchClassSet::new();
+        let triad = set(&[C, E, G]);
+        let scale = set(&[C, D, E, F, G, A, B]);
+
+        assert!(empty.is_subset(&triad));
+        assert!(triad.is_subset(&triad));
+        assert!(triad.is_subset(&scale));
+        assert!(!scale.is_subset(&triad));
+        assert!(scale.is_superset(&triad));
+        assert!(!triad.is_superset(&set(&[C, EFLAT])));
+    }
+
+    #[test]
+    fn test_prime_form() {
+        assert!(PitchClassSet::new().prime_form().is_empty());
+        assert_eq!(set(&[FSHARP]).prime_form(), set(&[C]));
+        assert_eq!(set(&[D, F, A]).prime_form(), set(&[C, DSHARP, G]));
+        assert_eq!(
+            set(&[C, E, G, B]).prime_form(),
+            set(&[C, CSHARP, F, GSHARP])
+        );
+
+        // Forte's left packing for 5-20: (0, 1, 3, 7, 8)
+        assert_eq!(
+            set(&[C, CSHARP, F, FSHARP, GSHARP]).prime_form(),
+            set(&[C, CSHARP, DSHARP, G, GSHARP])
+        );
+    }
+}
@@ -176,8 +176,9 @@
 //! assert!(!C4.is_canonical());
 //! ```
 
-use crate::{Interval, Octave};
+use crate::{Interval, Octave, OctaveConvention};
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents a musical pitch.
 ///
@@ -204,7 +205,7 @@ use std::fmt;
 /// // Get the octave
 /// assert_eq!(pitch.octave(), O4);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Pitch(u8);
 
 impl Pitch {
@@ -224,6 +225,24 @@ impl Pitch {
         Self(semitones)
     }
 
+    /// Returns an iterator over every pitch of the MIDI range, from C-1 up to G9.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(Pitch::all().len(), 128);
+    /// assert_eq!(Pitch::all().next(), Some(C));
+    /// assert_eq!(Pitch::all().last(), Some(G9));
+    /// assert_eq!(Pitch::all().filter(|pitch| pitch.canonical() == C).count(), 11);
+    /// ```
+    #[inline]
+    pub fn all() -> impl DoubleEndedIterator<Item = Pitch> + ExactSizeIterator {
+        (0..=MIDI_MAX).map(Pitch)
+    }
+
     /// Create a new pitch from a canonical pitch class and octave.
     ///
     /// # Examples
@@ -362,6 +381,265 @@ impl Pitch {
         Pitch(self.semitones() + interval.semitones())
     }
 
+    /// Returns this pitch raised by `n` octaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(C4.octave_up(1), C5);
+    /// assert_eq!(A0.octave_up(3), A3);
+    /// ```
+    #[inline]
+    pub const fn octave_up(&self, n: u8) -> Pitch {
+        match self.checked_octave_up(n) {
+            Some(pitch) => pitch,
+            None => panic!("pitch raised above the MIDI range"),
+        }
+    }
+
+    /// Returns this pitch lowered by `n` octaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting pitch would be below C-1, the bottom of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(C4.octave_down(1), C3);
+    /// assert_eq!(A4.octave_down(4), A0);
+    /// ```
+    #[inline]
+    pub const fn octave_down(&self, n: u8) -> Pitch {
+        match self.checked_octave_down(n) {
+            Some(pitch) => pitch,
+            None => panic!("pitch lowered below the MIDI range"),
+        }
+    }
+
+    /// Returns this pitch raised by `n` octaves, or `None` if the result
+    /// would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(C4.checked_octave_up(2), Some(C6));
+    /// assert_eq!(C9.checked_octave_up(1), None);
+    /// ```
+    #[inline]
+    pub const fn checked_octave_up(&self, n: u8) -> Option<Pitch> {
+        let Some(semitones) = n.checked_mul(crate::constants::SEMITONES_PER_OCTAVE) else {
+            return None;
+        };
+        match self.semitones().checked_add(semitones) {
+            Some(semitones) if semitones <= MIDI_MAX => Some(Pitch(semitones)),
+            _ => None,
+        }
+    }
+
+    /// Returns this pitch lowered by `n` octaves, or `None` if the result
+    /// would be below C-1, the bottom of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(C4.checked_octave_down(2), Some(C2));
+    /// assert_eq!(B0.checked_octave_down(2), None);
+    /// ```
+    #[inline]
+    pub const fn checked_octave_down(&self, n: u8) -> Option<Pitch> {
+        let Some(semitones) = n.checked_mul(crate::constants::SEMITONES_PER_OCTAVE) else {
+            return None;
+        };
+        match self.semitones().checked_sub(semitones) {
+            Some(semitones) => Some(Pitch(semitones)),
+            None => None,
+        }
+    }
+
+    /// Returns the frequency of this pitch in Hz.
+    ///
+    /// The frequency is computed in twelve-tone equal temperament with
+    /// A4 tuned to [`CONCERT_PITCH`](constants::CONCERT_PITCH) (440 Hz).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(A4.frequency(), 440.0);
+    /// assert_eq!(A5.frequency(), 880.0);
+    /// assert!((C4.frequency() - 261.63).abs() < 0.01);
+    /// ```
+    #[inline]
+    pub fn frequency(&self) -> f64 {
+        let semitones = self.semitones() as f64 - constants::A4.semitones() as f64;
+        constants::CONCERT_PITCH
+            * 2f64.powf(semitones / crate::constants::SEMITONES_PER_OCTAVE as f64)
+    }
+
+    /// Returns the pitch nearest to the given frequency, together with the
+    /// deviation of the frequency from that pitch in cents.
+    ///
+    /// Returns `None` if the frequency is not positive or if the nearest
+    /// pitch falls outside the MIDI range (C-1 to G9).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let (pitch, cents) = Pitch::from_frequency(440.0).unwrap();
+    /// assert_eq!(pitch, A4);
+    /// assert!(cents.abs() < 1e-9);
+    ///
+    /// let (pitch, cents) = Pitch::from_frequency(450.0).unwrap();
+    /// assert_eq!(pitch, A4);
+    /// assert!((cents - 38.91).abs() < 0.01);
+    /// ```
+    pub fn from_frequency(frequency: f64) -> Option<(Pitch, f64)> {
+        if frequency.is_nan() || frequency <= 0.0 {
+            return None;
+        }
+
+        let exact = constants::A4.semitones() as f64
+            + crate::constants::SEMITONES_PER_OCTAVE as f64
+                * (frequency / constants::CONCERT_PITCH).log2();
+        let nearest = exact.round();
+        if !(0.0..=MIDI_MAX as f64).contains(&nearest) {
+            return None;
+        }
+
+        Some((Pitch(nearest as u8), (exact - nearest) * 100.0))
+    }
+
+    /// Parses a pitch name using the given octave convention.
+    ///
+    /// A pitch name is a note letter (A to G), any number of accidentals, and an
+    /// optional octave number. Without an octave number the canonical pitch is
+    /// returned. Accidentals may be written in ASCII (`#`, `b`) or with the
+    /// Unicode glyphs `♯`, `♭`, `𝄪`, `𝄫` and `♮`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{OctaveConvention, Pitch};
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(Pitch::parse_with("C4", OctaveConvention::Scientific), Ok(C4));
+    /// assert_eq!(Pitch::parse_with("C3", OctaveConvention::Yamaha), Ok(C4));
+    /// assert_eq!(Pitch::parse_with("Eb", OctaveConvention::Yamaha), Ok(EFLAT));
+    /// assert_eq!(Pitch::parse_with("F♯4", OctaveConvention::Scientific), Ok(FSHARP4));
+    /// ```
+    pub fn parse_with(s: &str, convention: OctaveConvention) -> Result<Pitch, ParsePitchError> {
+        let mut chars = s.chars();
+        let letter = chars.next().ok_or(ParsePitchError::Empty)?;
+        let mut semitones: i16 = match letter.to_ascii_uppercase() {
+            'C' => 0,
+            'D' => 2,
+            'E' => 4,
+            'F' => 5,
+            'G' => 7,
+            'A' => 9,
+            'B' => 11,
+            _ => return Err(ParsePitchError::InvalidLetter),
+        };
+
+        let rest = chars.as_str();
+        let octave_start = rest
+            .find(|c: char| c == '-' || c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (accidentals, octave) = rest.split_at(octave_start);
+        for accidental in accidentals.chars() {
+            match accidental {
+                '#' | '♯' => semitones += 1,
+                'b' | '♭' => semitones -= 1,
+                '𝄪' => semitones += 2,
+                '𝄫' => semitones -= 2,
+                '♮' => {}
+                _ => return Err(ParsePitchError::InvalidAccidental),
+            }
+        }
+
+        let per_octave = crate::constants::SEMITONES_PER_OCTAVE as i16;
+        if octave.is_empty() {
+            return Ok(Pitch(semitones.rem_euclid(per_octave) as u8));
+        }
+
+        let number = octave
+            .parse::<i8>()
+            .map_err(|_| ParsePitchError::InvalidOctave)?;
+        let octave = convention
+            .octave(number)
+            .ok_or(ParsePitchError::InvalidOctave)?;
+        let semitones = semitones + (octave.value() as i16 + 1) * per_octave;
+        u8::try_from(semitones)
+            .ok()
+            .filter(|semitones| *semitones <= MIDI_MAX)
+            .map(Pitch)
+            .ok_or(ParsePitchError::OutOfRange)
+    }
+
+    /// Returns an object that displays this pitch using the given octave convention.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{OctaveConvention, Pitch};
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(C4.display_with(OctaveConvention::Scientific).to_string(), "C4");
+    /// assert_eq!(C4.display_with(OctaveConvention::Yamaha).to_string(), "C3");
+    ///
+    /// // The alternate flag renders Unicode accidentals
+    /// assert_eq!(format!("{:#}", CSHARP4.display_with(OctaveConvention::Yamaha)), "C♯3");
+    /// ```
+    #[inline]
+    pub const fn display_with(self, convention: OctaveConvention) -> PitchDisplay {
+        PitchDisplay {
+            pitch: self,
+            convention,
+        }
+    }
+
+    /// Transposes this pitch by the given interval, or returns `None` if the
+    /// result would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(C4.checked_transpose(PERFECT_FIFTH), Some(G4));
+    /// assert_eq!(G9.checked_transpose(MINOR_SECOND), None);
+    /// ```
+    #[inline]
+    pub const fn checked_transpose(&self, interval: Interval) -> Option<Pitch> {
+        match self.semitones().checked_add(interval.semitones()) {
+            Some(semitones) if semitones <= MIDI_MAX => Some(Pitch(semitones)),
+            _ => None,
+        }
+    }
+
     pub fn apply_pattern<P>(&self, pattern: P) -> Vec<Pitch>
     where
         P: IntoIterator<Item = Interval>,
@@ -371,6 +649,44 @@ impl Pitch {
             .map(|interval| self.transpose(interval))
             .collect()
     }
+
+    /// Applies a fixed-size interval pattern to this pitch, without allocating.
+    ///
+    /// This is the array counterpart of [`Pitch::apply_pattern`], for hot loops
+    /// such as arpeggiators and generators. It can be evaluated at compile time,
+    /// to build constant and static tables of scales and chords.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Pitch;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let triad = D4.apply_array([PERFECT_UNISON, MINOR_THIRD, PERFECT_FIFTH]);
+    /// assert_eq!(triad, [D4, F4, A4]);
+    ///
+    /// // Computed at compile time
+    /// static C_MAJOR_SCALE: [Pitch; 7] = C4.apply_array([
+    ///     PERFECT_UNISON,
+    ///     MAJOR_SECOND,
+    ///     MAJOR_THIRD,
+    ///     PERFECT_FOURTH,
+    ///     PERFECT_FIFTH,
+    ///     MAJOR_SIXTH,
+    ///     MAJOR_SEVENTH,
+    /// ]);
+    /// assert_eq!(C_MAJOR_SCALE, [C4, D4, E4, F4, G4, A4, B4]);
+    /// ```
+    #[inline]
+    pub const fn apply_array<const N: usize>(&self, pattern: [Interval; N]) -> [Pitch; N] {
+        let mut pitches = [*self; N];
+        let mut index = 0;
+        while index < N {
+            pitches[index] = self.transpose(pattern[index]);
+            index += 1;
+        }
+        pitches
+    }
 }
 
 macro_rules! generate_octave_pitches {
@@ -406,6 +722,9 @@ macro_rules! generate_octave_pitches {
 pub mod constants {
     use super::Pitch;
 
+    /// The reference frequency of A4 in Hz.
+    pub const CONCERT_PITCH: f64 = 440.0;
+
     pub const C: Pitch = Pitch(0);
     pub const CSHARP: Pitch = Pitch(1);
     pub const DFLAT: Pitch = CSHARP;
@@ -440,26 +759,77 @@ pub mod constants {
     generate_octave_pitches!(9);
 }
 
-const PITCH_NAMES: [&str; crate::constants::SEMITONES_PER_OCTAVE as usize] = [
-    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
-];
+/// The highest MIDI note number (G9).
+pub(crate) const MIDI_MAX: u8 = 127;
 
 impl fmt::Display for Pitch {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let canonical = self.canonical();
-        if self.is_canonical() {
-            write!(f, "{}", PITCH_NAMES[canonical.semitones() as usize])
-        } else {
-            write!(
-                f,
-                "{}{}",
-                PITCH_NAMES[canonical.semitones() as usize],
-                self.octave()
-            )
+        self.display_with(OctaveConvention::Scientific).fmt(f)
+    }
+}
+
+impl FromStr for Pitch {
+    type Err = ParsePitchError;
+
+    /// Parses a pitch name in scientific pitch notation (middle C is C4).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pitch::parse_with(s, OctaveConvention::Scientific)
+    }
+}
+
+/// Helper struct for displaying a pitch with a given octave convention.
+///
+/// This struct is created by [`Pitch::display_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct PitchDisplay {
+    pitch: Pitch,
+    convention: OctaveConvention,
+}
+
+impl fmt::Display for PitchDisplay {
+    /// Formats the pitch name with ASCII accidentals, or with Unicode accidentals
+    /// when the alternate flag (`{:#}`) is used.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spelled = self.pitch.spelled();
+        write!(f, "{}", spelled.letter())?;
+        fmt::Display::fmt(&spelled.accidental(), f)?;
+        if !self.pitch.is_canonical() {
+            write!(f, "{}", self.convention.octave_number(self.pitch.octave()))?;
         }
+        Ok(())
     }
 }
 
+/// An error returned when parsing a pitch name fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePitchError {
+    /// The input was empty.
+    Empty,
+    /// The input did not start with a note letter (A to G).
+    InvalidLetter,
+    /// The note letter was followed by something other than sharps or flats.
+    InvalidAccidental,
+    /// The octave number was malformed or outside the supported octaves.
+    InvalidOctave,
+    /// The pitch falls outside the MIDI range (C-1 to G9).
+    OutOfRange,
+}
+
+impl fmt::Display for ParsePitchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParsePitchError::Empty => "empty pitch name",
+            ParsePitchError::InvalidLetter => "invalid note letter",
+            ParsePitchError::InvalidAccidental => "invalid accidental",
+            ParsePitchError::InvalidOctave => "invalid octave number",
+            ParsePitchError::OutOfRange => "pitch outside the MIDI range",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for ParsePitchError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -606,6 +976,64 @@ mod tests {
         assert_eq!(B4.to_string(), "B4");
     }
 
+    #[test]
+    fn test_display_with() {
+        let yamaha = OctaveConvention::Yamaha;
+        assert_eq!(C.display_with(yamaha).to_string(), "C");
+        assert_eq!(C0.display_with(yamaha).to_string(), "C-1");
+        assert_eq!(FSHARP4.display_with(yamaha).to_string(), "F#3");
+        assert_eq!(G9.display_with(yamaha).to_string(), "G8");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("C4".parse(), Ok(C4));
+        assert_eq!("c#4".parse(), Ok(CSHARP4));
+        assert_eq!("Bb3".parse(), Ok(BFLAT3));
+        assert_eq!("Cb4".parse(), Ok(B3));
+        assert_eq!("B#3".parse(), Ok(C4));
+        assert_eq!("Ebb".parse(), Ok(D));
+        assert_eq!("Cb".parse(), Ok(B));
+        assert_eq!("C-1".parse(), Ok(C));
+        assert_eq!("G9".parse(), Ok(G9));
+
+        assert_eq!("".parse::<Pitch>(), Err(ParsePitchError::Empty));
+        assert_eq!("H4".parse::<Pitch>(), Err(ParsePitchError::InvalidLetter));
+        assert_eq!(
+            "Cx4".parse::<Pitch>(),
+            Err(ParsePitchError::InvalidAccidental)
+        );
+        assert_eq!("C4-".parse::<Pitch>(), Err(ParsePitchError::InvalidOctave));
+        assert_eq!("C10".parse::<Pitch>(), Err(ParsePitchError::InvalidOctave));
+        assert_eq!("G#9".parse::<Pitch>(), Err(ParsePitchError::OutOfRange));
+        assert_eq!("Cb-1".parse::<Pitch>(), Err(ParsePitchError::OutOfRange));
+    }
+
+    #[test]
+    fn test_unicode_accidentals() {
+        assert_eq!(format!("{:#}", CSHARP4), "C♯4");
+        assert_eq!(format!("{:#}", ASHARP), "A♯");
+        assert_eq!(format!("{:#}", C4), "C4");
+
+        assert_eq!("D♭4".parse(), Ok(DFLAT4));
+        assert_eq!("F𝄪4".parse(), Ok(G4));
+        assert_eq!("B𝄫3".parse(), Ok(A3));
+        assert_eq!("E♮4".parse(), Ok(E4));
+        for pitch in Pitch::all() {
+            assert_eq!(format!("{:#}", pitch).parse(), Ok(pitch));
+        }
+    }
+
+    #[test]
+    fn test_parse_display_roundtrip() {
+        for convention in [OctaveConvention::Scientific, OctaveConvention::Yamaha] {
+            for pitch in Pitch::all() {
+                let name = pitch.display_with(convention).to_string();
+                assert_eq!(Pitch::parse_with(&name, convention), Ok(pitch));
+            }
+        }
+    }
+
     #[test]
     fn test_from_canonical() {
         assert_eq!(C.from_canonical(O4), C4);
@@ -622,6 +1050,55 @@ mod tests {
         assert_eq!(DSHARP4.with_octave(O7), DSHARP7);
     }
 
+    #[test]
+    fn test_octave_up_down() {
+        assert_eq!(C4.octave_up(0), C4);
+        assert_eq!(C4.octave_up(2), C6);
+        assert_eq!(C4.octave_down(2), C2);
+        assert_eq!(G8.octave_up(1), G9);
+        assert_eq!(C0.octave_down(1), C);
+
+        assert_eq!(GSHARP8.checked_octave_up(1), None);
+        assert_eq!(C4.checked_octave_up(u8::MAX), None);
+        assert_eq!(B.checked_octave_down(1), None);
+        assert_eq!(C4.checked_octave_down(u8::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_octave_up_out_of_range() {
+        C9.octave_up(1);
+    }
+
+    #[test]
+    fn test_checked_transpose() {
+        assert_eq!(C4.checked_transpose(PERFECT_UNISON), Some(C4));
+        assert_eq!(C9.checked_transpose(PERFECT_FIFTH), Some(G9));
+        assert_eq!(C9.checked_transpose(MINOR_SIXTH), None);
+        assert_eq!(C9.checked_transpose(Interval::new(u8::MAX)), None);
+    }
+
+    #[test]
+    fn test_frequency() {
+        assert_eq!(A4.frequency(), 440.0);
+        assert_eq!(A3.frequency(), 220.0);
+        assert!((C4.frequency() - 261.6256).abs() < 1e-4);
+        assert!((Pitch::new(0).frequency() - 8.1758).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_from_frequency() {
+        for pitch in constants::PITCHES4 {
+            let (nearest, cents) = Pitch::from_frequency(pitch.frequency()).unwrap();
+            assert_eq!(nearest, pitch);
+            assert!(cents.abs() < 1e-9);
+        }
+
+        assert_eq!(Pitch::from_frequency(0.0), None);
+        assert_eq!(Pitch::from_frequency(-440.0), None);
+        assert_eq!(Pitch::from_frequency(20_000.0), None);
+    }
+
     #[test]
     fn test_apply_pattern() {
         let pattern = [MAJOR_SECOND, PERFECT_FOURTH];
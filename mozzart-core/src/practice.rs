@@ -0,0 +1,320 @@
+//! Practice sequences.
+//!
+//! This module provides functionality for turning scales into exercises, including:
+//! - Scales played in diatonic thirds and fourths
+//! - Repeated cells of scale degrees, such as 1-2-3-5
+//! - Enclosures of every degree of a scale
+//!
+//! # Sequences
+//!
+//! A sequence repeats a cell of degrees from every degree of the scale, moving
+//! up one degree at a time. Degrees past the last pitch continue into the octave
+//! above:
+//!
+//! ```text
+//! Cell      C major
+//! 1-3       C E  D F  E G  F A  G B  A C  B D
+//! 1-4       C F  D G  E A  F B  G C  A D  B E
+//! 1-2-3-5   C D E G  D E F A  E F G B  ...
+//! ```
+//!
+//! # Enclosures
+//!
+//! An enclosure surrounds a target note with the scale note above it and the
+//! chromatic note below it, before landing on the target:
+//!
+//! ```text
+//! Target   C       D       E
+//! Notes    D B C   E C# D  F D# E
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{Scale, ScaleType};
+//! use mozzart_core::constants::*;
+//!
+//! struct PentatonicScaleType;
+//! impl ScaleType for PentatonicScaleType {
+//!     fn name() -> &'static str {
+//!         "pentatonic"
+//!     }
+//! }
+//!
+//! let pentatonic = Scale::<PentatonicScaleType>::new(vec![C4, D4, E4, G4, A4]);
+//! assert_eq!(
+//!     pentatonic.in_thirds(),
+//!     [C4, E4, D4, G4, E4, A4, G4, C5, A4, D5]
+//! );
+//! ```
+
+use crate::{Pitch, Scale, ScaleType};
+
+impl<S: ScaleType> Scale<S> {
+    /// Returns the scale played in diatonic thirds: every degree followed by the
+    /// degree a third above it within the scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pitch would be above G9, the top of the MIDI range. Use
+    /// [`Scale::checked_sequence`] with the cell `[0, 2]` to avoid the panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert_eq!(
+    ///     c_major.in_thirds(),
+    ///     [C4, E4, D4, F4, E4, G4, F4, A4, G4, B4, A4, C5, B4, D5]
+    /// );
+    /// ```
+    pub fn in_thirds(&self) -> Vec<Pitch> {
+        self.sequence(&[0, 2])
+    }
+
+    /// Returns the scale played in broken fourths: every degree followed by the
+    /// degree a fourth above it within the scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let fourths = c_major.in_fourths();
+    /// assert_eq!(fourths[..4], [C4, F4, D4, G4]);
+    /// assert_eq!(fourths[12..], [B4, E5]);
+    /// ```
+    pub fn in_fourths(&self) -> Vec<Pitch> {
+        self.sequence(&[0, 3])
+    }
+
+    /// Returns a cell of degrees played from every degree of the scale, moving up
+    /// one degree at a time.
+    ///
+    /// The cell lists the degrees of the scale above the starting degree, counted
+    /// from 0 for the starting degree itself, so that `[0, 1, 2, 4]` plays the
+    /// 1-2-3-5 cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let g_major = Scale::<MajorScaleType>::new(vec![G3, A3, B3, C4, D4, E4, FSHARP4]);
+    /// let cells = g_major.sequence(&[0, 1, 2, 4]);
+    ///
+    /// assert_eq!(cells.len(), 28);
+    /// assert_eq!(cells[..8], [G3, A3, B3, D4, A3, B3, C4, E4]);
+    /// assert_eq!(cells[24..], [FSHARP4, G4, A4, C5]);
+    /// ```
+    pub fn sequence(&self, cell: &[usize]) -> Vec<Pitch> {
+        match self.checked_sequence(cell) {
+            Some(pitches) => pitches,
+            None => panic!("pitch raised above the MIDI range"),
+        }
+    }
+
+    /// Returns a cell of degrees played from every degree of the scale, like
+    /// [`Scale::sequence`], or `None` if a pitch would be above G9, the top of the
+    /// MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let g8_major = Scale::<MajorScaleType>::new(vec![G8, A8, B8, C9, D9, E9, FSHARP9]);
+    /// assert!(g8_major.checked_sequence(&[0, 2]).is_none());
+    /// assert_eq!(g8_major.checked_sequence(&[0]), Some(g8_major.pitches().to_vec()));
+    /// ```
+    pub fn checked_sequence(&self, cell: &[usize]) -> Option<Vec<Pitch>> {
+        (0..self.len())
+            .flat_map(|degree| {
+                cell.iter()
+                    .map(move |&offset| self.checked_wrapped(degree + offset))
+            })
+            .collect()
+    }
+
+    /// Returns every degree of the scale enclosed by the scale note above it and
+    /// the chromatic note below it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pitch would be above G9 or below C-1, outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let enclosures = c_major.enclosures();
+    ///
+    /// assert_eq!(enclosures.len(), 21);
+    /// assert_eq!(enclosures[..6], [D4, B3, C4, E4, CSHARP4, D4]);
+    /// assert_eq!(enclosures[18..], [C5, ASHARP4, B4]);
+    /// ```
+    pub fn enclosures(&self) -> Vec<Pitch> {
+        (0..self.len())
+            .flat_map(|degree| {
+                let target = self.wrapped(degree);
+                let below = match target.semitones().checked_sub(1) {
+                    Some(semitones) => Pitch::new(semitones),
+                    None => panic!("enclosure below the MIDI range"),
+                };
+                [self.wrapped(degree + 1), below, target]
+            })
+            .collect()
+    }
+
+    /// Returns every degree of the scale enclosed by the scale note above it and
+    /// the chromatic note below it, like [`Scale::enclosures`], or `None` if a
+    /// pitch would be above G9 or below C-1, outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c9_major = Scale::<MajorScaleType>::new(vec![C9, D9, E9, F9, G9]);
+    /// assert!(c9_major.checked_enclosures().is_none());
+    ///
+    /// let c4_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert_eq!(c4_major.checked_enclosures(), Some(c4_major.enclosures()));
+    /// ```
+    pub fn checked_enclosures(&self) -> Option<Vec<Pitch>> {
+        let mut pitches = Vec::with_capacity(3 * self.len());
+        for degree in 0..self.len() {
+            let target = self.checked_wrapped(degree)?;
+            let below = Pitch::new(target.semitones().checked_sub(1)?);
+            pitches.extend([self.checked_wrapped(degree + 1)?, below, target]);
+        }
+        Some(pitches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    struct MyScaleType;
+    impl ScaleType for MyScaleType {
+        fn name() -> &'static str {
+            "my scale"
+        }
+    }
+
+    #[test]
+    fn test_sequences() {
+        let scale = Scale::<MyScaleType>::new(vec![A3, B3, C4, D4, E4, F4, G4]);
+        assert_eq!(scale.sequence(&[0]), scale.pitches());
+        assert_eq!(scale.sequence(&[0, 2]), scale.in_thirds());
+        assert!(scale.sequence(&[]).is_empty());
+
+        // Descending cells reach below the starting degree of the next cell
+        let cells = scale.sequence(&[2, 1, 0]);
+        assert_eq!(cells[..6], [C4, B3, A3, D4, C4, B3]);
+
+        // Thirds of a seven-note scale span three or four semitones
+        let thirds = scale.in_thirds();
+        assert!(thirds.chunks(2).all(|pair| {
+            let semitones = pair[1].semitones() - pair[0].semitones();
+            semitones == 3 || semitones == 4
+        }));
+    }
+
+    #[test]
+    fn test_empty_scale() {
+        let scale = Scale::<MyScaleType>::new(vec![]);
+        assert!(scale.in_thirds().is_empty());
+        assert!(scale.enclosures().is_empty());
+    }
+
+    #[test]
+    fn test_checked_sequences() {
+        let scale = Scale::<MyScaleType>::new(vec![G8, A8, B8, C9, D9, E9, FSHARP9]);
+        assert_eq!(scale.checked_sequence(&[0, 2]), None);
+        assert_eq!(scale.checked_enclosures(), Some(scale.enclosures()));
+        assert_eq!(scale.checked_sequence(&[]), Some(vec![]));
+
+        let top = Scale::<MyScaleType>::new(vec![C9, D9, E9, F9, G9]);
+        assert_eq!(top.checked_enclosures(), None);
+
+        let low = Scale::<MyScaleType>::new(vec![Pitch::new(0), Pitch::new(2)]);
+        assert_eq!(low.checked_enclosures(), None);
+        assert_eq!(low.checked_sequence(&[0, 1]), Some(low.sequence(&[0, 1])));
+    }
+
+    #[test]
+    #[should_panic(expected = "pitch raised above the MIDI range")]
+    fn test_thirds_above_range() {
+        Scale::<MyScaleType>::new(vec![G8, A8, B8, C9, D9, E9, FSHARP9]).in_thirds();
+    }
+
+    #[test]
+    #[should_panic(expected = "enclosure below the MIDI range")]
+    fn test_enclosure_below_range() {
+        Scale::<MyScaleType>::new(vec![Pitch::new(0), Pitch::new(2)]).enclosures();
+    }
+}
@@ -0,0 +1,593 @@
+//! Interval qualities.
+//!
+//! This module provides functionality for naming intervals by quality and number, including:
+//! - Interval qualities (perfect, major, minor, augmented, diminished)
+//! - Intervals described by quality and number
+//! - Conversion to and from semitone-based intervals
+//! - Spelled intervals between spelled pitches
+//! - Parsing and formatting of interval abbreviations such as "P5" or "m3"
+//!
+//! # Quality and Number
+//!
+//! An [`Interval`] only knows how many semitones it spans, so an augmented fourth
+//! and a diminished fifth are the same interval. Written music tells them apart by
+//! the number of letters the interval spans, counted inclusively, and by its quality:
+//!
+//! ```text
+//! Number   Qualities            Semitones (d / m / P / M / A)
+//! Unison   P, A                 -    -    0    -    1
+//! Second   d, m, M, A           0    1    -    2    3
+//! Third    d, m, M, A           2    3    -    4    5
+//! Fourth   d, P, A              4    -    5    -    6
+//! Fifth    d, P, A              6    -    7    -    8
+//! Sixth    d, m, M, A           7    8    -    9    10
+//! Seventh  d, m, M, A           9    10   -    11   12
+//! Octave   d, P, A              11   -    12   -    13
+//! ```
+//!
+//! Compound intervals, such as the ninth, behave like their simple counterpart an
+//! octave higher.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{Interval, IntervalQuality, SpelledInterval};
+//! use mozzart_core::constants::*;
+//!
+//! let augmented_fourth = SpelledInterval::new(IntervalQuality::Augmented, 4).unwrap();
+//! let diminished_fifth = SpelledInterval::new(IntervalQuality::Diminished, 5).unwrap();
+//!
+//! assert_ne!(augmented_fourth, diminished_fifth);
+//! assert_eq!(Interval::from(augmented_fourth), Interval::from(diminished_fifth));
+//! assert_eq!(Interval::from(diminished_fifth), DIMINISHED_FIFTH);
+//! ```
+//!
+//! # Abbreviations
+//!
+//! Intervals are written as a quality letter followed by a number: `d` for
+//! diminished, `m` for minor, `P` for perfect, `M` for major and `A` for augmented.
+//!
+//! ```rust
+//! use mozzart_core::{Interval, SpelledInterval};
+//! use mozzart_core::constants::*;
+//!
+//! let augmented_fourth: SpelledInterval = "A4".parse().unwrap();
+//! assert_eq!(augmented_fourth.to_string(), "A4");
+//!
+//! let minor_ninth: Interval = "m9".parse().unwrap();
+//! assert_eq!(minor_ninth.semitones(), 13);
+//! assert_eq!(PERFECT_FIFTH.to_string(), "P5");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Interval, Letter, SpelledPitch};
+
+/// The number of semitones of the major and perfect simple intervals, by number.
+const MAJOR_OR_PERFECT: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Represents the quality of an interval.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{IntervalQuality, SpelledInterval};
+///
+/// let minor_third = SpelledInterval::new(IntervalQuality::Minor, 3).unwrap();
+/// assert_eq!(minor_third.quality(), IntervalQuality::Minor);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IntervalQuality {
+    Diminished,
+    Minor,
+    Perfect,
+    Major,
+    Augmented,
+}
+
+/// Represents an interval by its quality and number.
+///
+/// The number counts the letters spanned by the interval, inclusively: 1 is a
+/// unison, 5 is a fifth, 8 is an octave, 9 is a ninth, and so on.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Interval, IntervalQuality, SpelledInterval};
+/// use mozzart_core::constants::*;
+///
+/// let major_ninth = SpelledInterval::new(IntervalQuality::Major, 9).unwrap();
+/// assert_eq!(major_ninth.semitones(), 14);
+///
+/// // Seconds cannot be perfect
+/// assert_eq!(SpelledInterval::new(IntervalQuality::Perfect, 2), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpelledInterval {
+    quality: IntervalQuality,
+    number: u8,
+}
+
+impl SpelledInterval {
+    /// Creates a new interval from a quality and a number.
+    ///
+    /// Returns `None` if the quality does not apply to the number, such as a
+    /// perfect third or a major fifth, if the interval would span fewer than
+    /// zero semitones, as a diminished unison would, or if it is too large to be
+    /// an [`Interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{IntervalQuality, SpelledInterval};
+    ///
+    /// assert!(SpelledInterval::new(IntervalQuality::Perfect, 5).is_some());
+    /// assert!(SpelledInterval::new(IntervalQuality::Major, 5).is_none());
+    /// assert!(SpelledInterval::new(IntervalQuality::Diminished, 1).is_none());
+    /// assert!(SpelledInterval::new(IntervalQuality::Major, 0).is_none());
+    /// ```
+    pub const fn new(quality: IntervalQuality, number: u8) -> Option<SpelledInterval> {
+        if number == 0 {
+            return None;
+        }
+
+        let perfect = Self::is_perfect_number(number);
+        let valid = match quality {
+            IntervalQuality::Perfect => perfect,
+            IntervalQuality::Major | IntervalQuality::Minor => !perfect,
+            IntervalQuality::Augmented => true,
+            IntervalQuality::Diminished => number > 1,
+        };
+
+        if valid && Self::size(quality, number) <= u8::MAX as u16 {
+            Some(SpelledInterval { quality, number })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the quality of this interval.
+    #[inline]
+    pub const fn quality(&self) -> IntervalQuality {
+        self.quality
+    }
+
+    /// Returns the number of this interval.
+    #[inline]
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Returns the number of semitones in this interval.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{IntervalQuality, SpelledInterval};
+    ///
+    /// let diminished_seventh = SpelledInterval::new(IntervalQuality::Diminished, 7).unwrap();
+    /// assert_eq!(diminished_seventh.semitones(), 9);
+    ///
+    /// let augmented_eleventh = SpelledInterval::new(IntervalQuality::Augmented, 11).unwrap();
+    /// assert_eq!(augmented_eleventh.semitones(), 18);
+    /// ```
+    pub const fn semitones(&self) -> u8 {
+        Self::size(self.quality, self.number) as u8
+    }
+
+    /// Returns the usual name of an interval of the given size.
+    ///
+    /// Every size is named as a perfect, major or minor interval, except the
+    /// tritone, which is named a diminished fifth. Intervals of an octave or
+    /// more are named as compound intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, IntervalQuality, SpelledInterval};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let interval = SpelledInterval::from_interval(MINOR_SIXTH);
+    /// assert_eq!(interval.quality(), IntervalQuality::Minor);
+    /// assert_eq!(interval.number(), 6);
+    ///
+    /// let interval = SpelledInterval::from_interval(Interval::new(14));
+    /// assert_eq!(interval.quality(), IntervalQuality::Major);
+    /// assert_eq!(interval.number(), 9);
+    /// ```
+    pub const fn from_interval(interval: Interval) -> SpelledInterval {
+        let semitones = interval.semitones();
+        let octaves = semitones / crate::constants::SEMITONES_PER_OCTAVE;
+        let (quality, number) = match semitones % crate::constants::SEMITONES_PER_OCTAVE {
+            0 => (IntervalQuality::Perfect, 1),
+            1 => (IntervalQuality::Minor, 2),
+            2 => (IntervalQuality::Major, 2),
+            3 => (IntervalQuality::Minor, 3),
+            4 => (IntervalQuality::Major, 3),
+            5 => (IntervalQuality::Perfect, 4),
+            6 => (IntervalQuality::Diminished, 5),
+            7 => (IntervalQuality::Perfect, 5),
+            8 => (IntervalQuality::Minor, 6),
+            9 => (IntervalQuality::Major, 6),
+            10 => (IntervalQuality::Minor, 7),
+            _ => (IntervalQuality::Major, 7),
+        };
+
+        SpelledInterval {
+            quality,
+            number: number + octaves * 7,
+        }
+    }
+
+    /// Returns the spelled interval between two spelled pitches.
+    ///
+    /// The number counts the letters from the lower to the higher written note,
+    /// so enharmonically equivalent intervals are told apart by their spelling.
+    /// The pitches may be given in either order. Returns `None` if the interval
+    /// would be doubly augmented or doubly diminished.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{IntervalQuality, Letter, SpelledInterval, SpelledPitch};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c = SpelledPitch::new(C4, Letter::C).unwrap();
+    /// let f_sharp = SpelledPitch::new(FSHARP4, Letter::F).unwrap();
+    /// let g_flat = SpelledPitch::new(GFLAT4, Letter::G).unwrap();
+    ///
+    /// let augmented_fourth = SpelledInterval::between(c, f_sharp).unwrap();
+    /// assert_eq!(augmented_fourth.to_string(), "A4");
+    ///
+    /// let diminished_fifth = SpelledInterval::between(c, g_flat).unwrap();
+    /// assert_eq!(diminished_fifth.to_string(), "d5");
+    ///
+    /// // The order of the pitches does not matter
+    /// assert_eq!(SpelledInterval::between(g_flat, c), Some(diminished_fifth));
+    /// ```
+    pub const fn between(a: SpelledPitch, b: SpelledPitch) -> Option<SpelledInterval> {
+        let mut steps = Self::letter_position(b) - Self::letter_position(a);
+        let mut semitones = b.pitch().semitones() as i16 - a.pitch().semitones() as i16;
+        if steps < 0 || (steps == 0 && semitones < 0) {
+            steps = -steps;
+            semitones = -semitones;
+        }
+
+        let number = (steps + 1) as u8;
+        let perfect = Self::is_perfect_number(number);
+        let base = Self::size(IntervalQuality::Major, number) as i16;
+        let quality = match semitones - base {
+            -2 if !perfect => IntervalQuality::Diminished,
+            -1 if perfect => IntervalQuality::Diminished,
+            -1 => IntervalQuality::Minor,
+            0 if perfect => IntervalQuality::Perfect,
+            0 => IntervalQuality::Major,
+            1 => IntervalQuality::Augmented,
+            _ => return None,
+        };
+
+        SpelledInterval::new(quality, number)
+    }
+
+    /// Returns the number of letters from C-1 to the written note of a pitch.
+    const fn letter_position(pitch: SpelledPitch) -> i16 {
+        let letters = Letter::ALL.len() as i16;
+        (pitch.octave_number() as i16 + 1) * letters + pitch.letter().index() as i16
+    }
+
+    /// Returns the number of semitones of an interval of the given quality and
+    /// number, which must be valid apart from its size.
+    const fn size(quality: IntervalQuality, number: u8) -> u16 {
+        let degree = (number - 1) % 7;
+        let octaves = ((number - 1) / 7) as u16;
+        let base = MAJOR_OR_PERFECT[degree as usize] as u16
+            + octaves * crate::constants::SEMITONES_PER_OCTAVE as u16;
+
+        match quality {
+            IntervalQuality::Perfect | IntervalQuality::Major => base,
+            IntervalQuality::Augmented => base + 1,
+            IntervalQuality::Minor => base - 1,
+            IntervalQuality::Diminished if Self::is_perfect_number(number) => base - 1,
+            IntervalQuality::Diminished => base - 2,
+        }
+    }
+
+    /// Returns whether intervals of the given number are perfect rather than
+    /// major or minor.
+    #[inline]
+    const fn is_perfect_number(number: u8) -> bool {
+        matches!((number - 1) % 7, 0 | 3 | 4)
+    }
+}
+
+impl IntervalQuality {
+    /// Returns the quality written by the given abbreviation letter.
+    ///
+    /// The letters are case sensitive, as `m` is minor and `M` is major.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::IntervalQuality;
+    ///
+    /// assert_eq!(IntervalQuality::from_char('m'), Some(IntervalQuality::Minor));
+    /// assert_eq!(IntervalQuality::from_char('M'), Some(IntervalQuality::Major));
+    /// assert_eq!(IntervalQuality::from_char('x'), None);
+    /// ```
+    pub const fn from_char(c: char) -> Option<IntervalQuality> {
+        match c {
+            'd' => Some(IntervalQuality::Diminished),
+            'm' => Some(IntervalQuality::Minor),
+            'P' => Some(IntervalQuality::Perfect),
+            'M' => Some(IntervalQuality::Major),
+            'A' => Some(IntervalQuality::Augmented),
+            _ => None,
+        }
+    }
+
+    /// Returns the abbreviation letter of this quality.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::IntervalQuality;
+    ///
+    /// assert_eq!(IntervalQuality::Diminished.as_char(), 'd');
+    /// assert_eq!(IntervalQuality::Perfect.as_char(), 'P');
+    /// ```
+    pub const fn as_char(&self) -> char {
+        match self {
+            IntervalQuality::Diminished => 'd',
+            IntervalQuality::Minor => 'm',
+            IntervalQuality::Perfect => 'P',
+            IntervalQuality::Major => 'M',
+            IntervalQuality::Augmented => 'A',
+        }
+    }
+}
+
+impl fmt::Display for IntervalQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+impl fmt::Display for SpelledInterval {
+    /// Formats the interval as its quality letter followed by its number.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.quality, self.number)
+    }
+}
+
+impl FromStr for SpelledInterval {
+    type Err = ParseIntervalError;
+
+    /// Parses an interval written as a quality letter followed by a number, such
+    /// as "P5", "m3" or "A11".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let quality = chars.next().ok_or(ParseIntervalError::Empty)?;
+        let quality =
+            IntervalQuality::from_char(quality).ok_or(ParseIntervalError::InvalidQuality)?;
+
+        let number = chars.as_str();
+        if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseIntervalError::InvalidNumber);
+        }
+        let number = number
+            .parse::<u8>()
+            .map_err(|_| ParseIntervalError::InvalidNumber)?;
+        if number == 0 {
+            return Err(ParseIntervalError::InvalidNumber);
+        }
+
+        SpelledInterval::new(quality, number).ok_or(ParseIntervalError::InvalidInterval)
+    }
+}
+
+/// An error returned when parsing an interval name fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIntervalError {
+    /// The input was empty.
+    Empty,
+    /// The input did not start with a quality letter (d, m, P, M or A).
+    InvalidQuality,
+    /// The quality letter was not followed by a positive number.
+    InvalidNumber,
+    /// The quality does not apply to the number, such as a perfect third, or the
+    /// interval is too large.
+    InvalidInterval,
+}
+
+impl fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseIntervalError::Empty => "empty interval name",
+            ParseIntervalError::InvalidQuality => "invalid interval quality",
+            ParseIntervalError::InvalidNumber => "invalid interval number",
+            ParseIntervalError::InvalidInterval => "invalid quality for the interval number",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for ParseIntervalError {}
+
+impl From<SpelledInterval> for Interval {
+    #[inline]
+    fn from(interval: SpelledInterval) -> Self {
+        Interval::new(interval.semitones())
+    }
+}
+
+impl From<Interval> for SpelledInterval {
+    #[inline]
+    fn from(interval: Interval) -> Self {
+        SpelledInterval::from_interval(interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pitch;
+    use crate::constants::*;
+
+    fn interval(quality: IntervalQuality, number: u8) -> SpelledInterval {
+        SpelledInterval::new(quality, number).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        use IntervalQuality::*;
+
+        for number in [1, 4, 5, 8, 11, 12, 15] {
+            assert!(SpelledInterval::new(Perfect, number).is_some());
+            assert!(SpelledInterval::new(Major, number).is_none());
+            assert!(SpelledInterval::new(Minor, number).is_none());
+        }
+        for number in [2, 3, 6, 7, 9, 10, 13, 14] {
+            assert!(SpelledInterval::new(Perfect, number).is_none());
+            assert!(SpelledInterval::new(Major, number).is_some());
+            assert!(SpelledInterval::new(Minor, number).is_some());
+        }
+        assert!(SpelledInterval::new(Augmented, 1).is_some());
+        assert!(SpelledInterval::new(Diminished, 8).is_some());
+
+        // The largest intervals still fit in an Interval
+        assert_eq!(
+            SpelledInterval::new(Augmented, 148).unwrap().semitones(),
+            253
+        );
+        assert!(SpelledInterval::new(Major, 153).is_none());
+        assert!(SpelledInterval::new(Major, 255).is_none());
+    }
+
+    #[test]
+    fn test_semitones() {
+        use IntervalQuality::*;
+
+        assert_eq!(interval(Perfect, 1).semitones(), 0);
+        assert_eq!(interval(Augmented, 1).semitones(), 1);
+        assert_eq!(interval(Diminished, 2).semitones(), 0);
+        assert_eq!(interval(Augmented, 2).semitones(), 3);
+        assert_eq!(interval(Diminished, 3).semitones(), 2);
+        assert_eq!(interval(Augmented, 4).semitones(), 6);
+        assert_eq!(interval(Diminished, 5).semitones(), 6);
+        assert_eq!(interval(Augmented, 5).semitones(), 8);
+        assert_eq!(interval(Augmented, 6).semitones(), 10);
+        assert_eq!(interval(Diminished, 8).semitones(), 11);
+        assert_eq!(interval(Perfect, 8).semitones(), 12);
+        assert_eq!(interval(Minor, 9).semitones(), 13);
+        assert_eq!(interval(Minor, 13).semitones(), 20);
+        assert_eq!(interval(Perfect, 15).semitones(), 24);
+    }
+
+    #[test]
+    fn test_interval_roundtrip() {
+        for semitones in 0..=36 {
+            let spelled = SpelledInterval::from_interval(Interval::new(semitones));
+            assert_eq!(Interval::from(spelled), Interval::new(semitones));
+            assert_ne!(spelled.quality(), IntervalQuality::Augmented);
+        }
+
+        assert_eq!(
+            SpelledInterval::from(PERFECT_OCTAVE),
+            interval(IntervalQuality::Perfect, 8)
+        );
+        assert_eq!(
+            SpelledInterval::from(PERFECT_UNISON),
+            interval(IntervalQuality::Perfect, 1)
+        );
+    }
+
+    #[test]
+    fn test_between() {
+        let spelled = |pitch: Pitch, letter: Letter| SpelledPitch::new(pitch, letter).unwrap();
+        let between = |a, b| SpelledInterval::between(a, b).map(|i| i.to_string());
+
+        let c4 = spelled(C4, Letter::C);
+        assert_eq!(between(c4, c4).as_deref(), Some("P1"));
+        assert_eq!(
+            between(c4, spelled(CSHARP4, Letter::C)).as_deref(),
+            Some("A1")
+        );
+        assert_eq!(
+            between(c4, spelled(CSHARP4, Letter::D)).as_deref(),
+            Some("m2")
+        );
+        assert_eq!(
+            between(c4, spelled(DSHARP4, Letter::D)).as_deref(),
+            Some("A2")
+        );
+        assert_eq!(
+            between(c4, spelled(DSHARP4, Letter::E)).as_deref(),
+            Some("m3")
+        );
+        assert_eq!(between(c4, spelled(A4, Letter::B)).as_deref(), Some("d7"));
+        assert_eq!(between(c4, spelled(B4, Letter::C)).as_deref(), Some("d8"));
+        assert_eq!(between(c4, spelled(C5, Letter::C)).as_deref(), Some("P8"));
+        assert_eq!(
+            between(c4, spelled(CSHARP5, Letter::D)).as_deref(),
+            Some("m9")
+        );
+        assert_eq!(
+            between(c4, spelled(FSHARP5, Letter::F)).as_deref(),
+            Some("A11")
+        );
+
+        // B#3 sounds as C4 but is written a letter below it
+        let b_sharp = spelled(C4, Letter::B);
+        assert_eq!(between(b_sharp, c4).as_deref(), Some("d2"));
+        assert_eq!(
+            between(b_sharp, spelled(E4, Letter::E)).as_deref(),
+            Some("d4")
+        );
+
+        // Doubly augmented and doubly diminished intervals are not supported
+        assert_eq!(
+            between(spelled(B3, Letter::C), spelled(FSHARP4, Letter::F)),
+            None
+        );
+        assert_eq!(
+            between(spelled(C4, Letter::B), spelled(B3, Letter::C)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        use IntervalQuality::*;
+
+        for (name, quality, number) in [
+            ("P1", Perfect, 1),
+            ("m3", Minor, 3),
+            ("M7", Major, 7),
+            ("A4", Augmented, 4),
+            ("d5", Diminished, 5),
+            ("m9", Minor, 9),
+            ("P15", Perfect, 15),
+        ] {
+            let spelled = interval(quality, number);
+            assert_eq!(name.parse::<SpelledInterval>(), Ok(spelled));
+            assert_eq!(spelled.to_string(), name);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |s: &str| s.parse::<SpelledInterval>();
+        assert_eq!(parse(""), Err(ParseIntervalError::Empty));
+        assert_eq!(parse("X5"), Err(ParseIntervalError::InvalidQuality));
+        assert_eq!(parse("p5"), Err(ParseIntervalError::InvalidQuality));
+        assert_eq!(parse("P"), Err(ParseIntervalError::InvalidNumber));
+        assert_eq!(parse("P0"), Err(ParseIntervalError::InvalidNumber));
+        assert_eq!(parse("M+3"), Err(ParseIntervalError::InvalidNumber));
+        assert_eq!(parse("P256"), Err(ParseIntervalError::InvalidNumber));
+        assert_eq!(parse("P3"), Err(ParseIntervalError::InvalidInterval));
+        assert_eq!(parse("M5"), Err(ParseIntervalError::InvalidInterval));
+        assert_eq!(parse("d1"), Err(ParseIntervalError::InvalidInterval));
+        assert_eq!(parse("M153"), Err(ParseIntervalError::InvalidInterval));
+    }
+}
@@ -105,10 +105,14 @@
 //! Blues: Expressive, soulful sound
 //! ```
 
+use std::borrow::Borrow;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::ops::Index;
 
-use crate::{Interval, Pitch};
+use crate::pitch::MIDI_MAX;
+use crate::{BoxedChordPattern, Interval, Pitch, PitchClassSet, ScaleCategory, Venn};
 
 /// A trait representing a type of musical scale.
 ///
@@ -136,6 +140,41 @@ use crate::{Interval, Pitch};
 /// ```
 pub trait ScaleType {
     fn name() -> &'static str;
+
+    /// Returns the other names the scale type is known under.
+    ///
+    /// Scale types have no aliases unless they override this method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScaleType;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    ///
+    ///     fn aliases() -> &'static [&'static str] {
+    ///         &["ionian"]
+    ///     }
+    /// }
+    ///
+    /// struct BluesScaleType;
+    /// impl ScaleType for BluesScaleType {
+    ///     fn name() -> &'static str {
+    ///         "blues"
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(MajorScaleType::aliases(), ["ionian"]);
+    /// assert!(BluesScaleType::aliases().is_empty());
+    /// ```
+    #[inline]
+    fn aliases() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// A trait representing a musical scale pattern.
@@ -189,9 +228,189 @@ pub trait ScalePattern {
     /// The interval pattern that defines the scale.
     const PATTERN: Self::Pattern;
 
+    /// The interval pattern of the scale when played descending, measured from the
+    /// root like [`PATTERN`](Self::PATTERN).
+    ///
+    /// Most scales are the same in both directions, so this defaults to the
+    /// ascending pattern. Scales such as the melodic minor override it.
+    const DESCENDING_PATTERN: Self::Pattern = Self::PATTERN;
+
     /// The type of the scale.
     type ScaleTyp: ScaleType;
 
+    /// Returns the name of the scale type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// struct MajorPentatonicScalePattern;
+    /// impl ScalePattern for MajorPentatonicScalePattern {
+    ///     type Pattern = [Interval; 5];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+    ///     type ScaleTyp = MajorPentatonicScaleType;
+    /// }
+    ///
+    /// assert_eq!(MajorPentatonicScalePattern::name(), "major pentatonic");
+    /// ```
+    #[inline]
+    fn name() -> &'static str {
+        Self::ScaleTyp::name()
+    }
+
+    /// Returns the aliases of the scale type.
+    #[inline]
+    fn aliases() -> &'static [&'static str] {
+        Self::ScaleTyp::aliases()
+    }
+
+    /// Returns the intervals of the scale pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// struct MajorPentatonicScalePattern;
+    /// impl ScalePattern for MajorPentatonicScalePattern {
+    ///     type Pattern = [Interval; 5];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+    ///     type ScaleTyp = MajorPentatonicScaleType;
+    /// }
+    ///
+    /// assert_eq!(
+    ///     MajorPentatonicScalePattern::intervals(),
+    ///     [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH]
+    /// );
+    /// ```
+    #[inline]
+    fn intervals() -> Vec<Interval> {
+        Self::PATTERN.into_iter().collect()
+    }
+
+    /// Returns the number of intervals in the scale pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// struct MajorPentatonicScalePattern;
+    /// impl ScalePattern for MajorPentatonicScalePattern {
+    ///     type Pattern = [Interval; 5];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+    ///     type ScaleTyp = MajorPentatonicScaleType;
+    /// }
+    ///
+    /// assert_eq!(MajorPentatonicScalePattern::len(), 5);
+    /// ```
+    #[inline]
+    fn len() -> usize {
+        Self::PATTERN.into_iter().count()
+    }
+
+    /// Returns the category of the scale pattern from its number of intervals, or
+    /// `None` if no category has that number of pitches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, ScaleCategory, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// struct MajorPentatonicScalePattern;
+    /// impl ScalePattern for MajorPentatonicScalePattern {
+    ///     type Pattern = [Interval; 5];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+    ///     type ScaleTyp = MajorPentatonicScaleType;
+    /// }
+    ///
+    /// assert_eq!(MajorPentatonicScalePattern::category(), Some(ScaleCategory::Pentatonic));
+    /// ```
+    #[inline]
+    fn category() -> Option<ScaleCategory> {
+        ScaleCategory::from_size(Self::len())
+    }
+
+    /// Returns the brightness of the scale pattern: the sum of the semitones of
+    /// its intervals above the root.
+    ///
+    /// Raising a degree makes a scale brighter. Among scales of the same category,
+    /// a higher brightness sounds brighter, ordering the modes of the major scale
+    /// from lydian down to locrian:
+    ///
+    /// ```text
+    /// Lydian 39, Major 38, Mixolydian 37, Dorian 36,
+    /// Natural minor 35, Phrygian 34, Locrian 33
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// struct MajorPentatonicScalePattern;
+    /// impl ScalePattern for MajorPentatonicScalePattern {
+    ///     type Pattern = [Interval; 5];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+    ///     type ScaleTyp = MajorPentatonicScaleType;
+    /// }
+    ///
+    /// assert_eq!(MajorPentatonicScalePattern::brightness(), 22);
+    /// ```
+    #[inline]
+    fn brightness() -> u32 {
+        Self::PATTERN
+            .into_iter()
+            .map(|interval| interval.semitones() as u32)
+            .sum()
+    }
+
     /// Applies the scale pattern to a root pitch.
     ///
     /// This method generates a sequence of pitches by applying the scale's
@@ -241,6 +460,93 @@ pub trait ScalePattern {
         let pitches = root.apply_pattern(Self::PATTERN);
         Scale::<Self::ScaleTyp>::new(pitches)
     }
+
+    /// Applies a fixed-size scale pattern to a root pitch, returning the pitches
+    /// in an array instead of allocating a [`Scale`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, Pitch, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// pub struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// pub struct MajorPentatonicScalePattern;
+    /// impl ScalePattern for MajorPentatonicScalePattern {
+    ///     type Pattern = [Interval; 5];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_SECOND, MAJOR_THIRD, PERFECT_FIFTH, MAJOR_SIXTH];
+    ///     type ScaleTyp = MajorPentatonicScaleType;
+    /// }
+    ///
+    /// let pitches: [Pitch; 5] = MajorPentatonicScalePattern::apply_array(C4);
+    /// assert_eq!(pitches, [C4, D4, E4, G4, A4]);
+    /// ```
+    #[inline]
+    fn apply_array<const N: usize>(root: Pitch) -> [Pitch; N]
+    where
+        Self: ScalePattern<Pattern = [Interval; N]>,
+    {
+        root.apply_array(Self::PATTERN)
+    }
+
+    /// Applies the descending pattern of the scale to a root pitch, returning the
+    /// pitches from the highest down to the root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, Pitch, ScalePattern, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// pub struct MelodicMinorScaleType;
+    /// impl ScaleType for MelodicMinorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "melodic minor"
+    ///     }
+    /// }
+    ///
+    /// pub struct MelodicMinorScalePattern;
+    /// impl ScalePattern for MelodicMinorScalePattern {
+    ///     type Pattern = [Interval; 7];
+    ///     const PATTERN: Self::Pattern = [
+    ///         PERFECT_UNISON,
+    ///         MAJOR_SECOND,
+    ///         MINOR_THIRD,
+    ///         PERFECT_FOURTH,
+    ///         PERFECT_FIFTH,
+    ///         MAJOR_SIXTH,
+    ///         MAJOR_SEVENTH,
+    ///     ];
+    ///     const DESCENDING_PATTERN: Self::Pattern = [
+    ///         PERFECT_UNISON,
+    ///         MAJOR_SECOND,
+    ///         MINOR_THIRD,
+    ///         PERFECT_FOURTH,
+    ///         PERFECT_FIFTH,
+    ///         MINOR_SIXTH,
+    ///         MINOR_SEVENTH,
+    ///     ];
+    ///     type ScaleTyp = MelodicMinorScaleType;
+    /// }
+    ///
+    /// assert_eq!(
+    ///     MelodicMinorScalePattern::apply_descending(A4),
+    ///     [G5, F5, E5, D5, C5, B4, A4]
+    /// );
+    /// ```
+    #[inline]
+    fn apply_descending(root: Pitch) -> Vec<Pitch> {
+        let mut pitches = root.apply_pattern(Self::DESCENDING_PATTERN);
+        pitches.reverse();
+        pitches
+    }
 }
 
 /// A musical scale.
@@ -398,43 +704,1247 @@ impl<S: ScaleType> Scale<S> {
         &self.pitches
     }
 
-    /// Returns the root pitch of the scale.
+    /// Returns the pitches of the scale in reverse order, from the highest down
+    /// to the root.
+    ///
+    /// This is a plain reversal of the ascending pitches, and ignores the
+    /// [`ScalePattern::DESCENDING_PATTERN`] of the scale. Use
+    /// [`ScalePattern::apply_descending`] with the root of the scale for scales
+    /// whose descending form differs from the ascending one, such as the melodic
+    /// minor scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert_eq!(c_major.reversed(), [B4, A4, G4, F4, E4, D4, C4]);
+    /// ```
+    pub fn reversed(&self) -> Vec<Pitch> {
+        self.pitches.iter().rev().copied().collect()
+    }
+
+    /// Returns the number of pitches in the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorPentatonicScaleType;
+    /// impl ScaleType for MajorPentatonicScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major pentatonic"
+    ///     }
+    /// }
+    ///
+    /// let scale = Scale::<MajorPentatonicScaleType>::new(vec![C4, D4, E4, G4, A4]);
+    /// assert_eq!(scale.len(), 5);
+    /// assert!(!scale.is_empty());
+    /// ```
     #[inline]
-    pub fn root(&self) -> Pitch {
-        self.pitches[0]
+    pub fn len(&self) -> usize {
+        self.pitches.len()
     }
 
-    /// Returns the name of the scale type.
+    /// Returns whether the scale has no pitches.
     #[inline]
-    pub fn name(&self) -> &'static str {
-        S::name()
+    pub fn is_empty(&self) -> bool {
+        self.pitches.is_empty()
     }
-}
 
-impl<S: ScaleType> fmt::Display for Scale<S> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.root(), self.name())
+    /// Returns the root pitch of the scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale has no pitches.
+    #[inline]
+    pub fn root(&self) -> Pitch {
+        self.pitches[0]
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::constants::*;
-    use crate::pitch::constants::*;
+    /// Returns the intervals of the pitches of the scale measured from the root,
+    /// starting with the perfect unison of the root itself.
+    ///
+    /// For a scale built by [`ScalePattern::apply`] from a pattern starting with a
+    /// perfect unison, this is the pattern of the scale. Other patterns are
+    /// measured from their first pitch instead of the root they were applied to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale has no pitches, or if any pitch is lower than the
+    /// first one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct DorianScaleType;
+    /// impl ScaleType for DorianScaleType {
+    ///     fn name() -> &'static str {
+    ///         "dorian"
+    ///     }
+    /// }
+    ///
+    /// let d_dorian = Scale::<DorianScaleType>::new(vec![D4, E4, F4, G4, A4, B4, C5]);
+    /// assert_eq!(
+    ///     d_dorian.intervals(),
+    ///     [
+    ///         PERFECT_UNISON,
+    ///         MAJOR_SECOND,
+    ///         MINOR_THIRD,
+    ///         PERFECT_FOURTH,
+    ///         PERFECT_FIFTH,
+    ///         MAJOR_SIXTH,
+    ///         MINOR_SEVENTH,
+    ///     ]
+    /// );
+    /// ```
+    pub fn intervals(&self) -> Vec<Interval> {
+        let root = self.root().semitones();
+        self.pitches
+            .iter()
+            .map(|pitch| {
+                let semitones = pitch.semitones().checked_sub(root);
+                Interval::new(semitones.expect("pitch below the root of the scale"))
+            })
+            .collect()
+    }
 
-    struct MyScaleType;
-    impl ScaleType for MyScaleType {
-        fn name() -> &'static str {
-            "my scale"
-        }
+    /// Returns the pitch of the given scale degree, counted from 1 for the root.
+    ///
+    /// Returns `None` for degree 0 and for degrees past the last pitch of the
+    /// scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert_eq!(c_major.degree(1), Some(C4));
+    /// assert_eq!(c_major.degree(3), Some(E4));
+    /// assert_eq!(c_major.degree(7), Some(B4));
+    /// assert_eq!(c_major.degree(0), None);
+    /// assert_eq!(c_major.degree(8), None);
+    /// ```
+    #[inline]
+    pub fn degree(&self, n: usize) -> Option<Pitch> {
+        n.checked_sub(1)
+            .and_then(|index| self.pitches.get(index))
+            .copied()
     }
 
-    struct MyScalePattern;
-    impl ScalePattern for MyScalePattern {
-        type Pattern = [Interval; 2];
-        const PATTERN: Self::Pattern = [MAJOR_SECOND, PERFECT_FOURTH];
-        type ScaleTyp = MyScaleType;
+    /// Returns this scale with every pitch transposed by the given interval,
+    /// keeping its scale type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a transposed pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let d_major = c_major.transpose(MAJOR_SECOND);
+    /// assert_eq!(d_major.pitches(), [D4, E4, FSHARP4, G4, A4, B4, CSHARP5]);
+    /// assert_eq!(d_major.name(), "major");
+    /// ```
+    pub fn transpose(&self, interval: Interval) -> Scale<S> {
+        match self.checked_transpose(interval) {
+            Some(scale) => scale,
+            None => panic!("scale transposed above the MIDI range"),
+        }
+    }
+
+    /// Returns this scale with every pitch transposed by the given interval, or
+    /// `None` if a transposed pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C8, D8, E8, F8, G8, A8, B8]);
+    /// assert!(c_major.checked_transpose(PERFECT_FIFTH).is_some());
+    /// assert!(c_major.checked_transpose(PERFECT_OCTAVE).is_none());
+    /// ```
+    pub fn checked_transpose(&self, interval: Interval) -> Option<Scale<S>> {
+        self.shifted(interval.semitones() as i16)
+    }
+
+    /// Returns this scale moved so that it starts on the given root, keeping its
+    /// scale type and the intervals between its pitches.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a moved pitch would be outside the MIDI range (C-1 to G9).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MinorScaleType;
+    /// impl ScaleType for MinorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "minor"
+    ///     }
+    /// }
+    ///
+    /// let a_minor = Scale::<MinorScaleType>::new(vec![A4, B4, C5, D5, E5, F5, G5]);
+    /// let e_minor = a_minor.transpose_to(E4);
+    /// assert_eq!(e_minor.pitches(), [E4, FSHARP4, G4, A4, B4, C5, D5]);
+    /// ```
+    pub fn transpose_to(&self, root: Pitch) -> Scale<S> {
+        match self.checked_transpose_to(root) {
+            Some(scale) => scale,
+            None => panic!("scale transposed outside the MIDI range"),
+        }
+    }
+
+    /// Returns this scale moved so that it starts on the given root, or `None`
+    /// if a moved pitch would be outside the MIDI range (C-1 to G9).
+    ///
+    /// An empty scale has no root and always returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert!(c_major.checked_transpose_to(F2).is_some());
+    /// assert!(c_major.checked_transpose_to(G9).is_none());
+    /// ```
+    pub fn checked_transpose_to(&self, root: Pitch) -> Option<Scale<S>> {
+        let from = *self.pitches.first()?;
+        self.shifted(Interval::between(from, root).semitones())
+    }
+
+    /// Returns the scale with every pitch moved by a number of semitones, or
+    /// `None` if a pitch would leave the MIDI range.
+    fn shifted(&self, semitones: i16) -> Option<Scale<S>> {
+        self.pitches
+            .iter()
+            .map(|pitch| {
+                let semitones = pitch.semitones() as i16 + semitones;
+                (0..=MIDI_MAX as i16)
+                    .contains(&semitones)
+                    .then(|| Pitch::new(semitones as u8))
+            })
+            .collect::<Option<_>>()
+            .map(Scale::new)
+    }
+
+    /// Returns the triads built on every degree of the scale, from the root up.
+    ///
+    /// Each triad stacks the degree with the third and fifth degrees above it
+    /// within the scale. Degrees past the last pitch wrap around to the next
+    /// octave, so the scale is expected to span a single octave.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a chord pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let triads = c_major.triads();
+    ///
+    /// assert_eq!(triads.len(), 7);
+    /// assert_eq!(triads[0], [C4, E4, G4]); // I
+    /// assert_eq!(triads[1], [D4, F4, A4]); // ii
+    /// assert_eq!(triads[6], [B4, D5, F5]); // vii°
+    /// ```
+    pub fn triads(&self) -> Vec<Vec<Pitch>> {
+        match self.checked_triads() {
+            Some(triads) => triads,
+            None => panic!("pitch raised above the MIDI range"),
+        }
+    }
+
+    /// Returns the triads built on every degree of the scale, like
+    /// [`Scale::triads`], or `None` if a chord pitch would be above G9, the top
+    /// of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let g8_major = Scale::<MajorScaleType>::new(vec![G8, A8, B8, C9, D9, E9, FSHARP9]);
+    /// assert!(g8_major.checked_triads().is_none());
+    ///
+    /// let c4_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert_eq!(c4_major.checked_triads(), Some(c4_major.triads()));
+    /// ```
+    pub fn checked_triads(&self) -> Option<Vec<Vec<Pitch>>> {
+        self.stacked_thirds(3)
+    }
+
+    /// Returns the seventh chords built on every degree of the scale, from the
+    /// root up.
+    ///
+    /// Each chord stacks the degree with the third, fifth and seventh degrees
+    /// above it within the scale, wrapping around like [`Scale::triads`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a chord pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let sevenths = c_major.sevenths();
+    ///
+    /// assert_eq!(sevenths[0], [C4, E4, G4, B4]); // Imaj7
+    /// assert_eq!(sevenths[4], [G4, B4, D5, F5]); // V7
+    /// assert_eq!(sevenths[6], [B4, D5, F5, A5]); // viiø7
+    /// ```
+    pub fn sevenths(&self) -> Vec<Vec<Pitch>> {
+        match self.checked_sevenths() {
+            Some(sevenths) => sevenths,
+            None => panic!("pitch raised above the MIDI range"),
+        }
+    }
+
+    /// Returns the seventh chords built on every degree of the scale, like
+    /// [`Scale::sevenths`], or `None` if a chord pitch would be above G9, the top
+    /// of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let g8_major = Scale::<MajorScaleType>::new(vec![G8, A8, B8, C9, D9, E9, FSHARP9]);
+    /// assert!(g8_major.checked_sevenths().is_none());
+    ///
+    /// let c4_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert_eq!(c4_major.checked_sevenths(), Some(c4_major.sevenths()));
+    /// ```
+    pub fn checked_sevenths(&self) -> Option<Vec<Vec<Pitch>>> {
+        self.stacked_thirds(4)
+    }
+
+    /// Returns the pitches of the scale rotated to start on the pitch at `n`,
+    /// counted from 0 for the root.
+    ///
+    /// The pitches before the new starting pitch are raised by an octave and
+    /// moved to the end, so the rotated pitches keep ascending when the scale
+    /// spans a single octave. This is the nth mode of the scale, counted from 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rotated pitch would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    ///
+    /// // The dorian mode starts on the second degree
+    /// assert_eq!(c_major.rotate(1), [D4, E4, F4, G4, A4, B4, C5]);
+    /// assert_eq!(c_major.rotate(0), c_major.pitches());
+    /// ```
+    pub fn rotate(&self, n: usize) -> Vec<Pitch> {
+        (n..n + self.pitches.len())
+            .map(|index| self.wrapped(index))
+            .collect()
+    }
+
+    /// Returns the chords of `size` pitches stacked in thirds on every degree, or
+    /// `None` if a pitch would be above the MIDI range.
+    fn stacked_thirds(&self, size: usize) -> Option<Vec<Vec<Pitch>>> {
+        (0..self.pitches.len())
+            .map(|degree| {
+                (0..size)
+                    .map(|third| self.checked_wrapped(degree + 2 * third))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the pitch at an index past the end of the scale, continuing the
+    /// scale into the octaves above.
+    pub(crate) fn wrapped(&self, index: usize) -> Pitch {
+        match self.checked_wrapped(index) {
+            Some(pitch) => pitch,
+            None => panic!("pitch raised above the MIDI range"),
+        }
+    }
+
+    /// Returns the pitch at an index past the end of the scale, like
+    /// [`Scale::wrapped`], or `None` if it would be above the MIDI range.
+    pub(crate) fn checked_wrapped(&self, index: usize) -> Option<Pitch> {
+        let len = self.pitches.len();
+        let octaves = u8::try_from(index / len).ok()?;
+        self.pitches[index % len].checked_octave_up(octaves)
+    }
+
+    /// Returns the name of the scale type.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        S::name()
+    }
+
+    /// Returns the aliases of the scale type.
+    #[inline]
+    pub fn aliases(&self) -> &'static [&'static str] {
+        S::aliases()
+    }
+
+    /// Returns the interval-class vector of the pitch classes of the scale.
+    ///
+    /// See [`PitchClassSet::interval_class_vector`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct WholeToneScaleType;
+    /// impl ScaleType for WholeToneScaleType {
+    ///     fn name() -> &'static str {
+    ///         "whole tone"
+    ///     }
+    /// }
+    ///
+    /// let scale = Scale::<WholeToneScaleType>::new(vec![C4, D4, E4, FSHARP4, GSHARP4, ASHARP4]);
+    /// assert_eq!(scale.interval_class_vector(), [0, 6, 0, 6, 0, 3]);
+    /// ```
+    pub fn interval_class_vector(&self) -> [u8; 6] {
+        self.pitch_classes().interval_class_vector()
+    }
+
+    /// Returns the intervals transposing the pitch classes of the scale onto
+    /// themselves, in ascending order.
+    ///
+    /// See [`PitchClassSet::transpositional_symmetries`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct WholeToneScaleType;
+    /// impl ScaleType for WholeToneScaleType {
+    ///     fn name() -> &'static str {
+    ///         "whole tone"
+    ///     }
+    /// }
+    ///
+    /// let scale = Scale::<WholeToneScaleType>::new(vec![C4, D4, E4, FSHARP4, GSHARP4, ASHARP4]);
+    /// assert_eq!(scale.transpositional_symmetries().len(), 6);
+    /// ```
+    pub fn transpositional_symmetries(&self) -> Vec<Interval> {
+        self.pitch_classes().transpositional_symmetries()
+    }
+
+    /// Returns the inversions mapping the pitch classes of the scale onto
+    /// themselves, in ascending order.
+    ///
+    /// See [`PitchClassSet::inversional_symmetries`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct DorianScaleType;
+    /// impl ScaleType for DorianScaleType {
+    ///     fn name() -> &'static str {
+    ///         "dorian"
+    ///     }
+    /// }
+    ///
+    /// // The dorian mode is symmetrical around its root
+    /// let scale = Scale::<DorianScaleType>::new(vec![D4, E4, F4, G4, A4, B4, C5]);
+    /// assert_eq!(scale.inversional_symmetries(), [MAJOR_THIRD]);
+    /// ```
+    pub fn inversional_symmetries(&self) -> Vec<Interval> {
+        self.pitch_classes().inversional_symmetries()
+    }
+
+    /// Returns whether the pitch class of a pitch is in the scale, whatever the
+    /// octave of the pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// assert!(c_major.contains(E4));
+    /// assert!(c_major.contains(E5));
+    /// assert!(c_major.contains(B1));
+    /// assert!(!c_major.contains(FSHARP4));
+    /// ```
+    #[inline]
+    pub fn contains(&self, pitch: Pitch) -> bool {
+        let pitch_class = pitch.canonical();
+        self.pitches
+            .iter()
+            .any(|member| member.canonical() == pitch_class)
+    }
+
+    /// Returns whether every pitch of a chord, in any octave, is in the scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let g_major = Scale::<MajorScaleType>::new(vec![G4, A4, B4, C5, D5, E5, FSHARP5]);
+    ///
+    /// // Cmaj7 fits in G major, C7 does not
+    /// assert!(g_major.contains_chord(&[C3, E3, G3, B3]));
+    /// assert!(!g_major.contains_chord(&[C3, E3, G3, ASHARP3]));
+    /// ```
+    pub fn contains_chord<P, I>(&self, chord: I) -> bool
+    where
+        I: IntoIterator<Item = P>,
+        P: Borrow<Pitch>,
+    {
+        chord
+            .into_iter()
+            .collect::<PitchClassSet>()
+            .is_subset(&self.pitch_classes())
+    }
+
+    /// Returns whether this scale and another, of any scale type, hold the same
+    /// pitch classes, whatever their octaves, order and roots.
+    ///
+    /// Scales of the same type compared with `==` must instead hold the same
+    /// pitches in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct MinorScaleType;
+    /// impl ScaleType for MinorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "minor"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let a_minor = Scale::<MinorScaleType>::new(vec![A3, B3, C4, D4, E4, F4, G4]);
+    /// let e_minor = Scale::<MinorScaleType>::new(vec![E4, FSHARP4, G4, A4, B4, C5, D5]);
+    ///
+    /// assert!(c_major.same_pitches(&a_minor));
+    /// assert!(!a_minor.same_pitches(&e_minor));
+    /// ```
+    pub fn same_pitches<T: ScaleType>(&self, other: &Scale<T>) -> bool {
+        self.pitch_classes() == other.pitch_classes()
+    }
+
+    /// Returns the chords fitting in the scale, as each pitch of the scale paired
+    /// with each chord pattern whose chord on that pitch is in the scale.
+    ///
+    /// The candidate chords are given as boxed chord patterns, and the results
+    /// are ordered by root, then by the order of the candidates.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{BoxedChordPattern, ChordPattern, ChordType, Interval, Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct DominantSeventhType;
+    /// impl ChordType for DominantSeventhType {
+    ///     fn name() -> &'static str {
+    ///         "dominant seventh"
+    ///     }
+    /// }
+    ///
+    /// struct DominantSeventhPattern;
+    /// impl ChordPattern for DominantSeventhPattern {
+    ///     type Pattern = [Interval; 4];
+    ///     const PATTERN: Self::Pattern =
+    ///         [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH];
+    ///     type ChordTyp = DominantSeventhType;
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let chords = [BoxedChordPattern::new::<DominantSeventhPattern>()];
+    ///
+    /// // G7 is the only dominant seventh chord of C major
+    /// let compatible = c_major.compatible_chords(&chords);
+    /// assert_eq!(compatible.len(), 1);
+    /// assert_eq!(compatible[0].0, G4);
+    /// assert_eq!(compatible[0].1.name(), "dominant seventh");
+    /// ```
+    pub fn compatible_chords<'a>(
+        &self,
+        chords: &'a [BoxedChordPattern],
+    ) -> Vec<(Pitch, &'a BoxedChordPattern)> {
+        self.pitches
+            .iter()
+            .flat_map(|&root| chords.iter().map(move |chord| (root, chord)))
+            .filter(|(root, chord)| self.contains_chord(chord.apply(*root)))
+            .collect()
+    }
+
+    /// Returns a Venn comparison of the pitch classes of this scale and another.
+    ///
+    /// The set at position 0 is this scale, and the set at position 1 the other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct DorianScaleType;
+    /// impl ScaleType for DorianScaleType {
+    ///     fn name() -> &'static str {
+    ///         "dorian"
+    ///     }
+    /// }
+    ///
+    /// let major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let dorian = Scale::<DorianScaleType>::new(vec![C4, D4, DSHARP4, F4, G4, A4, ASHARP4]);
+    ///
+    /// let venn = major.compare(&dorian);
+    /// assert_eq!(venn.shared().pitches(), [C, D, F, G, A]);
+    /// assert_eq!(venn.unique(0).pitches(), [E, B]);
+    /// assert_eq!(venn.unique(1).pitches(), [DSHARP, ASHARP]);
+    /// ```
+    pub fn compare<T: ScaleType>(&self, other: &Scale<T>) -> Venn {
+        Venn::new([self.pitch_classes(), other.pitch_classes()])
+    }
+
+    /// Returns the number of pitch classes the scale shares with another scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let g_major = Scale::<MajorScaleType>::new(vec![G4, A4, B4, C5, D5, E5, FSHARP5]);
+    /// let fsharp_major = Scale::<MajorScaleType>::new(vec![FSHARP4, GSHARP4, ASHARP4, B4, CSHARP5, DSHARP5, F5]);
+    ///
+    /// assert_eq!(c_major.shared_tones(&g_major), 6);
+    /// assert_eq!(c_major.shared_tones(&fsharp_major), 2);
+    /// ```
+    pub fn shared_tones<T: ScaleType>(&self, other: &Scale<T>) -> usize {
+        self.pitch_classes()
+            .common_tones(&other.pitch_classes())
+            .len()
+    }
+
+    /// Returns the voice-leading distance from the scale to another scale with as
+    /// many pitch classes, or `None` if the scales have different sizes.
+    ///
+    /// See [`PitchClassSet::voice_leading_distance`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// struct MelodicMinorScaleType;
+    /// impl ScaleType for MelodicMinorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "melodic minor"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    /// let d_major = Scale::<MajorScaleType>::new(vec![D4, E4, FSHARP4, G4, A4, B4, CSHARP5]);
+    /// let c_melodic_minor = Scale::<MelodicMinorScaleType>::new(vec![C4, D4, DSHARP4, F4, G4, A4, B4]);
+    ///
+    /// assert_eq!(c_major.distance(&d_major), Some(2));
+    /// assert_eq!(c_major.distance(&c_melodic_minor), Some(1));
+    /// ```
+    pub fn distance<T: ScaleType>(&self, other: &Scale<T>) -> Option<u32> {
+        self.pitch_classes()
+            .voice_leading_distance(&other.pitch_classes())
+    }
+
+    /// Returns the pitch classes of the scale as a set, whatever the octaves of
+    /// its pitches.
+    ///
+    /// Scales in different keys, registers or orders can be compared through
+    /// their pitch classes, and the set gives access to the pitch-class set
+    /// operations of [`PitchClassSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let g_major = Scale::<MajorScaleType>::new(vec![G4, A4, B4, C5, D5, E5, FSHARP5]);
+    /// let pitch_classes = g_major.pitch_classes();
+    ///
+    /// assert_eq!(pitch_classes.pitches(), [C, D, E, FSHARP, G, A, B]);
+    /// assert!(pitch_classes.contains(FSHARP2));
+    /// assert_eq!(pitch_classes.prime_form().len(), 7);
+    /// ```
+    pub fn pitch_classes(&self) -> PitchClassSet {
+        self.pitches.iter().collect()
+    }
+}
+
+impl<S: ScaleType> fmt::Debug for Scale<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scale")
+            .field("name", &self.name())
+            .field("pitches", &self.pitches)
+            .finish()
+    }
+}
+
+/// Compares scales of the same type by their pitches, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Scale, ScaleType};
+/// use mozzart_core::constants::*;
+///
+/// struct MajorScaleType;
+/// impl ScaleType for MajorScaleType {
+///     fn name() -> &'static str {
+///         "major"
+///     }
+/// }
+///
+/// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+/// assert_eq!(c_major, Scale::new(vec![C4, D4, E4, F4, G4, A4, B4]));
+/// assert_ne!(c_major, Scale::new(vec![C5, D5, E5, F5, G5, A5, B5]));
+/// ```
+impl<S: ScaleType> PartialEq for Scale<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pitches == other.pitches
+    }
+}
+
+impl<S: ScaleType> Eq for Scale<S> {}
+
+impl<S: ScaleType> Hash for Scale<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pitches.hash(state);
+    }
+}
+
+impl<S: ScaleType> fmt::Display for Scale<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.root(), self.name())
+    }
+}
+
+/// Indexes the pitches of the scale, counted from 0 for the root.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Scale, ScaleType};
+/// use mozzart_core::constants::*;
+///
+/// struct MajorScaleType;
+/// impl ScaleType for MajorScaleType {
+///     fn name() -> &'static str {
+///         "major"
+///     }
+/// }
+///
+/// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+/// assert_eq!(c_major[0], C4);
+/// assert_eq!(c_major[4], G4);
+/// ```
+impl<S: ScaleType> Index<usize> for Scale<S> {
+    type Output = Pitch;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.pitches[index]
+    }
+}
+
+/// Iterates over the pitches of the scale, consuming it.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Pitch, Scale, ScaleType};
+/// use mozzart_core::constants::*;
+///
+/// struct MajorScaleType;
+/// impl ScaleType for MajorScaleType {
+///     fn name() -> &'static str {
+///         "major"
+///     }
+/// }
+///
+/// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+/// let pitches: Vec<Pitch> = c_major.into_iter().collect();
+/// assert_eq!(pitches, [C4, D4, E4, F4, G4, A4, B4]);
+/// ```
+impl<S: ScaleType> IntoIterator for Scale<S> {
+    type Item = Pitch;
+    type IntoIter = std::vec::IntoIter<Pitch>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.pitches.into_iter()
+    }
+}
+
+/// Iterates over references to the pitches of the scale.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Scale, ScaleType};
+/// use mozzart_core::constants::*;
+///
+/// struct MajorScaleType;
+/// impl ScaleType for MajorScaleType {
+///     fn name() -> &'static str {
+///         "major"
+///     }
+/// }
+///
+/// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+/// let mut highest = C4;
+/// for pitch in &c_major {
+///     highest = highest.max(*pitch);
+/// }
+/// assert_eq!(highest, B4);
+/// ```
+impl<'a, S: ScaleType> IntoIterator for &'a Scale<S> {
+    type Item = &'a Pitch;
+    type IntoIter = std::slice::Iter<'a, Pitch>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.pitches.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::pitch::constants::*;
+    use crate::{ChordPattern, ChordType};
+
+    struct MyScaleType;
+    impl ScaleType for MyScaleType {
+        fn name() -> &'static str {
+            "my scale"
+        }
+    }
+
+    struct MyScalePattern;
+    impl ScalePattern for MyScalePattern {
+        type Pattern = [Interval; 2];
+        const PATTERN: Self::Pattern = [MAJOR_SECOND, PERFECT_FOURTH];
+        type ScaleTyp = MyScaleType;
+    }
+
+    struct MyChordType;
+    impl ChordType for MyChordType {
+        fn name() -> &'static str {
+            "my chord"
+        }
+    }
+
+    struct MyChordPattern;
+    impl ChordPattern for MyChordPattern {
+        type Pattern = [Interval; 2];
+        const PATTERN: Self::Pattern = [PERFECT_UNISON, PERFECT_FIFTH];
+        type ChordTyp = MyChordType;
+    }
+
+    #[test]
+    fn test_scale_pattern_introspection() {
+        assert_eq!(MyScalePattern::name(), "my scale");
+        assert_eq!(MyScalePattern::intervals(), [MAJOR_SECOND, PERFECT_FOURTH]);
+        assert_eq!(MyScalePattern::len(), 2);
+        assert!(MyScalePattern::aliases().is_empty());
+        assert_eq!(MyScalePattern::category(), None);
+        assert_eq!(MyScalePattern::brightness(), 7);
+    }
+
+    #[test]
+    fn test_apply_array() {
+        assert_eq!(MyScalePattern::apply_array(C4), [D4, F4]);
+        assert_eq!(
+            MyScalePattern::apply_array(A3),
+            MyScalePattern::apply(A3).pitches()
+        );
+    }
+
+    #[test]
+    fn test_descending() {
+        assert_eq!(MyScalePattern::apply_descending(C4), [F4, D4]);
+        assert_eq!(MyScalePattern::apply(C4).reversed(), [F4, D4]);
+        assert!(Scale::<MyScaleType>::new(vec![]).reversed().is_empty());
+    }
+
+    #[test]
+    fn test_interval_class_vector() {
+        let scale = Scale::<MyScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4, C5]);
+        assert_eq!(scale.interval_class_vector(), [2, 5, 4, 3, 6, 1]);
+    }
+
+    #[test]
+    fn test_intervals() {
+        let scale = Scale::<MyScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+        assert_eq!(
+            scale.intervals(),
+            [
+                PERFECT_UNISON,
+                MAJOR_SECOND,
+                MAJOR_THIRD,
+                PERFECT_FOURTH,
+                PERFECT_FIFTH,
+                MAJOR_SIXTH,
+                MAJOR_SEVENTH
+            ]
+        );
+
+        // A pattern without the unison is measured from its first pitch
+        assert_eq!(
+            MyScalePattern::apply(C4).intervals(),
+            [PERFECT_UNISON, MINOR_THIRD]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "pitch below the root of the scale")]
+    fn test_intervals_below_root() {
+        Scale::<MyScaleType>::new(vec![C4, B3]).intervals();
+    }
+
+    #[test]
+    fn test_degree() {
+        let scale = MyScalePattern::apply(C4);
+        assert_eq!(scale.degree(0), None);
+        assert_eq!(scale.degree(1), Some(D4));
+        assert_eq!(scale.degree(2), Some(F4));
+        assert_eq!(scale.degree(3), None);
+        assert_eq!(scale.degree(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_iteration_and_indexing() {
+        let scale = MyScalePattern::apply(C4);
+        assert_eq!(scale.len(), 2);
+        assert_eq!(scale[1], F4);
+        assert_eq!((&scale).into_iter().count(), 2);
+        assert_eq!(scale.into_iter().collect::<Vec<_>>(), [D4, F4]);
+        assert!(Scale::<MyScaleType>::new(vec![]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let _ = MyScalePattern::apply(C4)[2];
+    }
+
+    #[test]
+    fn test_transpose() {
+        let scale = MyScalePattern::apply(C4);
+        assert_eq!(scale.transpose(PERFECT_FIFTH).pitches(), [A4, C5]);
+        assert_eq!(scale.transpose_to(C2).pitches(), [C2, DSHARP2]);
+        assert_eq!(scale.transpose_to(D4).pitches(), scale.pitches());
+        assert_eq!(scale.transpose_to(C0).transpose_to(D4).pitches(), [D4, F4]);
+
+        let high = MyScalePattern::apply(C9);
+        assert!(high.checked_transpose(MAJOR_SECOND).is_some());
+        assert!(high.checked_transpose(MINOR_THIRD).is_none());
+
+        // Pitches below the root may fall off the bottom of the range
+        let scale = Scale::<MyScaleType>::new(vec![C4, G3]);
+        let lowest = scale.checked_transpose_to(Pitch::new(5)).unwrap();
+        assert_eq!(lowest.pitches(), [Pitch::new(5), Pitch::new(0)]);
+        assert!(scale.checked_transpose_to(Pitch::new(4)).is_none());
+        assert!(
+            Scale::<MyScaleType>::new(vec![])
+                .checked_transpose_to(C4)
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transpose_above_range() {
+        MyScalePattern::apply(C9).transpose(MINOR_THIRD);
+    }
+
+    #[test]
+    fn test_diatonic_chords() {
+        let scale = Scale::<MyScaleType>::new(vec![A3, B3, C4, D4, E4, F4, G4]);
+        let triads = scale.triads();
+        assert_eq!(triads[0], [A3, C4, E4]);
+        assert_eq!(triads[2], [C4, E4, G4]);
+        assert_eq!(triads[5], [F4, A4, C5]);
+
+        let sevenths = scale.sevenths();
+        assert_eq!(sevenths.len(), 7);
+        assert_eq!(sevenths[4], [E4, G4, B4, D5]);
+        assert_eq!(sevenths[6], [G4, B4, D5, F5]);
+
+        // Every other pitch of a shorter scale
+        let pentatonic = Scale::<MyScaleType>::new(vec![C4, D4, E4, G4, A4]);
+        assert_eq!(pentatonic.triads()[3], [G4, C5, E5]);
+        assert!(Scale::<MyScaleType>::new(vec![]).triads().is_empty());
+    }
+
+    #[test]
+    fn test_checked_diatonic_chords() {
+        // The chords on the upper degrees of a high scale run past G9
+        let scale = Scale::<MyScaleType>::new(vec![G8, A8, B8, C9, D9, E9, FSHARP9]);
+        assert_eq!(scale.checked_triads(), None);
+        assert_eq!(scale.checked_sevenths(), None);
+
+        let scale = Scale::<MyScaleType>::new(vec![C7, D7, E7, F7, G7, A7, B7]);
+        assert_eq!(scale.checked_triads(), Some(scale.triads()));
+        assert_eq!(scale.checked_sevenths(), Some(scale.sevenths()));
+    }
+
+    #[test]
+    #[should_panic(expected = "pitch raised above the MIDI range")]
+    fn test_triads_above_range() {
+        Scale::<MyScaleType>::new(vec![G8, A8, B8, C9, D9, E9, FSHARP9]).triads();
+    }
+
+    #[test]
+    fn test_rotate() {
+        let scale = Scale::<MyScaleType>::new(vec![C4, D4, E4, G4, A4]);
+        assert_eq!(scale.rotate(4), [A4, C5, D5, E5, G5]);
+        assert_eq!(scale.rotate(5), [C5, D5, E5, G5, A5]);
+        assert!(Scale::<MyScaleType>::new(vec![]).rotate(3).is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let scale = MyScalePattern::apply(C4);
+        assert!(scale.contains(D4));
+        assert!(scale.contains(D));
+        assert!(scale.contains(F9));
+        assert!(!scale.contains(C4));
+        assert!(!Scale::<MyScaleType>::new(vec![]).contains(C4));
+    }
+
+    #[test]
+    fn test_contains_chord() {
+        let scale = Scale::<MyScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+        assert!(scale.contains_chord([C5, E5, G5]));
+        assert!(scale.contains_chord(&[B2, D3, F3, A3][..]));
+        assert!(scale.contains_chord(Vec::<Pitch>::new()));
+        assert!(!scale.contains_chord([D4, FSHARP4, A4].iter()));
+    }
+
+    #[test]
+    fn test_pitch_classes() {
+        let scale = Scale::<MyScaleType>::new(vec![A3, C4, E4, A4]);
+        assert_eq!(scale.pitch_classes().pitches(), [C, E, A]);
+        assert_eq!(
+            scale.pitch_classes(),
+            scale.transpose(PERFECT_OCTAVE).pitch_classes()
+        );
+        assert!(Scale::<MyScaleType>::new(vec![]).pitch_classes().is_empty());
+    }
+
+    #[test]
+    fn test_equality() {
+        use std::collections::HashSet;
+
+        let scale = MyScalePattern::apply(C4);
+        assert_eq!(scale, Scale::new(vec![D4, F4]));
+        assert_ne!(scale, Scale::new(vec![F4, D4]));
+        assert!(scale.same_pitches(&Scale::<MyScaleType>::new(vec![F2, D6])));
+        assert!(!scale.same_pitches(&Scale::<MyScaleType>::new(vec![D4])));
+
+        let set: HashSet<_> = [scale, MyScalePattern::apply(C4)].into_iter().collect();
+        assert_eq!(set.len(), 1);
+        assert_eq!(
+            format!("{:?}", MyScalePattern::apply(C4)),
+            format!("Scale {{ name: \"my scale\", pitches: {:?} }}", [D4, F4])
+        );
+    }
+
+    #[test]
+    fn test_compatible_chords() {
+        let scale = Scale::<MyScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+        let fifth = BoxedChordPattern::new::<MyChordPattern>();
+        let roots: Vec<_> = scale
+            .compatible_chords(std::slice::from_ref(&fifth))
+            .into_iter()
+            .map(|(root, _)| root)
+            .collect();
+        // Every degree but the seventh has a perfect fifth above it
+        assert_eq!(roots, [C4, D4, E4, F4, G4, A4]);
+        assert!(scale.compatible_chords(&[]).is_empty());
     }
 
     #[test]
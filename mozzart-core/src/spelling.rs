@@ -0,0 +1,538 @@
+//! Pitch spelling.
+//!
+//! This module provides functionality for spelling pitches with note letters, including:
+//! - Note letters and accidentals
+//! - Spelled pitches, which tell apart enharmonic names such as G# and Ab
+//! - Spelling chord members from the chord root and interval pattern
+//!
+//! # Spelling
+//!
+//! A [`Pitch`] only knows its MIDI note number, so G#4 and Ab4 are the same pitch.
+//! Written music distinguishes them: the name of a chord member follows from
+//! its degree in the chord, counted in letters from the root.
+//!
+//! ```text
+//! E7:  E  G# B  D     (root, third, fifth, seventh -> E, G, B, D)
+//! Ab7: Ab C  Eb Gb    (root, third, fifth, seventh -> A, C, E, G)
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{Letter, SpelledPitch, spell_chord};
+//! use mozzart_core::constants::*;
+//!
+//! let root = SpelledPitch::new(E4, Letter::E).unwrap();
+//! let e7 = spell_chord(root, [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH]);
+//!
+//! let names: Vec<_> = e7.iter().map(|pitch| pitch.to_string()).collect();
+//! assert_eq!(names, ["E4", "G#4", "B4", "D5"]);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Interval, ParsePitchError, Pitch};
+
+/// Represents a note letter.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::Letter;
+///
+/// assert_eq!(Letter::E.semitones(), 4);
+/// assert_eq!(Letter::B.offset(2), Letter::D);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Letter {
+    C,
+    D,
+    E,
+    F,
+    G,
+    A,
+    B,
+}
+
+impl Letter {
+    /// All note letters, in order from C.
+    pub const ALL: [Letter; 7] = [
+        Letter::C,
+        Letter::D,
+        Letter::E,
+        Letter::F,
+        Letter::G,
+        Letter::A,
+        Letter::B,
+    ];
+
+    /// Returns the number of semitones of the natural note above C.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Letter;
+    ///
+    /// assert_eq!(Letter::C.semitones(), 0);
+    /// assert_eq!(Letter::A.semitones(), 9);
+    /// ```
+    #[inline]
+    pub const fn semitones(&self) -> u8 {
+        match self {
+            Letter::C => 0,
+            Letter::D => 2,
+            Letter::E => 4,
+            Letter::F => 5,
+            Letter::G => 7,
+            Letter::A => 9,
+            Letter::B => 11,
+        }
+    }
+
+    /// Returns the position of this letter counted from C (C is 0, B is 6).
+    #[inline]
+    pub const fn index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the letter `steps` letters above this one, wrapping from B to C.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Letter;
+    ///
+    /// assert_eq!(Letter::C.offset(4), Letter::G);
+    /// assert_eq!(Letter::A.offset(2), Letter::C);
+    /// ```
+    #[inline]
+    pub const fn offset(&self, steps: u8) -> Letter {
+        Letter::ALL[((self.index() as usize) + steps as usize) % Letter::ALL.len()]
+    }
+
+    /// Returns the letter for the given character, if it is a note letter.
+    const fn from_char(c: char) -> Option<Letter> {
+        match c.to_ascii_uppercase() {
+            'C' => Some(Letter::C),
+            'D' => Some(Letter::D),
+            'E' => Some(Letter::E),
+            'F' => Some(Letter::F),
+            'G' => Some(Letter::G),
+            'A' => Some(Letter::A),
+            'B' => Some(Letter::B),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Letter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = ["C", "D", "E", "F", "G", "A", "B"][self.index() as usize];
+        write!(f, "{}", name)
+    }
+}
+
+/// Represents an accidental.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::Accidental;
+///
+/// assert_eq!(Accidental::Flat.semitones(), -1);
+/// assert_eq!(Accidental::DoubleSharp.semitones(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Accidental {
+    DoubleFlat,
+    Flat,
+    Natural,
+    Sharp,
+    DoubleSharp,
+}
+
+impl Accidental {
+    /// Returns the number of semitones this accidental alters a natural note by.
+    #[inline]
+    pub const fn semitones(&self) -> i8 {
+        *self as i8 - 2
+    }
+
+    /// Returns the accidental altering a natural note by the given number of
+    /// semitones, or `None` if there is no such accidental.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Accidental;
+    ///
+    /// assert_eq!(Accidental::from_semitones(1), Some(Accidental::Sharp));
+    /// assert_eq!(Accidental::from_semitones(3), None);
+    /// ```
+    #[inline]
+    pub const fn from_semitones(semitones: i8) -> Option<Accidental> {
+        match semitones {
+            -2 => Some(Accidental::DoubleFlat),
+            -1 => Some(Accidental::Flat),
+            0 => Some(Accidental::Natural),
+            1 => Some(Accidental::Sharp),
+            2 => Some(Accidental::DoubleSharp),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Accidental {
+    /// Formats the accidental in ASCII (`#`, `b`), or with the Unicode glyphs
+    /// `♯`, `♭`, `𝄪` and `𝄫` when the alternate flag (`{:#}`) is used.
+    /// A natural is written as an empty string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match (self, f.alternate()) {
+            (Accidental::DoubleFlat, false) => "bb",
+            (Accidental::DoubleFlat, true) => "𝄫",
+            (Accidental::Flat, false) => "b",
+            (Accidental::Flat, true) => "♭",
+            (Accidental::Natural, _) => "",
+            (Accidental::Sharp, false) => "#",
+            (Accidental::Sharp, true) => "♯",
+            (Accidental::DoubleSharp, false) => "##",
+            (Accidental::DoubleSharp, true) => "𝄪",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// Represents a pitch spelled with a note letter.
+///
+/// A spelled pitch pairs a [`Pitch`] with the letter it is written with; the
+/// accidental and the written octave follow from the two. For example, MIDI
+/// note 60 can be spelled C4, B#3 or Dbb4.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Accidental, Letter, SpelledPitch};
+/// use mozzart_core::constants::*;
+///
+/// let b_sharp = SpelledPitch::new(C4, Letter::B).unwrap();
+/// assert_eq!(b_sharp.pitch(), C4);
+/// assert_eq!(b_sharp.accidental(), Accidental::Sharp);
+/// assert_eq!(b_sharp.to_string(), "B#3");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpelledPitch {
+    pitch: Pitch,
+    letter: Letter,
+}
+
+impl SpelledPitch {
+    /// Spells a pitch with the given letter.
+    ///
+    /// Returns `None` if the letter is more than two semitones away from the
+    /// pitch, since no accidental could reach it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Letter, SpelledPitch};
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert!(SpelledPitch::new(GSHARP4, Letter::A).is_some());
+    /// assert!(SpelledPitch::new(GSHARP4, Letter::E).is_none());
+    /// ```
+    pub const fn new(pitch: Pitch, letter: Letter) -> Option<SpelledPitch> {
+        match Accidental::from_semitones(alteration(pitch, letter)) {
+            Some(_) => Some(SpelledPitch { pitch, letter }),
+            None => None,
+        }
+    }
+
+    /// Returns the pitch this spelling denotes.
+    #[inline]
+    pub const fn pitch(&self) -> Pitch {
+        self.pitch
+    }
+
+    /// Returns the letter this pitch is spelled with.
+    #[inline]
+    pub const fn letter(&self) -> Letter {
+        self.letter
+    }
+
+    /// Returns the accidental this pitch is spelled with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Accidental, Letter, SpelledPitch};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let d_double_flat = SpelledPitch::new(C4, Letter::D).unwrap();
+    /// assert_eq!(d_double_flat.accidental(), Accidental::DoubleFlat);
+    /// ```
+    pub const fn accidental(&self) -> Accidental {
+        match Accidental::from_semitones(alteration(self.pitch, self.letter)) {
+            Some(accidental) => accidental,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the written octave number of this pitch in scientific notation.
+    ///
+    /// The written octave follows the letter rather than the sounding pitch, so
+    /// B#3 is in octave 3 even though it sounds as C4.
+    pub(crate) const fn octave_number(&self) -> i8 {
+        let per_octave = crate::constants::SEMITONES_PER_OCTAVE as i16;
+        let natural = self.pitch.semitones() as i16 - self.accidental().semitones() as i16;
+        (natural.div_euclid(per_octave) - 1) as i8
+    }
+}
+
+/// Returns the smallest number of semitones, up or down, that takes the natural
+/// note of `letter` to the pitch class of `pitch`.
+const fn alteration(pitch: Pitch, letter: Letter) -> i8 {
+    let per_octave = crate::constants::SEMITONES_PER_OCTAVE as i8;
+    let difference =
+        (pitch.canonical().semitones() as i8 - letter.semitones() as i8).rem_euclid(per_octave);
+    if difference > per_octave / 2 {
+        difference - per_octave
+    } else {
+        difference
+    }
+}
+
+impl Pitch {
+    /// Returns the default spelling of this pitch, using sharps for black keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Letter;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(CSHARP4.spelled().letter(), Letter::C);
+    /// assert_eq!(CSHARP4.spelled().to_string(), "C#4");
+    /// ```
+    pub const fn spelled(&self) -> SpelledPitch {
+        let letter = match self.canonical().semitones() {
+            0 | 1 => Letter::C,
+            2 | 3 => Letter::D,
+            4 => Letter::E,
+            5 | 6 => Letter::F,
+            7 | 8 => Letter::G,
+            9 | 10 => Letter::A,
+            _ => Letter::B,
+        };
+        SpelledPitch {
+            pitch: *self,
+            letter,
+        }
+    }
+}
+
+impl From<Pitch> for SpelledPitch {
+    fn from(pitch: Pitch) -> Self {
+        pitch.spelled()
+    }
+}
+
+impl From<SpelledPitch> for Pitch {
+    fn from(spelled: SpelledPitch) -> Self {
+        spelled.pitch()
+    }
+}
+
+impl fmt::Display for SpelledPitch {
+    /// Formats the spelled pitch with ASCII accidentals, or with Unicode
+    /// accidentals when the alternate flag (`{:#}`) is used.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letter)?;
+        fmt::Display::fmt(&self.accidental(), f)?;
+        if !self.pitch.is_canonical() {
+            write!(f, "{}", self.octave_number())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SpelledPitch {
+    type Err = ParsePitchError;
+
+    /// Parses a spelled pitch name in scientific pitch notation, keeping the
+    /// letter it is written with.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pitch = s.parse::<Pitch>()?;
+        let letter = s
+            .chars()
+            .next()
+            .and_then(Letter::from_char)
+            .ok_or(ParsePitchError::InvalidLetter)?;
+        SpelledPitch::new(pitch, letter).ok_or(ParsePitchError::InvalidAccidental)
+    }
+}
+
+/// Returns the number of letters between the chord root and each chord member,
+/// given the octave-reduced semitones of every member.
+///
+/// Ambiguous intervals are resolved from the other members of the chord:
+/// a minor third next to a major third is a sharp ninth, a tritone next to a
+/// perfect fifth is a sharp eleventh, a minor sixth next to a perfect fifth is a
+/// flat thirteenth, and a major sixth in a diminished chord without a seventh
+/// is a diminished seventh.
+fn chord_degrees(semitones: &[u8]) -> Vec<u8> {
+    let has = |semitone: u8| semitones.contains(&semitone);
+    semitones
+        .iter()
+        .map(|&semitone| match semitone {
+            0 => 0,
+            1 | 2 => 1,
+            3 if has(4) => 1,
+            3 | 4 => 2,
+            5 => 3,
+            6 if has(7) => 3,
+            6 => 4,
+            7 => 4,
+            8 if has(7) => 5,
+            8 => 4,
+            9 if has(3) && has(6) && !has(10) && !has(11) => 6,
+            9 => 5,
+            _ => 6,
+        })
+        .collect()
+}
+
+/// Spells the members of a chord built on `root` from its interval pattern.
+///
+/// Every member is spelled with the letter of its chord degree, counted from the
+/// root's letter: thirds with the letter two above the root, fifths with the
+/// letter four above, and so on. A member whose letter would need more than a
+/// double sharp or double flat falls back to its default spelling.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{Letter, SpelledPitch, spell_chord};
+/// use mozzart_core::constants::*;
+///
+/// let root = SpelledPitch::new(AFLAT3, Letter::A).unwrap();
+/// let chord = spell_chord(root, [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH]);
+///
+/// let names: Vec<_> = chord.iter().map(|pitch| pitch.to_string()).collect();
+/// assert_eq!(names, ["Ab3", "C4", "Eb4"]);
+/// ```
+pub fn spell_chord<P>(root: SpelledPitch, pattern: P) -> Vec<SpelledPitch>
+where
+    P: IntoIterator<Item = Interval>,
+{
+    let intervals: Vec<Interval> = pattern.into_iter().collect();
+    let semitones: Vec<u8> = intervals
+        .iter()
+        .map(|interval| interval.semitones() % crate::constants::SEMITONES_PER_OCTAVE)
+        .collect();
+
+    intervals
+        .iter()
+        .zip(chord_degrees(&semitones))
+        .map(|(&interval, degree)| {
+            let pitch = root.pitch().transpose(interval);
+            SpelledPitch::new(pitch, root.letter().offset(degree)).unwrap_or(pitch.spelled())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn names(pitches: &[SpelledPitch]) -> Vec<String> {
+        pitches.iter().map(|pitch| pitch.to_string()).collect()
+    }
+
+    #[test]
+    fn test_spelled_pitch() {
+        for pitch in O4.pitches() {
+            let spelled = pitch.spelled();
+            assert_eq!(spelled.pitch(), pitch);
+            assert_eq!(spelled.to_string(), pitch.to_string());
+        }
+
+        let c_flat = SpelledPitch::new(B3, Letter::C).unwrap();
+        assert_eq!(c_flat.accidental(), Accidental::Flat);
+        assert_eq!(c_flat.to_string(), "Cb4");
+
+        let e_sharp = SpelledPitch::new(F, Letter::E).unwrap();
+        assert_eq!(e_sharp.to_string(), "E#");
+
+        assert!(SpelledPitch::new(C4, Letter::F).is_none());
+    }
+
+    #[test]
+    fn test_spelled_pitch_from_str() {
+        for name in ["C4", "Cb4", "B#3", "Fbb2", "G##5", "Eb"] {
+            assert_eq!(name.parse::<SpelledPitch>().unwrap().to_string(), name);
+        }
+        assert_eq!("Cb4".parse::<SpelledPitch>().unwrap().pitch(), B3);
+        assert_eq!(
+            "C###4".parse::<SpelledPitch>(),
+            Err(ParsePitchError::InvalidAccidental)
+        );
+    }
+
+    #[test]
+    fn test_unicode_accidentals() {
+        for name in ["Cb4", "B#3", "Fbb2", "G##5", "D4"] {
+            let spelled = name.parse::<SpelledPitch>().unwrap();
+            let unicode = format!("{:#}", spelled);
+            assert_eq!(unicode.parse::<SpelledPitch>(), Ok(spelled));
+        }
+        assert_eq!(
+            format!("{:#}", "Fbb2".parse::<SpelledPitch>().unwrap()),
+            "F𝄫2"
+        );
+        assert_eq!(
+            format!("{:#}", "G##5".parse::<SpelledPitch>().unwrap()),
+            "G𝄪5"
+        );
+    }
+
+    #[test]
+    fn test_spell_chord() {
+        let e = E4.spelled();
+        let e7 = [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH];
+        assert_eq!(names(&spell_chord(e, e7)), ["E4", "G#4", "B4", "D5"]);
+
+        let e_flat = SpelledPitch::new(EFLAT4, Letter::E).unwrap();
+        let minor = [PERFECT_UNISON, MINOR_THIRD, PERFECT_FIFTH];
+        assert_eq!(names(&spell_chord(e_flat, minor)), ["Eb4", "Gb4", "Bb4"]);
+
+        let augmented = [PERFECT_UNISON, MAJOR_THIRD, MINOR_SIXTH];
+        assert_eq!(
+            names(&spell_chord(C4.spelled(), augmented)),
+            ["C4", "E4", "G#4"]
+        );
+
+        let diminished_seventh = [PERFECT_UNISON, MINOR_THIRD, DIMINISHED_FIFTH, MAJOR_SIXTH];
+        assert_eq!(
+            names(&spell_chord(C4.spelled(), diminished_seventh)),
+            ["C4", "Eb4", "Gb4", "Bbb4"]
+        );
+
+        let sharp_nine = [PERFECT_UNISON, MAJOR_THIRD, MINOR_SEVENTH, AUGMENTED_NINTH];
+        assert_eq!(
+            names(&spell_chord(C4.spelled(), sharp_nine)),
+            ["C4", "E4", "Bb4", "D#5"]
+        );
+    }
+
+    #[test]
+    fn test_spell_chord_falls_back() {
+        let f_flat = SpelledPitch::new(E4, Letter::F).unwrap();
+        let diminished_seventh = [PERFECT_UNISON, MINOR_THIRD, DIMINISHED_FIFTH, MAJOR_SIXTH];
+        assert_eq!(
+            names(&spell_chord(f_flat, diminished_seventh)),
+            ["Fb4", "Abb4", "Cbb5", "C#5"]
+        );
+    }
+}
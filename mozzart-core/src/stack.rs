@@ -0,0 +1,223 @@
+//! Interval stacking.
+//!
+//! This module provides functionality for building sonorities from stacked intervals,
+//! including:
+//! - Stacking successive intervals on a root pitch
+//! - Reading the stacked pitches
+//! - Reading the intervals of the stacked pitches from the root
+//!
+//! # Stacked Intervals
+//!
+//! Chord patterns measure every interval from the root. Many sonorities are easier
+//! to think of as intervals stacked one on top of the other, each measured from
+//! the pitch below:
+//!
+//! ```text
+//! Stacked:        C4 -M3-> E4 -m3-> G4 -M3-> B4
+//! From the root:  P1       M3       P5       M7
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::Stack;
+//! use mozzart_core::constants::*;
+//!
+//! // A quartal voicing
+//! let quartal = Stack::new(D4).then(PERFECT_FOURTH).then(PERFECT_FOURTH);
+//!
+//! assert_eq!(quartal.pitches(), [D4, G4, C5]);
+//! assert_eq!(
+//!     quartal.intervals(),
+//!     [PERFECT_UNISON, PERFECT_FOURTH, MINOR_SEVENTH]
+//! );
+//! ```
+
+use crate::{Interval, Pitch};
+
+/// A builder stacking intervals on a root pitch.
+///
+/// Each interval is measured from the pitch added before it, rather than from
+/// the root.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::Stack;
+/// use mozzart_core::constants::*;
+///
+/// let major_seventh = Stack::new(C4)
+///     .then(MAJOR_THIRD)
+///     .then(MINOR_THIRD)
+///     .then(MAJOR_THIRD);
+///
+/// assert_eq!(major_seventh.pitches(), [C4, E4, G4, B4]);
+/// assert_eq!(major_seventh.top(), B4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stack {
+    pitches: Vec<Pitch>,
+}
+
+impl Stack {
+    /// Creates a new stack holding only the root pitch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Stack;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let stack = Stack::new(A3);
+    /// assert_eq!(stack.pitches(), [A3]);
+    /// ```
+    #[inline]
+    pub fn new(root: Pitch) -> Self {
+        Self {
+            pitches: vec![root],
+        }
+    }
+
+    /// Adds a pitch the given interval above the top pitch of the stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new pitch is above the top of the MIDI range (G9).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Stack;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let stack = Stack::new(C4).then(PERFECT_FIFTH).then(PERFECT_FIFTH);
+    /// assert_eq!(stack.pitches(), [C4, G4, D5]);
+    /// ```
+    pub fn then(mut self, interval: Interval) -> Self {
+        let pitch = self
+            .top()
+            .checked_transpose(interval)
+            .expect("pitch stacked above the MIDI range");
+        self.pitches.push(pitch);
+        self
+    }
+
+    /// Returns the root pitch of the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Stack;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let stack = Stack::new(F3).then(MAJOR_SIXTH);
+    /// assert_eq!(stack.root(), F3);
+    /// ```
+    #[inline]
+    pub fn root(&self) -> Pitch {
+        self.pitches[0]
+    }
+
+    /// Returns the highest pitch of the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Stack;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let stack = Stack::new(F3).then(MAJOR_SIXTH);
+    /// assert_eq!(stack.top(), D4);
+    /// ```
+    #[inline]
+    pub fn top(&self) -> Pitch {
+        self.pitches[self.pitches.len() - 1]
+    }
+
+    /// Returns the pitches of the stack, from the root upwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Stack;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let stack = Stack::new(E2).then(PERFECT_FIFTH).then(PERFECT_FOURTH);
+    /// assert_eq!(stack.pitches(), [E2, B2, E3]);
+    /// ```
+    #[inline]
+    pub fn pitches(&self) -> &[Pitch] {
+        &self.pitches
+    }
+
+    /// Returns the intervals of the pitches of the stack measured from the root,
+    /// starting with the perfect unison of the root itself.
+    ///
+    /// The result can be used as a chord pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::Stack;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let stack = Stack::new(G3).then(MAJOR_THIRD).then(MINOR_THIRD).then(MINOR_THIRD);
+    /// assert_eq!(
+    ///     stack.intervals(),
+    ///     [PERFECT_UNISON, MAJOR_THIRD, PERFECT_FIFTH, MINOR_SEVENTH]
+    /// );
+    /// ```
+    pub fn intervals(&self) -> Vec<Interval> {
+        let root = self.root().semitones();
+        self.pitches
+            .iter()
+            .map(|pitch| Interval::new(pitch.semitones() - root))
+            .collect()
+    }
+}
+
+impl From<Stack> for Vec<Pitch> {
+    #[inline]
+    fn from(stack: Stack) -> Self {
+        stack.pitches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_stack() {
+        let stack = Stack::new(C4);
+        assert_eq!(stack.root(), C4);
+        assert_eq!(stack.top(), C4);
+        assert_eq!(stack.intervals(), [PERFECT_UNISON]);
+
+        let thirteenth = [
+            MAJOR_THIRD,
+            MINOR_THIRD,
+            MINOR_THIRD,
+            MAJOR_THIRD,
+            MINOR_THIRD,
+            MAJOR_THIRD,
+        ]
+        .into_iter()
+        .fold(Stack::new(G3), Stack::then);
+        assert_eq!(thirteenth.top(), E5);
+        assert_eq!(Vec::from(thirteenth), [G3, B3, D4, F4, A4, C5, E5]);
+    }
+
+    #[test]
+    fn test_stack_reaches_top_of_range() {
+        let stack = Stack::new(G8).then(PERFECT_OCTAVE);
+        assert_eq!(stack.top(), G9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_stack_above_range() {
+        let _ = Stack::new(G9).then(MINOR_SECOND);
+    }
+}
@@ -0,0 +1,310 @@
+//! Scales from steps.
+//!
+//! This module provides functionality for building scales from the steps between
+//! their pitches, including:
+//! - Step patterns written with whole and half steps, such as "W W H W W W H"
+//! - Step patterns given as intervals
+//! - Conversion of steps to intervals from the root
+//!
+//! # Steps and Intervals
+//!
+//! [`ScalePattern`](crate::ScalePattern) measures every interval from the root.
+//! Scales are often described by the steps between consecutive pitches instead,
+//! the last step leading back to the root an octave higher:
+//!
+//! ```text
+//! Steps:           W  W  H  W  W  W  H
+//! Pitches:       C  D  E  F  G  A  B  (C)
+//! From the root: 0  2  4  5  7  9  11
+//! ```
+//!
+//! Steps are written with `W` for a whole step (2 semitones), `H` for a half step
+//! (1 semitone) and `A` for an augmented second (3 semitones). Whitespace between
+//! steps is ignored.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::ScaleBuilder;
+//! use mozzart_core::constants::*;
+//!
+//! let major = ScaleBuilder::from_steps("WWHWWWH").unwrap();
+//! assert_eq!(major.apply(C4), [C4, D4, E4, F4, G4, A4, B4]);
+//!
+//! let harmonic_minor = ScaleBuilder::from_steps("W H W W H A H").unwrap();
+//! assert_eq!(harmonic_minor.apply(A3), [A3, B3, C4, D4, E4, F4, GSHARP4]);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::constants::{MAJOR_SECOND, MINOR_SECOND, MINOR_THIRD, PERFECT_UNISON};
+use crate::{Interval, Pitch};
+
+/// A scale described by the steps between its pitches.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScaleBuilder;
+/// use mozzart_core::constants::*;
+///
+/// let pentatonic = ScaleBuilder::new([
+///     MAJOR_SECOND,
+///     MAJOR_SECOND,
+///     MINOR_THIRD,
+///     MAJOR_SECOND,
+///     MINOR_THIRD,
+/// ]);
+/// assert_eq!(pentatonic.apply(G3), [G3, A3, B3, D4, E4]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScaleBuilder {
+    steps: Vec<Interval>,
+}
+
+impl ScaleBuilder {
+    /// Creates a new scale from the steps between its pitches.
+    ///
+    /// The last step leads from the highest pitch back to the root an octave
+    /// higher, and is not part of the scale.
+    #[inline]
+    pub fn new<I>(steps: I) -> Self
+    where
+        I: IntoIterator<Item = Interval>,
+    {
+        Self {
+            steps: steps.into_iter().collect(),
+        }
+    }
+
+    /// Creates a new scale from steps written with `W`, `H` and `A`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the steps are empty, contain another character than
+    /// a step letter or whitespace, or span more than 255 semitones before the
+    /// last step.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{ParseStepsError, ScaleBuilder};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let dorian = ScaleBuilder::from_steps("W H W W W H W").unwrap();
+    /// assert_eq!(dorian.apply(D4), [D4, E4, F4, G4, A4, B4, C5]);
+    ///
+    /// assert_eq!(ScaleBuilder::from_steps("WWX"), Err(ParseStepsError::InvalidStep('X')));
+    /// assert_eq!(ScaleBuilder::from_steps(" "), Err(ParseStepsError::Empty));
+    /// assert_eq!(ScaleBuilder::from_steps(&"A".repeat(90)), Err(ParseStepsError::TooWide));
+    /// ```
+    pub fn from_steps(steps: &str) -> Result<Self, ParseStepsError> {
+        let steps = steps
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                'W' => Ok(MAJOR_SECOND),
+                'H' => Ok(MINOR_SECOND),
+                'A' => Ok(MINOR_THIRD),
+                _ => Err(ParseStepsError::InvalidStep(c)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if steps.is_empty() {
+            return Err(ParseStepsError::Empty);
+        }
+        let scale = Self::new(steps);
+        if scale.checked_intervals().is_none() {
+            return Err(ParseStepsError::TooWide);
+        }
+        Ok(scale)
+    }
+
+    /// Returns the steps of the scale.
+    #[inline]
+    pub fn steps(&self) -> &[Interval] {
+        &self.steps
+    }
+
+    /// Returns the intervals of the pitches of the scale measured from the root,
+    /// starting with the perfect unison of the root itself.
+    ///
+    /// The intervals are the pattern of the scale, as used by
+    /// [`ScalePattern`](crate::ScalePattern).
+    ///
+    /// # Panics
+    ///
+    /// Panics if an interval would be wider than 255 semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScaleBuilder;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let blues = ScaleBuilder::from_steps("A W H H A W").unwrap();
+    /// assert_eq!(
+    ///     blues.intervals(),
+    ///     [
+    ///         PERFECT_UNISON,
+    ///         MINOR_THIRD,
+    ///         PERFECT_FOURTH,
+    ///         DIMINISHED_FIFTH,
+    ///         PERFECT_FIFTH,
+    ///         MINOR_SEVENTH
+    ///     ]
+    /// );
+    /// ```
+    pub fn intervals(&self) -> Vec<Interval> {
+        match self.checked_intervals() {
+            Some(intervals) => intervals,
+            None => panic!("scale steps wider than 255 semitones"),
+        }
+    }
+
+    /// Returns the intervals of the pitches of the scale measured from the root,
+    /// like [`ScaleBuilder::intervals`], or `None` if an interval would be wider
+    /// than 255 semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScaleBuilder;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let major = ScaleBuilder::from_steps("WWHWWWH").unwrap();
+    /// assert_eq!(major.checked_intervals(), Some(major.intervals()));
+    ///
+    /// let wide = ScaleBuilder::new([PERFECT_OCTAVE; 23]);
+    /// assert_eq!(wide.checked_intervals(), None);
+    /// ```
+    pub fn checked_intervals(&self) -> Option<Vec<Interval>> {
+        let steps = &self.steps[..self.steps.len().saturating_sub(1)];
+        let mut intervals = Vec::with_capacity(self.steps.len());
+        intervals.push(PERFECT_UNISON);
+        let mut semitones: u8 = 0;
+        for step in steps {
+            semitones = semitones.checked_add(step.semitones())?;
+            intervals.push(Interval::new(semitones));
+        }
+        Some(intervals)
+    }
+
+    /// Applies the scale to a root pitch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an interval of the scale would be wider than 255 semitones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScaleBuilder;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let whole_tone = ScaleBuilder::from_steps("WWWWWW").unwrap();
+    /// assert_eq!(whole_tone.apply(C4), [C4, D4, E4, FSHARP4, GSHARP4, ASHARP4]);
+    /// ```
+    #[inline]
+    pub fn apply(&self, root: Pitch) -> Vec<Pitch> {
+        root.apply_pattern(self.intervals())
+    }
+}
+
+impl FromStr for ScaleBuilder {
+    type Err = ParseStepsError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_steps(s)
+    }
+}
+
+/// An error returned when parsing scale steps fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseStepsError {
+    /// The input had no steps.
+    Empty,
+    /// The input contained a character that is not a step letter.
+    InvalidStep(char),
+    /// The steps spanned more than 255 semitones before the last step.
+    TooWide,
+}
+
+impl fmt::Display for ParseStepsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseStepsError::Empty => write!(f, "empty scale steps"),
+            ParseStepsError::InvalidStep(c) => write!(f, "invalid scale step '{}'", c),
+            ParseStepsError::TooWide => write!(f, "scale steps wider than 255 semitones"),
+        }
+    }
+}
+
+impl std::error::Error for ParseStepsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_steps_match_intervals() {
+        let major: ScaleBuilder = "WWHWWWH".parse().unwrap();
+        assert_eq!(major.steps().len(), 7);
+        assert_eq!(
+            major.intervals(),
+            [
+                PERFECT_UNISON,
+                MAJOR_SECOND,
+                MAJOR_THIRD,
+                PERFECT_FOURTH,
+                PERFECT_FIFTH,
+                MAJOR_SIXTH,
+                MAJOR_SEVENTH
+            ]
+        );
+        assert_eq!(major, ScaleBuilder::from_steps("W W H W W W H").unwrap());
+    }
+
+    #[test]
+    fn test_single_step() {
+        let unison = ScaleBuilder::new([PERFECT_OCTAVE]);
+        assert_eq!(unison.apply(E4), [E4]);
+        assert_eq!(ScaleBuilder::new([]).intervals(), [PERFECT_UNISON]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(ScaleBuilder::from_steps(""), Err(ParseStepsError::Empty));
+        assert_eq!(
+            "W h".parse::<ScaleBuilder>(),
+            Err(ParseStepsError::InvalidStep('h'))
+        );
+        assert_eq!(
+            ParseStepsError::InvalidStep('3').to_string(),
+            "invalid scale step '3'"
+        );
+    }
+
+    #[test]
+    fn test_steps_too_wide() {
+        // The last step is not part of the scale, so it may reach past 255 semitones
+        let widest = ScaleBuilder::new([Interval::new(255), PERFECT_OCTAVE]);
+        assert_eq!(widest.intervals(), [PERFECT_UNISON, Interval::new(255)]);
+
+        let wide = ScaleBuilder::new([Interval::new(255), MINOR_SECOND, PERFECT_OCTAVE]);
+        assert_eq!(wide.checked_intervals(), None);
+        assert_eq!(
+            ScaleBuilder::from_steps(&"A".repeat(87)),
+            Err(ParseStepsError::TooWide)
+        );
+        assert!(ScaleBuilder::from_steps(&"A".repeat(86)).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "scale steps wider than 255 semitones")]
+    fn test_intervals_too_wide() {
+        ScaleBuilder::new([PERFECT_OCTAVE; 23]).intervals();
+    }
+}
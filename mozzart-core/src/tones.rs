@@ -0,0 +1,205 @@
+//! Characteristic and avoid tones.
+//!
+//! This module provides functionality for choosing notes from a scale, including:
+//! - The characteristic tones setting a scale apart from the major or minor scale
+//! - The avoid notes of a scale over a chord
+//!
+//! # Characteristic Tones
+//!
+//! A scale is compared with the major scale on the same root when it has a major
+//! third, and with the natural minor scale otherwise. The tones missing from that
+//! reference scale give the scale its colour:
+//!
+//! ```text
+//! Scale            Reference        Characteristic tones
+//! D dorian         D natural minor  B  (natural 6)
+//! F lydian         F major          B  (#4)
+//! G mixolydian     G major          F  (b7)
+//! E phrygian       E natural minor  F  (b2)
+//! A harmonic minor A natural minor  G# (natural 7)
+//! ```
+//!
+//! # Avoid Notes
+//!
+//! An avoid note is a scale tone, not in the chord, lying a half step above a
+//! chord tone. Held over the chord, it clashes with the chord tone below it:
+//!
+//! ```text
+//! Scale: C major    Chord: C E G B (Cmaj7)
+//! Avoid: F          (a half step above E)
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{Scale, ScaleType};
+//! use mozzart_core::constants::*;
+//!
+//! struct LydianScaleType;
+//! impl ScaleType for LydianScaleType {
+//!     fn name() -> &'static str {
+//!         "lydian"
+//!     }
+//! }
+//!
+//! let f_lydian = Scale::<LydianScaleType>::new(vec![F4, G4, A4, B4, C5, D5, E5]);
+//! assert_eq!(f_lydian.characteristic_tones(), [B4]);
+//!
+//! // Lydian has no avoid note over its tonic chord
+//! assert!(f_lydian.avoid_notes(&[F3, A3, C4, E4]).is_empty());
+//! ```
+
+use std::borrow::Borrow;
+
+use crate::constants::{MAJOR_THIRD, MINOR_SECOND, SEMITONES_PER_OCTAVE};
+use crate::{Pitch, PitchClassSet, Scale, ScaleType};
+
+/// The semitones above the root of the major scale.
+const MAJOR: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// The semitones above the root of the natural minor scale.
+const NATURAL_MINOR: [u8; 7] = [0, 2, 3, 5, 7, 8, 10];
+
+impl<S: ScaleType> Scale<S> {
+    /// Returns the pitches of the scale missing from the major scale on the same
+    /// root, for scales with a major third, or from the natural minor scale
+    /// otherwise.
+    ///
+    /// The major and natural minor scales have no characteristic tones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct DorianScaleType;
+    /// impl ScaleType for DorianScaleType {
+    ///     fn name() -> &'static str {
+    ///         "dorian"
+    ///     }
+    /// }
+    ///
+    /// struct LocrianScaleType;
+    /// impl ScaleType for LocrianScaleType {
+    ///     fn name() -> &'static str {
+    ///         "locrian"
+    ///     }
+    /// }
+    ///
+    /// let d_dorian = Scale::<DorianScaleType>::new(vec![D4, E4, F4, G4, A4, B4, C5]);
+    /// assert_eq!(d_dorian.characteristic_tones(), [B4]);
+    ///
+    /// let b_locrian = Scale::<LocrianScaleType>::new(vec![B3, C4, D4, E4, F4, G4, A4]);
+    /// assert_eq!(b_locrian.characteristic_tones(), [C4, F4]);
+    /// ```
+    pub fn characteristic_tones(&self) -> Vec<Pitch> {
+        let Some(&root) = self.pitches().first() else {
+            return Vec::new();
+        };
+
+        let above_root = |pitch: &Pitch| {
+            (pitch.semitones() + SEMITONES_PER_OCTAVE - root.canonical().semitones())
+                % SEMITONES_PER_OCTAVE
+        };
+        let reference = if self
+            .pitches()
+            .iter()
+            .any(|p| above_root(p) == MAJOR_THIRD.semitones())
+        {
+            MAJOR
+        } else {
+            NATURAL_MINOR
+        };
+
+        self.pitches()
+            .iter()
+            .filter(|pitch| !reference.contains(&above_root(pitch)))
+            .copied()
+            .collect()
+    }
+
+    /// Returns the pitches of the scale to avoid over a chord: those not in the
+    /// chord and a half step above one of its pitches, in any octave.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Scale, ScaleType};
+    /// use mozzart_core::constants::*;
+    ///
+    /// struct MajorScaleType;
+    /// impl ScaleType for MajorScaleType {
+    ///     fn name() -> &'static str {
+    ///         "major"
+    ///     }
+    /// }
+    ///
+    /// let c_major = Scale::<MajorScaleType>::new(vec![C4, D4, E4, F4, G4, A4, B4]);
+    ///
+    /// // Over Cmaj7, F clashes with E
+    /// assert_eq!(c_major.avoid_notes(&[C3, E3, G3, B3]), [F4]);
+    ///
+    /// // Over G7, C clashes with B
+    /// assert_eq!(c_major.avoid_notes(&[G2, B2, D3, F3]), [C4]);
+    /// ```
+    pub fn avoid_notes<P, I>(&self, chord: I) -> Vec<Pitch>
+    where
+        I: IntoIterator<Item = P>,
+        P: Borrow<Pitch>,
+    {
+        let chord: PitchClassSet = chord.into_iter().collect();
+        let clashing = chord.transpose(MINOR_SECOND);
+        self.pitches()
+            .iter()
+            .filter(|&&pitch| clashing.contains(pitch) && !chord.contains(pitch))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    struct MyScaleType;
+    impl ScaleType for MyScaleType {
+        fn name() -> &'static str {
+            "my scale"
+        }
+    }
+
+    fn scale(pitches: &[Pitch]) -> Scale<MyScaleType> {
+        Scale::new(pitches.to_vec())
+    }
+
+    #[test]
+    fn test_characteristic_tones() {
+        let major = scale(&[C4, D4, E4, F4, G4, A4, B4]);
+        let minor = scale(&[A3, B3, C4, D4, E4, F4, G4]);
+        assert!(major.characteristic_tones().is_empty());
+        assert!(minor.characteristic_tones().is_empty());
+
+        let mixolydian = scale(&[G4, A4, B4, C5, D5, E5, F5]);
+        assert_eq!(mixolydian.characteristic_tones(), [F5]);
+        let phrygian = scale(&[E4, F4, G4, A4, B4, C5, D5]);
+        assert_eq!(phrygian.characteristic_tones(), [F4]);
+        let melodic_minor = scale(&[C4, D4, DSHARP4, F4, G4, A4, B4]);
+        assert_eq!(melodic_minor.characteristic_tones(), [A4, B4]);
+
+        assert!(scale(&[]).characteristic_tones().is_empty());
+    }
+
+    #[test]
+    fn test_avoid_notes() {
+        let dorian = scale(&[D4, E4, F4, G4, A4, B4, C5]);
+        // Over Dm7, dorian has no avoid note
+        assert!(dorian.avoid_notes([D3, F3, A3, C4]).is_empty());
+
+        // Chord tones are never avoided, even a half step above another
+        let major = scale(&[C4, D4, E4, F4, G4, A4, B4]);
+        assert!(major.avoid_notes([E4, F4]).is_empty());
+        assert_eq!(major.avoid_notes(Vec::<Pitch>::new()), []);
+    }
+}
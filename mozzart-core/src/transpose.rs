@@ -0,0 +1,406 @@
+//! Bulk transposition of pitch sequences.
+//!
+//! This module provides functionality for transposing many pitches at once, including:
+//! - Transposition of a pitch slice into a new vector
+//! - In-place transposition of a mutable pitch buffer
+//! - An iterator adapter transposing pitches lazily
+//! - An iterator stepping repeatedly by an interval from a root
+//! - Octave folding of pitches into a register
+//!
+//! These helpers are intended for large note buffers such as sequencer tracks,
+//! where transposing pitch by pitch through [`Pitch::transpose`] is tedious.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{PitchIteratorExt, transpose_all, transpose_slice_mut};
+//! use mozzart_core::constants::*;
+//!
+//! let melody = [C4, E4, G4, C5];
+//!
+//! // Transpose into a new vector
+//! assert_eq!(transpose_all(&melody, PERFECT_FIFTH), [G4, B4, D5, G5]);
+//!
+//! // Transpose a buffer in place
+//! let mut track = melody;
+//! transpose_slice_mut(&mut track, MAJOR_SECOND);
+//! assert_eq!(track, [D4, FSHARP4, A4, D5]);
+//!
+//! // Transpose lazily
+//! let transposed: Vec<_> = melody.iter().transposed(MAJOR_THIRD).collect();
+//! assert_eq!(transposed, [E4, GSHARP4, B4, E5]);
+//! ```
+//!
+//! # Register Folding
+//!
+//! Folding moves pitches by whole octaves into a register, keeping their pitch
+//! classes. Pitches are either moved as little as possible, or stacked upwards
+//! so that they keep their order:
+//!
+//! ```text
+//! Register:           C4..C6
+//! Pitches:            G2  E6  C3  B5
+//! Folded:             G4  E5  C4  B5
+//! Folded in order:    G4  E5  C6  -    (no room for B above C6)
+//! ```
+
+use std::borrow::Borrow;
+
+use crate::constants::PERFECT_UNISON;
+use crate::{Interval, Pitch};
+
+/// Returns a new vector with every pitch of `pitches` transposed by `interval`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::transpose_all;
+/// use mozzart_core::constants::*;
+///
+/// let triad = transpose_all(&[C4, E4, G4], PERFECT_FOURTH);
+/// assert_eq!(triad, [F4, A4, C5]);
+/// ```
+pub fn transpose_all(pitches: &[Pitch], interval: Interval) -> Vec<Pitch> {
+    pitches.iter().transposed(interval).collect()
+}
+
+/// Transposes every pitch of `pitches` by `interval` in place.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::transpose_slice_mut;
+/// use mozzart_core::constants::*;
+///
+/// let mut triad = [C4, E4, G4];
+/// transpose_slice_mut(&mut triad, PERFECT_OCTAVE);
+/// assert_eq!(triad, [C5, E5, G5]);
+/// ```
+pub fn transpose_slice_mut(pitches: &mut [Pitch], interval: Interval) {
+    for pitch in pitches.iter_mut() {
+        *pitch = pitch.transpose(interval);
+    }
+}
+
+/// Returns a new vector with every pitch of `pitches` folded by octaves into the
+/// register from `low` to `high`, inclusive.
+///
+/// Each pitch is moved by as few octaves as possible. Returns `None` if the pitch
+/// class of a pitch does not occur in the register.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::fold_into_range;
+/// use mozzart_core::constants::*;
+///
+/// let folded = fold_into_range(&[C2, E6, G4], C4, B4);
+/// assert_eq!(folded, Some(vec![C4, E4, G4]));
+///
+/// // A minor third register has no room for a D
+/// assert_eq!(fold_into_range(&[D3], E4, G4), None);
+/// ```
+pub fn fold_into_range(pitches: &[Pitch], low: Pitch, high: Pitch) -> Option<Vec<Pitch>> {
+    pitches
+        .iter()
+        .map(|pitch| pitch.fold_into(low, high))
+        .collect()
+}
+
+/// Returns a new vector with the pitches of `pitches` folded by octaves into the
+/// register from `low` to `high`, inclusive, keeping their order from the lowest
+/// pitch upwards.
+///
+/// The first pitch is placed as low as possible in the register, and every
+/// following pitch as low as possible above the one before it. Returns `None` if
+/// the pitches do not fit in the register.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::fold_into_range_ordered;
+/// use mozzart_core::constants::*;
+///
+/// // A first-inversion C major triad voiced from E3
+/// let voicing = fold_into_range_ordered(&[E5, G2, C4], E3, C5);
+/// assert_eq!(voicing, Some(vec![E3, G3, C4]));
+///
+/// // An open voicing no longer fits in an octave
+/// assert_eq!(fold_into_range_ordered(&[C4, G4, E4], C4, C5), None);
+/// ```
+pub fn fold_into_range_ordered(pitches: &[Pitch], low: Pitch, high: Pitch) -> Option<Vec<Pitch>> {
+    let mut floor = low.semitones() as u16;
+    pitches
+        .iter()
+        .map(|pitch| {
+            let class = pitch.canonical().semitones() as u16;
+            let octave = crate::constants::SEMITONES_PER_OCTAVE as u16;
+            let folded = floor + (class + octave - floor % octave) % octave;
+            if folded > high.semitones() as u16 {
+                return None;
+            }
+            floor = folded + 1;
+            Some(Pitch::new(folded as u8))
+        })
+        .collect()
+}
+
+/// An iterator that transposes the pitches of an underlying iterator.
+///
+/// This struct is created by [`PitchIteratorExt::transposed`].
+#[derive(Debug, Clone)]
+pub struct Transposed<I> {
+    iter: I,
+    interval: Interval,
+}
+
+impl<I> Iterator for Transposed<I>
+where
+    I: Iterator,
+    I::Item: Borrow<Pitch>,
+{
+    type Item = Pitch;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|pitch| pitch.borrow().transpose(self.interval))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for Transposed<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Borrow<Pitch>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|pitch| pitch.borrow().transpose(self.interval))
+    }
+}
+
+impl<I> ExactSizeIterator for Transposed<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<Pitch>,
+{
+}
+
+/// An iterator that repeatedly transposes a pitch by an interval.
+///
+/// This struct is created by [`Pitch::iter_by`].
+#[derive(Debug, Clone)]
+pub struct IterBy {
+    next: Option<Pitch>,
+    interval: Interval,
+}
+
+impl Iterator for IterBy {
+    type Item = Pitch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pitch = self.next?;
+        self.next = if self.interval == PERFECT_UNISON {
+            None
+        } else {
+            pitch.checked_transpose(self.interval)
+        };
+        Some(pitch)
+    }
+}
+
+impl std::iter::FusedIterator for IterBy {}
+
+impl Pitch {
+    /// Returns this pitch moved by as few octaves as possible into the register
+    /// from `low` to `high`, inclusive.
+    ///
+    /// Returns `None` if the pitch class of this pitch does not occur in the
+    /// register.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(A5.fold_into(C4, C5), Some(A4));
+    /// assert_eq!(A1.fold_into(C4, C5), Some(A4));
+    /// assert_eq!(A4.fold_into(C4, C5), Some(A4));
+    /// assert_eq!(A4.fold_into(C4, G4), None);
+    /// ```
+    pub fn fold_into(&self, low: Pitch, high: Pitch) -> Option<Pitch> {
+        let octave = crate::constants::SEMITONES_PER_OCTAVE as i16;
+        let (low, high) = (low.semitones() as i16, high.semitones() as i16);
+        let semitones = self.semitones() as i16;
+        let folded = if semitones < low {
+            semitones + (low - semitones + octave - 1) / octave * octave
+        } else if semitones > high {
+            semitones - (semitones - high + octave - 1) / octave * octave
+        } else {
+            semitones
+        };
+
+        (low..=high)
+            .contains(&folded)
+            .then(|| Pitch::new(folded as u8))
+    }
+
+    /// Returns an iterator starting at this pitch and repeatedly transposing it
+    /// by `interval`, until the top of the MIDI range (G9) is reached.
+    ///
+    /// Stepping by a perfect unison yields this pitch once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::constants::*;
+    ///
+    /// // A walk up the circle of fifths
+    /// let fifths: Vec<_> = C4.iter_by(PERFECT_FIFTH).take(4).collect();
+    /// assert_eq!(fifths, [C4, G4, D5, A5]);
+    ///
+    /// // The iterator stops at the top of the MIDI range
+    /// let octaves: Vec<_> = C7.iter_by(PERFECT_OCTAVE).collect();
+    /// assert_eq!(octaves, [C7, C8, C9]);
+    /// ```
+    #[inline]
+    pub fn iter_by(&self, interval: Interval) -> IterBy {
+        IterBy {
+            next: Some(*self),
+            interval,
+        }
+    }
+}
+
+/// Extension methods for iterators over pitches.
+///
+/// This trait is implemented for every iterator yielding `Pitch` or `&Pitch`.
+pub trait PitchIteratorExt: Iterator + Sized
+where
+    Self::Item: Borrow<Pitch>,
+{
+    /// Returns an iterator transposing every pitch by `interval`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::PitchIteratorExt;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let pitches = [C4, D4, E4];
+    /// let transposed: Vec<_> = pitches.iter().transposed(MINOR_THIRD).collect();
+    /// assert_eq!(transposed, [EFLAT4, F4, G4]);
+    /// ```
+    #[inline]
+    fn transposed(self, interval: Interval) -> Transposed<Self> {
+        Transposed {
+            iter: self,
+            interval,
+        }
+    }
+}
+
+impl<I> PitchIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<Pitch>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_transpose_all() {
+        assert!(transpose_all(&[], PERFECT_FIFTH).is_empty());
+        assert_eq!(transpose_all(&[C4, E4, G4], PERFECT_FIFTH), [G4, B4, D5]);
+    }
+
+    #[test]
+    fn test_transpose_slice_mut() {
+        let mut pitches = O4.pitches();
+        transpose_slice_mut(&mut pitches, PERFECT_OCTAVE);
+        assert_eq!(pitches, O5.pitches());
+    }
+
+    #[test]
+    fn test_fold_into() {
+        assert_eq!(C.fold_into(G9, G9), None);
+        assert_eq!(G.fold_into(G9, G9), Some(G9));
+        assert_eq!(G9.fold_into(C, C), None);
+        assert_eq!(B9.fold_into(C, B), Some(B));
+        assert_eq!(C4.fold_into(C5, C4), None);
+        assert_eq!(FSHARP2.fold_into(FSHARP4, FSHARP5), Some(FSHARP4));
+        assert_eq!(FSHARP7.fold_into(FSHARP4, FSHARP5), Some(FSHARP5));
+    }
+
+    #[test]
+    fn test_fold_into_range() {
+        assert_eq!(fold_into_range(&[], C4, C4), Some(vec![]));
+        assert_eq!(
+            fold_into_range(&[G2, E6, C3, B5], C4, C5),
+            Some(vec![G4, E4, C4, B4])
+        );
+        assert_eq!(
+            fold_into_range(&[G2, E6, C3, B5], C4, C6),
+            Some(vec![G4, E5, C4, B5])
+        );
+        assert_eq!(
+            fold_into_range(&[G2, E6, CSHARP3], C4, C5),
+            Some(vec![G4, E4, CSHARP4])
+        );
+        assert_eq!(fold_into_range(&[G2, DSHARP6], E4, A4), None);
+    }
+
+    #[test]
+    fn test_fold_into_range_ordered() {
+        assert_eq!(fold_into_range_ordered(&[G2, E6, C3, B5], C4, C6), None);
+        assert_eq!(
+            fold_into_range_ordered(&[G2, E6, C3, B5], C4, B6),
+            Some(vec![G4, E5, C6, B6])
+        );
+        assert_eq!(
+            fold_into_range_ordered(&[C2, C2, C2], C4, C6),
+            Some(vec![C4, C5, C6])
+        );
+        assert_eq!(
+            fold_into_range_ordered(&[D, FSHARP, A, CSHARP], A3, A5),
+            Some(vec![D4, FSHARP4, A4, CSHARP5])
+        );
+    }
+
+    #[test]
+    fn test_iter_by() {
+        let fourths: Vec<_> = E2.iter_by(PERFECT_FOURTH).take(4).collect();
+        assert_eq!(fourths, [E2, A2, D3, G3]);
+
+        let whole_tones: Vec<_> = C9.iter_by(MAJOR_SECOND).collect();
+        assert_eq!(whole_tones, [C9, D9, E9, FSHARP9]);
+
+        assert_eq!(C.iter_by(MINOR_SECOND).count(), 128);
+        assert_eq!(C4.iter_by(PERFECT_UNISON).collect::<Vec<_>>(), [C4]);
+    }
+
+    #[test]
+    fn test_transposed() {
+        let pitches = vec![C4, E4, G4];
+        let by_ref: Vec<_> = pitches.iter().transposed(MAJOR_THIRD).collect();
+        let by_value: Vec<_> = pitches.into_iter().transposed(MAJOR_THIRD).collect();
+        assert_eq!(by_ref, [E4, GSHARP4, B4]);
+        assert_eq!(by_ref, by_value);
+
+        let reversed: Vec<_> = [C4, E4].iter().transposed(MAJOR_SECOND).rev().collect();
+        assert_eq!(reversed, [FSHARP4, D4]);
+        assert_eq!([C4, E4].iter().transposed(MAJOR_SECOND).len(), 2);
+    }
+}
@@ -0,0 +1,274 @@
+//! Tuning systems.
+//!
+//! This module provides functionality for working with tuning systems, including:
+//! - Equal temperament, just intonation and Pythagorean tuning
+//! - Frequency ratios of intervals in each tuning
+//! - Interval sizes in cents in each tuning
+//! - Tuning pairs of pitches relative to each other
+//!
+//! # Tuning Systems
+//!
+//! The pitches of this crate are equal-tempered: every semitone has the same
+//! frequency ratio of 2^(1/12). Other tuning systems derive interval sizes from
+//! whole-number frequency ratios instead:
+//!
+//! ```text
+//! Interval   Equal       Just     Pythagorean
+//! P1         1.0000      1/1      1/1
+//! m3         1.1892      6/5      32/27
+//! M3         1.2599      5/4      81/64
+//! P4         1.3348      4/3      4/3
+//! P5         1.4983      3/2      3/2
+//! P8         2.0000      2/1      2/1
+//! ```
+//!
+//! Intervals larger than an octave are tuned as the octave-reduced interval
+//! stacked on pure octaves.
+//!
+//! # Cents
+//!
+//! Interval sizes are compared in cents, hundredths of an equal-tempered
+//! semitone, so that an octave spans 1200 cents in every tuning:
+//!
+//! ```text
+//! Interval   Equal    Just      Pythagorean
+//! M3         400.0    386.3     407.8
+//! P5         700.0    702.0     702.0
+//! m7         1000.0   1017.6    996.1
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::TuningSystem;
+//! use mozzart_core::constants::*;
+//!
+//! assert_eq!(TuningSystem::JustIntonation.ratio(PERFECT_FIFTH), 1.5);
+//! assert_eq!(TuningSystem::Pythagorean.ratio(MAJOR_THIRD), 81.0 / 64.0);
+//! assert!((TuningSystem::EqualTemperament.ratio(PERFECT_FIFTH) - 1.4983).abs() < 1e-4);
+//! ```
+
+use crate::{Interval, Pitch};
+
+/// Frequency ratios of the simple intervals in 5-limit just intonation.
+const JUST_RATIOS: [(u32, u32); crate::constants::SEMITONES_PER_OCTAVE as usize] = [
+    (1, 1),
+    (16, 15),
+    (9, 8),
+    (6, 5),
+    (5, 4),
+    (4, 3),
+    (45, 32),
+    (3, 2),
+    (8, 5),
+    (5, 3),
+    (9, 5),
+    (15, 8),
+];
+
+/// Frequency ratios of the simple intervals in Pythagorean tuning.
+const PYTHAGOREAN_RATIOS: [(u32, u32); crate::constants::SEMITONES_PER_OCTAVE as usize] = [
+    (1, 1),
+    (256, 243),
+    (9, 8),
+    (32, 27),
+    (81, 64),
+    (4, 3),
+    (729, 512),
+    (3, 2),
+    (128, 81),
+    (27, 16),
+    (16, 9),
+    (243, 128),
+];
+
+/// Represents a tuning system.
+///
+/// A tuning system decides the frequency ratio of every interval.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::TuningSystem;
+/// use mozzart_core::constants::*;
+///
+/// let tuning = TuningSystem::JustIntonation;
+/// assert_eq!(tuning.ratio(MAJOR_THIRD), 1.25);
+/// assert_eq!(tuning.ratio(PERFECT_OCTAVE), 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TuningSystem {
+    /// Twelve-tone equal temperament, where every semitone has a ratio of 2^(1/12).
+    #[default]
+    EqualTemperament,
+    /// 5-limit just intonation, built from ratios of small whole numbers.
+    JustIntonation,
+    /// Pythagorean tuning, built from stacked pure fifths (3/2).
+    Pythagorean,
+}
+
+impl TuningSystem {
+    /// Returns the frequency ratio of the given interval in this tuning system.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{Interval, TuningSystem};
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(TuningSystem::JustIntonation.ratio(MINOR_THIRD), 1.2);
+    ///
+    /// // A major tenth is a major third above an octave
+    /// assert_eq!(TuningSystem::JustIntonation.ratio(MAJOR_TENTH), 2.5);
+    /// ```
+    pub fn ratio(&self, interval: Interval) -> f64 {
+        let semitones = interval.semitones();
+        match self {
+            TuningSystem::EqualTemperament => {
+                2f64.powf(semitones as f64 / crate::constants::SEMITONES_PER_OCTAVE as f64)
+            }
+            TuningSystem::JustIntonation | TuningSystem::Pythagorean => {
+                let (numerator, denominator) = self.simple_ratio(semitones);
+                let octaves = (semitones / crate::constants::SEMITONES_PER_OCTAVE) as i32;
+                numerator as f64 / denominator as f64 * 2f64.powi(octaves)
+            }
+        }
+    }
+
+    /// Returns the frequencies in Hz of two pitches tuned relative to each other.
+    ///
+    /// The lower pitch keeps its equal-tempered frequency and the higher pitch is
+    /// tuned above it using the frequency ratio of the interval between them.
+    /// The frequencies are returned in the order of the arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::TuningSystem;
+    /// use mozzart_core::constants::*;
+    ///
+    /// let (a, e) = TuningSystem::JustIntonation.frequencies(A4, E5);
+    /// assert_eq!(a, 440.0);
+    /// assert_eq!(e, 660.0);
+    /// ```
+    pub fn frequencies(&self, a: Pitch, b: Pitch) -> (f64, f64) {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        let low_frequency = low.frequency();
+        let interval = Interval::new(high.semitones() - low.semitones());
+        let high_frequency = low_frequency * self.ratio(interval);
+
+        if a <= b {
+            (low_frequency, high_frequency)
+        } else {
+            (high_frequency, low_frequency)
+        }
+    }
+
+    /// Returns the whole-number ratio of the octave-reduced interval.
+    ///
+    /// Equal temperament has no whole-number ratios, so the just ratios are used.
+    fn simple_ratio(&self, semitones: u8) -> (u32, u32) {
+        let index = (semitones % crate::constants::SEMITONES_PER_OCTAVE) as usize;
+        match self {
+            TuningSystem::Pythagorean => PYTHAGOREAN_RATIOS[index],
+            _ => JUST_RATIOS[index],
+        }
+    }
+}
+
+impl Interval {
+    /// Returns the size of this interval in cents in the given tuning system.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::TuningSystem;
+    /// use mozzart_core::constants::*;
+    ///
+    /// assert_eq!(PERFECT_FIFTH.cents(TuningSystem::EqualTemperament), 700.0);
+    /// assert!((MAJOR_THIRD.cents(TuningSystem::JustIntonation) - 386.31).abs() < 0.01);
+    /// assert!((MAJOR_THIRD.cents(TuningSystem::Pythagorean) - 407.82).abs() < 0.01);
+    /// ```
+    pub fn cents(&self, tuning: TuningSystem) -> f64 {
+        match tuning {
+            TuningSystem::EqualTemperament => self.semitones() as f64 * 100.0,
+            _ => 1200.0 * tuning.ratio(*self).log2(),
+        }
+    }
+}
+
+/// Returns the just ratio of the given interval, including octaves, as a
+/// numerator and denominator.
+pub(crate) fn just_ratio(interval: Interval) -> (u32, u32) {
+    let semitones = interval.semitones();
+    let (numerator, denominator) = TuningSystem::JustIntonation.simple_ratio(semitones);
+    let octaves = (semitones / crate::constants::SEMITONES_PER_OCTAVE) as u32;
+    (numerator << octaves, denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    #[test]
+    fn test_equal_temperament_ratio() {
+        let tuning = TuningSystem::EqualTemperament;
+        assert_eq!(tuning.ratio(PERFECT_UNISON), 1.0);
+        assert_eq!(tuning.ratio(PERFECT_OCTAVE), 2.0);
+        assert!((tuning.ratio(DIMINISHED_FIFTH) - 2f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_just_and_pythagorean_ratios() {
+        assert_eq!(TuningSystem::JustIntonation.ratio(MAJOR_SIXTH), 5.0 / 3.0);
+        assert_eq!(TuningSystem::Pythagorean.ratio(MAJOR_SIXTH), 27.0 / 16.0);
+        assert_eq!(TuningSystem::Pythagorean.ratio(PERFECT_OCTAVE), 2.0);
+        assert_eq!(
+            TuningSystem::Pythagorean.ratio(PERFECT_TWELFTH),
+            2.0 * 3.0 / 2.0
+        );
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let (c, g) = TuningSystem::Pythagorean.frequencies(C4, G4);
+        assert_eq!(c, C4.frequency());
+        assert_eq!(g, C4.frequency() * 1.5);
+
+        let (g, c) = TuningSystem::Pythagorean.frequencies(G4, C4);
+        assert_eq!(c, C4.frequency());
+        assert_eq!(g, C4.frequency() * 1.5);
+
+        let (a, b) = TuningSystem::EqualTemperament.frequencies(A4, A4);
+        assert_eq!((a, b), (440.0, 440.0));
+    }
+
+    #[test]
+    fn test_cents() {
+        for tuning in [
+            TuningSystem::EqualTemperament,
+            TuningSystem::JustIntonation,
+            TuningSystem::Pythagorean,
+        ] {
+            assert_eq!(PERFECT_UNISON.cents(tuning), 0.0);
+            assert_eq!(PERFECT_OCTAVE.cents(tuning), 1200.0);
+            assert_eq!(DOUBLE_OCTAVE.cents(tuning), 2400.0);
+        }
+
+        assert_eq!(MINOR_SECOND.cents(TuningSystem::EqualTemperament), 100.0);
+        assert!((PERFECT_FIFTH.cents(TuningSystem::JustIntonation) - 701.955).abs() < 1e-3);
+        assert!((MINOR_SEVENTH.cents(TuningSystem::Pythagorean) - 996.09).abs() < 1e-2);
+
+        // The syntonic comma separates the Pythagorean and just major thirds
+        let comma = MAJOR_THIRD.cents(TuningSystem::Pythagorean)
+            - MAJOR_THIRD.cents(TuningSystem::JustIntonation);
+        assert!((comma - 21.506).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_just_ratio() {
+        assert_eq!(just_ratio(PERFECT_FIFTH), (3, 2));
+        assert_eq!(just_ratio(MAJOR_TENTH), (10, 4));
+    }
+}
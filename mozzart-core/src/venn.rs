@@ -0,0 +1,317 @@
+//! Venn comparison of pitch-class sets.
+//!
+//! This module provides functionality for comparing a few pitch-class sets at once,
+//! including:
+//! - The pitch classes shared by every set
+//! - The pitch classes unique to each set
+//! - Every region of the Venn diagram of the sets
+//!
+//! # Regions
+//!
+//! Every pitch class belongs to exactly one region of the diagram: the one for the
+//! sets containing it. Comparing C major, C lydian and C mixolydian:
+//!
+//! ```text
+//! Region                        Pitch classes
+//! major, lydian, mixolydian     C D E G A
+//! major, lydian                 B
+//! major, mixolydian             F
+//! lydian                        F#
+//! mixolydian                    A#
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::{PitchClassSet, Venn};
+//! use mozzart_core::constants::*;
+//!
+//! let major: PitchClassSet = [C, D, E, F, G, A, B].into_iter().collect();
+//! let lydian: PitchClassSet = [C, D, E, FSHARP, G, A, B].into_iter().collect();
+//! let mixolydian: PitchClassSet = [C, D, E, F, G, A, ASHARP].into_iter().collect();
+//!
+//! let venn = Venn::new([major, lydian, mixolydian]);
+//! assert_eq!(venn.shared().pitches(), [C, D, E, G, A]);
+//! assert_eq!(venn.region(&[0, 1]).pitches(), [B]);
+//! assert_eq!(venn.unique(1).pitches(), [FSHARP]);
+//! assert!(venn.unique(0).is_empty());
+//! ```
+
+use crate::{Pitch, PitchClassSet};
+
+/// The largest number of sets a Venn comparison can hold.
+const MAX_SETS: usize = u8::BITS as usize;
+
+/// A Venn comparison of pitch-class sets.
+///
+/// The sets are identified by their position in the comparison.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{PitchClassSet, Venn};
+/// use mozzart_core::constants::*;
+///
+/// let c_major: PitchClassSet = [C, D, E, F, G, A, B].into_iter().collect();
+/// let a_minor_pentatonic: PitchClassSet = [A, C, D, E, G].into_iter().collect();
+///
+/// let venn = Venn::new([c_major, a_minor_pentatonic]);
+/// assert_eq!(venn.unique(0).pitches(), [F, B]);
+/// assert!(venn.unique(1).is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Venn {
+    sets: Vec<PitchClassSet>,
+}
+
+/// A region of a Venn comparison.
+///
+/// A region holds the pitch classes contained in exactly a given group of sets.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::{PitchClassSet, Venn};
+/// use mozzart_core::constants::*;
+///
+/// let a: PitchClassSet = [C, E, G].into_iter().collect();
+/// let b: PitchClassSet = [A, C, E].into_iter().collect();
+///
+/// let regions = Venn::new([a, b]).regions();
+/// assert_eq!(regions.len(), 3);
+/// assert_eq!(regions[2].members(), [0, 1]);
+/// assert_eq!(regions[2].pitch_classes().pitches(), [C, E]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VennRegion {
+    members: u8,
+    pitch_classes: PitchClassSet,
+}
+
+impl Venn {
+    /// Creates a new Venn comparison of pitch-class sets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than eight sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{PitchClassSet, Venn};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let triad: PitchClassSet = [C, E, G].into_iter().collect();
+    /// let venn = Venn::new([triad, triad.transpose(PERFECT_FIFTH)]);
+    /// assert_eq!(venn.sets().len(), 2);
+    /// ```
+    pub fn new<I>(sets: I) -> Self
+    where
+        I: IntoIterator<Item = PitchClassSet>,
+    {
+        let sets: Vec<_> = sets.into_iter().collect();
+        assert!(sets.len() <= MAX_SETS, "too many sets in a Venn comparison");
+        Self { sets }
+    }
+
+    /// Returns the compared sets.
+    #[inline]
+    pub fn sets(&self) -> &[PitchClassSet] {
+        &self.sets
+    }
+
+    /// Returns the pitch classes contained in every set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{PitchClassSet, Venn};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c: PitchClassSet = [C, E, G].into_iter().collect();
+    /// let e_minor: PitchClassSet = [E, G, B].into_iter().collect();
+    /// let g: PitchClassSet = [G, B, D].into_iter().collect();
+    ///
+    /// assert_eq!(Venn::new([c, e_minor, g]).shared().pitches(), [G]);
+    /// ```
+    pub fn shared(&self) -> PitchClassSet {
+        self.region_of(self.all_members())
+    }
+
+    /// Returns the pitch classes contained only in the set at `index`, or an empty
+    /// set if there is no set at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{PitchClassSet, Venn};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c: PitchClassSet = [C, E, G].into_iter().collect();
+    /// let e_minor: PitchClassSet = [E, G, B].into_iter().collect();
+    ///
+    /// let venn = Venn::new([c, e_minor]);
+    /// assert_eq!(venn.unique(0).pitches(), [C]);
+    /// assert_eq!(venn.unique(1).pitches(), [B]);
+    /// assert!(venn.unique(2).is_empty());
+    /// ```
+    pub fn unique(&self, index: usize) -> PitchClassSet {
+        self.region(&[index])
+    }
+
+    /// Returns the pitch classes contained in exactly the sets at `members`, or an
+    /// empty set if a member is not the index of a set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{PitchClassSet, Venn};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c: PitchClassSet = [C, E, G].into_iter().collect();
+    /// let e_minor: PitchClassSet = [E, G, B].into_iter().collect();
+    /// let a_minor: PitchClassSet = [A, C, E].into_iter().collect();
+    ///
+    /// let venn = Venn::new([c, e_minor, a_minor]);
+    /// assert_eq!(venn.region(&[0, 1]).pitches(), [G]);
+    /// assert_eq!(venn.region(&[0, 2]).pitches(), [C]);
+    /// assert!(venn.region(&[1, 2]).is_empty());
+    /// ```
+    pub fn region(&self, members: &[usize]) -> PitchClassSet {
+        if members.iter().any(|&index| index >= self.sets.len()) {
+            return PitchClassSet::new();
+        }
+        let members = members.iter().fold(0, |mask, &index| mask | 1 << index);
+        self.region_of(members)
+    }
+
+    /// Returns the non-empty regions of the comparison.
+    ///
+    /// Regions are ordered by their members, compared as binary numbers with the
+    /// first set as the lowest bit: the region of the first set comes first, then
+    /// the region of the second set, then the region of both, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::{PitchClassSet, Venn};
+    /// use mozzart_core::constants::*;
+    ///
+    /// let c: PitchClassSet = [C, E, G].into_iter().collect();
+    /// let c_minor: PitchClassSet = [C, DSHARP, G].into_iter().collect();
+    ///
+    /// let regions = Venn::new([c, c_minor]).regions();
+    /// let members: Vec<_> = regions.iter().map(|region| region.members()).collect();
+    /// assert_eq!(members, [vec![0], vec![1], vec![0, 1]]);
+    /// ```
+    pub fn regions(&self) -> Vec<VennRegion> {
+        (1..=self.all_members())
+            .map(|members| VennRegion {
+                members,
+                pitch_classes: self.region_of(members),
+            })
+            .filter(|region| !region.pitch_classes.is_empty())
+            .collect()
+    }
+
+    /// Returns the mask with a bit set for every set.
+    fn all_members(&self) -> u8 {
+        (u8::MAX as u16 >> (MAX_SETS - self.sets.len())) as u8
+    }
+
+    /// Returns the pitch classes contained in exactly the sets of the mask.
+    fn region_of(&self, members: u8) -> PitchClassSet {
+        let octave = crate::constants::SEMITONES_PER_OCTAVE;
+        (0..octave)
+            .map(Pitch::new)
+            .filter(|&pitch| members != 0 && self.members_of(pitch) == members)
+            .collect()
+    }
+
+    /// Returns the mask of the sets containing a pitch class.
+    fn members_of(&self, pitch: Pitch) -> u8 {
+        self.sets
+            .iter()
+            .enumerate()
+            .filter(|(_, set)| set.contains(pitch))
+            .fold(0, |mask, (index, _)| mask | 1 << index)
+    }
+}
+
+impl VennRegion {
+    /// Returns the positions of the sets containing the pitch classes of this
+    /// region, in ascending order.
+    pub fn members(&self) -> Vec<usize> {
+        (0..MAX_SETS)
+            .filter(|index| self.members & (1 << index) != 0)
+            .collect()
+    }
+
+    /// Returns the pitch classes of this region.
+    #[inline]
+    pub fn pitch_classes(&self) -> PitchClassSet {
+        self.pitch_classes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn set(pitches: &[Pitch]) -> PitchClassSet {
+        pitches.iter().collect()
+    }
+
+    #[test]
+    fn test_empty_comparison() {
+        let venn = Venn::new([]);
+        assert!(venn.shared().is_empty());
+        assert!(venn.regions().is_empty());
+    }
+
+    #[test]
+    fn test_regions_partition_the_union() {
+        let major = set(&[C, D, E, F, G, A, B]);
+        let lydian = set(&[C, D, E, FSHARP, G, A, B]);
+        let mixolydian = set(&[C, D, E, F, G, A, ASHARP]);
+        let venn = Venn::new([major, lydian, mixolydian]);
+
+        let regions = venn.regions();
+        assert_eq!(regions.len(), 5);
+        assert_eq!(
+            regions
+                .iter()
+                .map(|r| r.pitch_classes().len())
+                .sum::<usize>(),
+            9
+        );
+        assert_eq!(regions[4].members(), [0, 1, 2]);
+        assert_eq!(regions[4].pitch_classes(), venn.shared());
+        assert_eq!(venn.region(&[0, 2]).pitches(), [F]);
+        assert_eq!(venn.unique(2).pitches(), [ASHARP]);
+    }
+
+    #[test]
+    fn test_eight_sets() {
+        let sets: Vec<_> = (0..8).map(|i| set(&[Pitch::new(i)])).collect();
+        let venn = Venn::new(sets);
+        assert_eq!(venn.regions().len(), 8);
+        assert_eq!(venn.unique(7).pitches(), [G]);
+    }
+
+    #[test]
+    fn test_members_out_of_range() {
+        let venn = Venn::new([set(&[C, E, G]), set(&[E, G, B])]);
+        assert!(venn.unique(2).is_empty());
+        assert!(venn.unique(8).is_empty());
+        assert!(venn.unique(usize::MAX).is_empty());
+        assert!(venn.region(&[0, 9]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_many_sets() {
+        Venn::new(vec![PitchClassSet::new(); 9]);
+    }
+}
@@ -82,8 +82,11 @@ impl Transposable<Octave> for Pitch {
     type Output = Pitch;
 
     fn transpose(&self, other: Octave) -> Self::Output {
-        let semitones = self.0 as i8 + other.0 * 12;
-        Pitch(semitones as u8)
+        let semitones = self.0 as i16 + other.0 as i16 * 12;
+        match u8::try_from(semitones) {
+            Ok(semitones) if semitones <= 127 => Pitch(semitones),
+            _ => panic!("pitch transposed outside the MIDI range"),
+        }
     }
 }
 
@@ -112,3 +115,27 @@ impl<T: ScaleType> Scale<T> {
         format!("{:?} {}", self.root(), T::name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose_octave() {
+        assert_eq!(Pitch(60).transpose(Octave(1)), Pitch(72));
+        assert_eq!(Pitch(60).transpose(Octave(-5)), Pitch(0));
+        assert_eq!(Pitch(115).transpose(Octave(1)), Pitch(127));
+    }
+
+    #[test]
+    #[should_panic(expected = "pitch transposed outside the MIDI range")]
+    fn test_transpose_octave_above_range() {
+        Pitch(120).transpose(Octave(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "pitch transposed outside the MIDI range")]
+    fn test_transpose_octave_below_range() {
+        Pitch(11).transpose(Octave(-1));
+    }
+}
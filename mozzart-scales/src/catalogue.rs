@@ -0,0 +1,735 @@
+//! Scale and mode name lookup.
+//!
+//! The catalogue lists the conventional names of the common scales and of the
+//! modes of the major, melodic minor, harmonic minor and harmonic major scales.
+//! It is used to name interval patterns that were not built from a scale pattern
+//! type, such as the modes of a scale, and to select scales by name at runtime.
+//!
+//! # Matching
+//!
+//! A pattern matches a catalogued scale when both hold the same pitch classes
+//! above the root. The root is always counted, and intervals of an octave or more
+//! are reduced to their simple counterpart:
+//!
+//! ```text
+//! Pattern                      Names
+//! 0 2 3 5 7 9 10               dorian
+//! 0 2 4 5 7 9 11               major, ionian
+//! 2 3 5 7 9 10 (no unison)     dorian
+//! ```
+use std::borrow::{Borrow, Cow};
+
+use mozzart_core::constants::PITCHES;
+use mozzart_core::{BoxedScalePattern, DynScale, Interval, Pitch, PitchClassSet, Scale, ScaleType};
+
+/// The catalogued names of scale pattern types of this crate that share their
+/// pattern with a scale catalogued before them.
+const MODE_NAMES: &[&str] = &["aeolian"];
+
+/// The catalogued scales, as names and semitones above the root.
+///
+/// A pattern known under several names has one entry per name, the name of the
+/// scale pattern type of this crate first.
+const CATALOGUE: &[(&str, &[u8])] = &[
+    // Modes of the major scale
+    ("major", &[0, 2, 4, 5, 7, 9, 11]),
+    ("ionian", &[0, 2, 4, 5, 7, 9, 11]),
+    ("dorian", &[0, 2, 3, 5, 7, 9, 10]),
+    ("phrygian", &[0, 1, 3, 5, 7, 8, 10]),
+    ("lydian", &[0, 2, 4, 6, 7, 9, 11]),
+    ("mixolydian", &[0, 2, 4, 5, 7, 9, 10]),
+    ("natural minor", &[0, 2, 3, 5, 7, 8, 10]),
+    ("aeolian", &[0, 2, 3, 5, 7, 8, 10]),
+    ("locrian", &[0, 1, 3, 5, 6, 8, 10]),
+    // Modes of the melodic minor scale
+    ("melodic minor", &[0, 2, 3, 5, 7, 9, 11]),
+    ("dorian b2", &[0, 1, 3, 5, 7, 9, 10]),
+    ("phrygian #6", &[0, 1, 3, 5, 7, 9, 10]),
+    ("lydian augmented", &[0, 2, 4, 6, 8, 9, 11]),
+    ("lydian dominant", &[0, 2, 4, 6, 7, 9, 10]),
+    ("acoustic", &[0, 2, 4, 6, 7, 9, 10]),
+    ("mixolydian b6", &[0, 2, 4, 5, 7, 8, 10]),
+    ("aeolian dominant", &[0, 2, 4, 5, 7, 8, 10]),
+    ("locrian #2", &[0, 2, 3, 5, 6, 8, 10]),
+    ("half-diminished", &[0, 2, 3, 5, 6, 8, 10]),
+    ("altered", &[0, 1, 3, 4, 6, 8, 10]),
+    ("super locrian", &[0, 1, 3, 4, 6, 8, 10]),
+    // Modes of the harmonic minor scale
+    ("harmonic minor", &[0, 2, 3, 5, 7, 8, 11]),
+    ("locrian #6", &[0, 1, 3, 5, 6, 9, 10]),
+    ("ionian #5", &[0, 2, 4, 5, 8, 9, 11]),
+    ("ionian augmented", &[0, 2, 4, 5, 8, 9, 11]),
+    ("dorian #4", &[0, 2, 3, 6, 7, 9, 10]),
+    ("ukrainian dorian", &[0, 2, 3, 6, 7, 9, 10]),
+    ("phrygian dominant", &[0, 1, 4, 5, 7, 8, 10]),
+    ("spanish phrygian", &[0, 1, 4, 5, 7, 8, 10]),
+    ("lydian #2", &[0, 3, 4, 6, 7, 9, 11]),
+    ("ultralocrian", &[0, 1, 3, 4, 6, 8, 9]),
+    ("altered diminished", &[0, 1, 3, 4, 6, 8, 9]),
+    // Modes of the harmonic major scale
+    ("harmonic major", &[0, 2, 4, 5, 7, 8, 11]),
+    ("dorian b5", &[0, 2, 3, 5, 6, 9, 10]),
+    ("phrygian b4", &[0, 1, 3, 4, 7, 8, 10]),
+    ("lydian b3", &[0, 2, 3, 6, 7, 9, 11]),
+    ("mixolydian b2", &[0, 1, 4, 5, 7, 9, 10]),
+    ("lydian augmented #2", &[0, 3, 4, 6, 8, 9, 11]),
+    ("locrian bb7", &[0, 1, 3, 5, 6, 8, 9]),
+    // Octatonic scales
+    ("bebop dominant", &[0, 2, 4, 5, 7, 9, 10, 11]),
+    ("bebop major", &[0, 2, 4, 5, 7, 8, 9, 11]),
+    ("bebop melodic minor", &[0, 2, 3, 5, 7, 8, 9, 11]),
+    ("half-whole diminished", &[0, 1, 3, 4, 6, 7, 9, 10]),
+    ("dominant diminished", &[0, 1, 3, 4, 6, 7, 9, 10]),
+    ("whole-half diminished", &[0, 2, 3, 5, 6, 8, 9, 11]),
+    ("diminished", &[0, 2, 3, 5, 6, 8, 9, 11]),
+    // Hexatonic scales
+    ("blues", &[0, 3, 5, 6, 7, 10]),
+    ("whole tone", &[0, 2, 4, 6, 8, 10]),
+    ("augmented", &[0, 3, 4, 7, 8, 11]),
+    ("prometheus", &[0, 2, 4, 6, 9, 10]),
+    ("mystic", &[0, 2, 4, 6, 9, 10]),
+    ("blues major", &[0, 2, 3, 4, 7, 9]),
+    ("major blues", &[0, 2, 3, 4, 7, 9]),
+    // Pentatonic scales
+    ("pentatonic major", &[0, 2, 4, 7, 9]),
+    ("pentatonic minor", &[0, 3, 5, 7, 10]),
+    ("hirajoshi", &[0, 2, 3, 7, 8]),
+    ("in-sen", &[0, 1, 5, 7, 10]),
+    ("yo", &[0, 2, 5, 7, 9]),
+    ("egyptian", &[0, 2, 5, 7, 10]),
+    ("suspended pentatonic", &[0, 2, 5, 7, 10]),
+];
+
+/// Returns the conventional names of the scale with the given interval pattern,
+/// or an empty vector if the pattern is not catalogued.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::names_for;
+///
+/// let dorian = [
+///     PERFECT_UNISON,
+///     MAJOR_SECOND,
+///     MINOR_THIRD,
+///     PERFECT_FOURTH,
+///     PERFECT_FIFTH,
+///     MAJOR_SIXTH,
+///     MINOR_SEVENTH,
+/// ];
+/// assert_eq!(names_for(dorian), ["dorian"]);
+///
+/// let ionian = [
+///     PERFECT_UNISON,
+///     MAJOR_SECOND,
+///     MAJOR_THIRD,
+///     PERFECT_FOURTH,
+///     PERFECT_FIFTH,
+///     MAJOR_SIXTH,
+///     MAJOR_SEVENTH,
+/// ];
+/// assert_eq!(names_for(ionian), ["major", "ionian"]);
+///
+/// assert!(names_for([PERFECT_UNISON, MINOR_SECOND, MAJOR_SECOND]).is_empty());
+/// ```
+pub fn names_for<I>(pattern: I) -> Vec<&'static str>
+where
+    I: IntoIterator<Item = Interval>,
+{
+    let set = pitch_classes(pattern.into_iter().map(|interval| interval.semitones()));
+    CATALOGUE
+        .iter()
+        .filter(|(_, semitones)| pitch_classes(semitones.iter().copied()) == set)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Returns the interval pattern of the catalogued scale with the given name, or
+/// `None` if no scale has that name.
+///
+/// Names are compared ignoring ASCII case.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::intervals_for;
+///
+/// assert_eq!(
+///     intervals_for("Pentatonic Minor").unwrap(),
+///     [PERFECT_UNISON, MINOR_THIRD, PERFECT_FOURTH, PERFECT_FIFTH, MINOR_SEVENTH]
+/// );
+/// assert_eq!(intervals_for("aeolian"), intervals_for("natural minor"));
+/// assert_eq!(intervals_for("bebop"), None);
+/// ```
+pub fn intervals_for(name: &str) -> Option<Vec<Interval>> {
+    CATALOGUE
+        .iter()
+        .find(|(catalogued, _)| catalogued.eq_ignore_ascii_case(name))
+        .map(|(_, semitones)| semitones.iter().copied().map(Interval::new).collect())
+}
+
+/// Returns the pattern of the catalogued scale with the given name or alias, or
+/// `None` if no scale has that name.
+///
+/// Names are compared ignoring ASCII case. A scale known under several names is
+/// named after the first of them, so that aliases such as "ionian" resolve to the
+/// name of the scale pattern type of this crate. Names of scale pattern types of
+/// their own, such as "aeolian", keep their name.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::lookup;
+///
+/// let dorian = lookup("dorian").unwrap();
+/// assert_eq!(dorian.apply(D4), [D4, E4, F4, G4, A4, B4, C5]);
+///
+/// assert_eq!(lookup("Aeolian").unwrap().name(), "aeolian");
+/// assert_eq!(lookup("ionian"), lookup("major"));
+/// assert_eq!(lookup("bebop"), None);
+/// ```
+pub fn lookup(name: &str) -> Option<BoxedScalePattern> {
+    let intervals = intervals_for(name)?;
+    let name = match MODE_NAMES
+        .iter()
+        .find(|mode| mode.eq_ignore_ascii_case(name))
+    {
+        Some(mode) => mode,
+        None => names_for(intervals.iter().copied())[0],
+    };
+    Some(BoxedScalePattern::from_intervals(name, intervals))
+}
+
+/// Returns the catalogued scale names and aliases, in catalogue order.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_scales::{lookup, names};
+///
+/// assert!(names().any(|name| name == "lydian dominant"));
+/// assert!(names().all(|name| lookup(name).is_some()));
+/// ```
+pub fn names() -> impl Iterator<Item = &'static str> {
+    CATALOGUE.iter().map(|(name, _)| *name)
+}
+
+/// Returns the catalogued scales containing every pitch of a chord, in any
+/// octave, on every root from C to B.
+///
+/// Each scale is listed once, under its first catalogued name, and starts on a
+/// canonical pitch. The scales are ordered by root, then in catalogue order.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::compatible_scales;
+///
+/// // Scales over Bm7b5
+/// let scales = compatible_scales([B3, D4, F4, A4]);
+/// let names: Vec<_> = scales.iter().map(|scale| scale.to_string()).collect();
+/// assert!(names.contains(&"C major".to_string()));
+/// assert!(names.contains(&"B locrian".to_string()));
+/// assert!(names.contains(&"B locrian #2".to_string()));
+/// assert!(!names.contains(&"B phrygian".to_string()));
+/// ```
+pub fn compatible_scales<I, P>(chord: I) -> Vec<DynScale>
+where
+    I: IntoIterator<Item = P>,
+    P: Borrow<Pitch>,
+{
+    scales_containing(&chord.into_iter().collect())
+        .map(|(root, name, semitones)| {
+            let pattern = semitones.iter().copied().map(Interval::new);
+            DynScale::new(name, root.apply_pattern(pattern))
+        })
+        .collect()
+}
+
+/// Returns the roots and names of the catalogued scales containing every given
+/// pitch, in any octave.
+///
+/// The pitches may be the whole scale or only some of its pitches, so that a
+/// melody finds every scale it fits in. Each scale is listed once per root,
+/// under its first catalogued name, with the root as a canonical pitch. The
+/// scales are ordered by root, then in catalogue order.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::find_scales;
+///
+/// // The whole tone scale is the same on each of its pitches
+/// let scales = find_scales(&[E4, FSHARP4, GSHARP4, ASHARP4, C5, D5]);
+/// assert_eq!(scales.len(), 6);
+/// assert_eq!(scales[0], (C, "whole tone"));
+/// assert!(scales.iter().all(|&(_, name)| name == "whole tone"));
+///
+/// let scales = find_scales(&[C4, E4, G4, B4, D5, FSHARP5]);
+/// assert!(scales.contains(&(G, "major")));
+/// assert!(scales.contains(&(C, "lydian")));
+/// assert!(!scales.contains(&(C, "major")));
+/// ```
+pub fn find_scales(pitches: &[Pitch]) -> Vec<(Pitch, &'static str)> {
+    scales_containing(&pitches.iter().collect())
+        .map(|(root, name, _)| (root, name))
+        .collect()
+}
+
+/// Returns the catalogued scales with the same number of pitch classes as a scale,
+/// on every root from C to B, with their voice-leading distance to the scale,
+/// closest first.
+///
+/// Scales holding the same pitch classes as the scale, such as its modes, are
+/// left out. Each scale is listed under its first catalogued name and starts on
+/// a canonical pitch. Scales at the same distance are ordered by root, then in
+/// catalogue order.
+///
+/// See [`Scale::distance`] for the distance.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScalePattern;
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::{MajorScalePattern, closest_scales};
+///
+/// let closest = closest_scales(&MajorScalePattern::apply(C4));
+/// let nearest: Vec<_> = closest
+///     .iter()
+///     .take_while(|(_, distance)| *distance == 1)
+///     .map(|(scale, _)| scale.to_string())
+///     .collect();
+///
+/// // One semitone away: the neighbouring keys, and the minor scales sharing six notes
+/// assert!(nearest.contains(&"G major".to_string()));
+/// assert!(nearest.contains(&"F major".to_string()));
+/// assert!(nearest.contains(&"C melodic minor".to_string()));
+/// assert!(nearest.contains(&"A harmonic minor".to_string()));
+/// assert!(!nearest.contains(&"D major".to_string()));
+/// ```
+pub fn closest_scales<S: ScaleType>(scale: &Scale<S>) -> Vec<(DynScale, u32)> {
+    let set = scale.pitch_classes();
+    let mut scales: Vec<_> = PITCHES
+        .iter()
+        .flat_map(|&root| {
+            catalogued().map(move |&(name, semitones)| {
+                let pattern = semitones.iter().copied().map(Interval::new);
+                DynScale::new(name, root.apply_pattern(pattern))
+            })
+        })
+        .filter_map(|candidate| {
+            let other: PitchClassSet = candidate.pitches().iter().collect();
+            match set.voice_leading_distance(&other) {
+                Some(distance) if distance > 0 => Some((candidate, distance)),
+                _ => None,
+            }
+        })
+        .collect();
+    scales.sort_by_key(|&(_, distance)| distance);
+    scales
+}
+
+/// Returns the catalogue entries listed under the first name of their pattern.
+fn catalogued() -> impl Iterator<Item = &'static (&'static str, &'static [u8])> {
+    CATALOGUE.iter().filter(|(name, semitones)| {
+        names_for(semitones.iter().copied().map(Interval::new))[0] == *name
+    })
+}
+
+/// Returns the root, first name and semitones of the catalogued scales containing
+/// a set of pitch classes, on every root from C to B.
+fn scales_containing(
+    set: &PitchClassSet,
+) -> impl Iterator<Item = (Pitch, &'static str, &'static [u8])> + '_ {
+    PITCHES.iter().flat_map(move |&root| {
+        catalogued()
+            .filter(move |(_, semitones)| {
+                let scale: PitchClassSet = semitones
+                    .iter()
+                    .map(|&semitone| root.transpose(Interval::new(semitone)))
+                    .collect();
+                set.is_subset(&scale)
+            })
+            .map(move |&(name, semitones)| (root, name, semitones))
+    })
+}
+
+/// Returns the nth mode of a scale, counted from 1 for the scale itself, or
+/// `None` for mode 0 and for modes past the last degree of the scale.
+///
+/// The mode starts on the nth degree of the scale, with the degrees below it
+/// raised by an octave, as for [`Scale::rotate`]. It is named after the first
+/// catalogued scale with its pattern, or as the mode of the scale if it is not
+/// catalogued.
+///
+/// # Panics
+///
+/// Panics if a pitch of the mode would be above G9, the top of the MIDI range.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScalePattern;
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::{BluesScalePattern, MajorScalePattern, mode};
+///
+/// let c_major = MajorScalePattern::apply(C4);
+///
+/// let d_dorian = mode(&c_major, 2).unwrap();
+/// assert_eq!(d_dorian.to_string(), "D4 dorian");
+/// assert_eq!(d_dorian.pitches(), [D4, E4, F4, G4, A4, B4, C5]);
+///
+/// assert_eq!(mode(&c_major, 5).unwrap().name(), "mixolydian");
+/// assert_eq!(mode(&c_major, 8), None);
+///
+/// let blues = BluesScalePattern::apply(A3);
+/// assert_eq!(mode(&blues, 2).unwrap().name(), "blues major");
+/// assert_eq!(mode(&blues, 3).unwrap().name(), "mode 3 of blues");
+/// ```
+pub fn mode<S: ScaleType>(scale: &Scale<S>, n: usize) -> Option<DynScale> {
+    if n == 0 || n > scale.len() {
+        return None;
+    }
+
+    let pitches = scale.rotate(n - 1);
+    let root = pitches[0].semitones();
+    let pattern = pitches
+        .iter()
+        .map(|pitch| Interval::new(pitch.semitones() - root));
+    let name: Cow<'static, str> = match names_for(pattern).first() {
+        Some(name) => (*name).into(),
+        None => format!("mode {} of {}", n, scale.name()).into(),
+    };
+    Some(DynScale::new(name, pitches))
+}
+
+/// Extension methods for building the modes of a scale.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScalePattern;
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::{MajorScalePattern, ModeExt};
+///
+/// let c_major = MajorScalePattern::apply(C4);
+/// assert_eq!(c_major.mode(2).unwrap().to_string(), "D4 dorian");
+/// assert_eq!(c_major.mode(6).unwrap().to_string(), "A4 natural minor");
+/// ```
+pub trait ModeExt {
+    /// Returns the nth mode of the scale as a new scale rooted on its nth degree,
+    /// counted from 1 for the scale itself, or `None` for mode 0 and for modes
+    /// past the last degree of the scale. See [`mode`] for how the mode is named.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pitch of the mode would be above G9, the top of the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScalePattern;
+    /// use mozzart_core::constants::*;
+    /// use mozzart_scales::{HarmonicMinorScalePattern, ModeExt};
+    ///
+    /// let a_harmonic_minor = HarmonicMinorScalePattern::apply(A3);
+    /// let mode = a_harmonic_minor.mode(5).unwrap();
+    /// assert_eq!(mode.root(), Some(E4));
+    /// assert_eq!(mode.name(), "phrygian dominant");
+    /// assert_eq!(a_harmonic_minor.mode(0), None);
+    /// ```
+    fn mode(&self, n: usize) -> Option<DynScale>;
+}
+
+impl<S: ScaleType> ModeExt for Scale<S> {
+    fn mode(&self, n: usize) -> Option<DynScale> {
+        mode(self, n)
+    }
+}
+
+/// Returns the pitch classes of a pattern built on C, including the root.
+fn pitch_classes<I>(semitones: I) -> PitchClassSet
+where
+    I: IntoIterator<Item = u8>,
+{
+    std::iter::once(0)
+        .chain(semitones)
+        .map(|semitones| Pitch::new(semitones).canonical())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use mozzart_core::constants::*;
+    use mozzart_core::{Locale, ScalePattern};
+
+    #[test]
+    fn test_names_of_scale_patterns() {
+        assert_eq!(names_for(MajorScalePattern::PATTERN)[0], "major");
+        assert_eq!(
+            names_for(NaturalMinorScalePattern::PATTERN)[0],
+            "natural minor"
+        );
+        assert_eq!(names_for(DorianScalePattern::PATTERN), ["dorian"]);
+        assert_eq!(
+            names_for(AeolianScalePattern::PATTERN),
+            ["natural minor", "aeolian"]
+        );
+        assert_eq!(
+            names_for(HarmonicMinorScalePattern::PATTERN),
+            ["harmonic minor"]
+        );
+        assert_eq!(
+            names_for(HarmonicMajorScalePattern::PATTERN),
+            ["harmonic major"]
+        );
+        assert_eq!(
+            names_for(MelodicMinorScalePattern::PATTERN),
+            ["melodic minor"]
+        );
+        assert_eq!(
+            names_for(HalfWholeDiminishedScalePattern::PATTERN),
+            ["half-whole diminished", "dominant diminished"]
+        );
+        assert_eq!(
+            names_for(WholeHalfDiminishedScalePattern::PATTERN),
+            ["whole-half diminished", "diminished"]
+        );
+        assert_eq!(names_for(BluesScalePattern::PATTERN), ["blues"]);
+        assert_eq!(names_for(WholeToneScalePattern::PATTERN), ["whole tone"]);
+        assert_eq!(
+            names_for(PentatonicMajorScalePattern::PATTERN),
+            ["pentatonic major"]
+        );
+        assert_eq!(
+            names_for(PentatonicMinorScalePattern::PATTERN),
+            ["pentatonic minor"]
+        );
+    }
+
+    #[test]
+    fn test_modes_are_rotations() {
+        // Every mode of a seven-note family starts on a degree of the parent scale
+        let parents = ["major", "melodic minor", "harmonic minor", "harmonic major"];
+        for parent in parents {
+            let (_, semitones) = CATALOGUE.iter().find(|(name, _)| *name == parent).unwrap();
+            for degree in semitones.iter() {
+                let mode = semitones
+                    .iter()
+                    .map(|semitone| Interval::new((semitone + 12 - degree) % 12));
+                assert!(!names_for(mode).is_empty(), "{parent} from {degree}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_intervals_for_names() {
+        for (name, _) in CATALOGUE {
+            let intervals = intervals_for(name).unwrap();
+            assert!(names_for(intervals).contains(name));
+        }
+        assert_eq!(
+            intervals_for("MAJOR").unwrap(),
+            MajorScalePattern::intervals()
+        );
+    }
+
+    #[test]
+    fn test_lookup_scale_patterns() {
+        fn check<P: ScalePattern>() {
+            let pattern = lookup(P::name()).unwrap();
+            assert_eq!(pattern, BoxedScalePattern::new::<P>());
+            for alias in P::aliases() {
+                assert_eq!(
+                    lookup(alias),
+                    Some(BoxedScalePattern::new::<P>()),
+                    "{alias}"
+                );
+            }
+        }
+
+        check::<MajorScalePattern>();
+        check::<NaturalMinorScalePattern>();
+        check::<AeolianScalePattern>();
+        check::<DorianScalePattern>();
+        check::<PhrygianScalePattern>();
+        check::<LydianScalePattern>();
+        check::<MixolydianScalePattern>();
+        check::<LocrianScalePattern>();
+        check::<HarmonicMinorScalePattern>();
+        check::<LocrianSharp6ScalePattern>();
+        check::<IonianSharp5ScalePattern>();
+        check::<DorianSharp4ScalePattern>();
+        check::<PhrygianDominantScalePattern>();
+        check::<LydianSharp2ScalePattern>();
+        check::<UltralocrianScalePattern>();
+        check::<HarmonicMajorScalePattern>();
+        check::<MelodicMinorScalePattern>();
+        check::<DorianFlat2ScalePattern>();
+        check::<LydianAugmentedScalePattern>();
+        check::<LydianDominantScalePattern>();
+        check::<MixolydianFlat6ScalePattern>();
+        check::<LocrianSharp2ScalePattern>();
+        check::<AlteredScalePattern>();
+        check::<BebopDominantScalePattern>();
+        check::<BebopMajorScalePattern>();
+        check::<BebopMelodicMinorScalePattern>();
+        check::<HalfWholeDiminishedScalePattern>();
+        check::<WholeHalfDiminishedScalePattern>();
+        check::<BluesScalePattern>();
+        check::<WholeToneScalePattern>();
+        check::<AugmentedScalePattern>();
+        check::<PrometheusScalePattern>();
+        check::<BluesMajorScalePattern>();
+        check::<PentatonicMajorScalePattern>();
+        check::<PentatonicMinorScalePattern>();
+        check::<HirajoshiScalePattern>();
+        check::<InSenScalePattern>();
+        check::<YoScalePattern>();
+        check::<EgyptianScalePattern>();
+    }
+
+    #[test]
+    fn test_find_scales() {
+        // A full scale finds its modes, and the larger scales holding its pitches
+        let scales = find_scales(MajorScalePattern::apply(D4).pitches());
+        let modes = [
+            (D, "major"),
+            (E, "dorian"),
+            (FSHARP, "phrygian"),
+            (G, "lydian"),
+            (A, "mixolydian"),
+            (B, "natural minor"),
+            (CSHARP, "locrian"),
+        ];
+        assert!(modes.iter().all(|mode| scales.contains(mode)));
+        let heptatonic = scales
+            .iter()
+            .filter(|(_, name)| intervals_for(name).unwrap().len() == 7);
+        assert_eq!(heptatonic.count(), modes.len());
+        assert!(scales.contains(&(A, "bebop dominant")));
+
+        // Fewer pitches fit in more scales
+        let fewer = find_scales(&[D4, FSHARP4, A4]);
+        assert!(fewer.len() > scales.len());
+        assert!(scales.iter().all(|scale| fewer.contains(scale)));
+
+        // The octaves of the pitches do not matter
+        assert_eq!(find_scales(&[D1, FSHARP7, A4]), fewer);
+    }
+
+    #[test]
+    fn test_closest_scales() {
+        let a_minor = NaturalMinorScalePattern::apply(A3);
+        let closest = closest_scales(&a_minor);
+        assert!(closest.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(closest.iter().all(|(scale, _)| scale.len() == 7));
+        assert!(
+            closest
+                .iter()
+                .all(|(scale, _)| !scale.pitches().iter().all(|&p| a_minor.contains(p)))
+        );
+
+        let distance = |name: &str| {
+            closest
+                .iter()
+                .find(|(scale, _)| scale.to_string() == name)
+                .map(|&(_, distance)| distance)
+        };
+        assert_eq!(distance("A harmonic minor"), Some(1));
+        assert_eq!(distance("A melodic minor"), Some(2));
+        assert_eq!(distance("A major"), Some(3));
+        assert_eq!(distance("C major"), None);
+
+        // Pentatonic scales are compared with pentatonic scales only
+        let pentatonic = closest_scales(&PentatonicMajorScalePattern::apply(C4));
+        assert!(pentatonic.iter().all(|(scale, _)| scale.len() == 5));
+    }
+
+    #[test]
+    fn test_modes() {
+        let a_minor = NaturalMinorScalePattern::apply(A3);
+        let names: Vec<_> = (1..=7)
+            .map(|n| mode(&a_minor, n).unwrap().name().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "natural minor",
+                "locrian",
+                "major",
+                "dorian",
+                "phrygian",
+                "lydian",
+                "mixolydian"
+            ]
+        );
+
+        let c_melodic = MelodicMinorScalePattern::apply(C4);
+        let altered = mode(&c_melodic, 7).unwrap();
+        assert_eq!(altered.name(), "altered");
+        assert_eq!(altered.root(), Some(B4));
+
+        assert_eq!(mode(&a_minor, 0), None);
+        assert_eq!(
+            mode(&PentatonicMajorScalePattern::apply(C4), 5)
+                .unwrap()
+                .name(),
+            "pentatonic minor"
+        );
+
+        // Modes are also built as a method of the scale
+        assert_eq!(a_minor.mode(4), mode(&a_minor, 4));
+        assert_eq!(a_minor.mode(4).unwrap().to_string(), "D4 dorian");
+        assert_eq!(a_minor.mode(8), None);
+    }
+
+    #[test]
+    fn test_compatible_scales() {
+        // A single pitch fits in a scale on as many roots as the scale has pitches
+        let expected: usize = names()
+            .filter_map(|name| {
+                intervals_for(name).filter(|pattern| names_for(pattern.clone())[0] == name)
+            })
+            .map(|pattern| pattern.len())
+            .sum();
+        assert_eq!(compatible_scales([C4]).len(), expected);
+
+        // The whole tone scale holds C7#5 on each of its six roots
+        let scales = compatible_scales([C4, E4, GSHARP4, ASHARP4]);
+        let whole_tone: Vec<_> = scales
+            .iter()
+            .filter(|scale| scale.name() == "whole tone")
+            .map(|scale| scale.root().unwrap())
+            .collect();
+        assert_eq!(whole_tone, [C, D, E, FSHARP, GSHARP, ASHARP]);
+        assert!(scales.iter().all(|scale| scale.name() != "major"));
+    }
+
+    #[test]
+    fn test_names_are_translated() {
+        // German names are capitalized or compounded, so none matches its English name
+        for name in names() {
+            assert_ne!(Locale::German.translate(name), name, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_names_are_unique() {
+        for (index, (name, _)) in CATALOGUE.iter().enumerate() {
+            assert!(
+                CATALOGUE[index + 1..]
+                    .iter()
+                    .all(|(other, _)| other != name)
+            );
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Aeolian mode implementation.
+//!
+//! The aeolian mode is the sixth mode of the major scale. It has the same
+//! pitches as the natural minor scale, and is named as a mode when the music is
+//! described in terms of the modes of the major scale.
+//!
+//! # Scale Structure
+//!
+//! The aeolian mode follows the pattern of whole and half steps:
+//! ```text
+//! W H W W H W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The aeolian mode is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Perfect 4th (5) +
+//! Perfect 5th (7) + Minor 6th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for aeolian scales.
+///
+/// This type is used to distinguish aeolian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AeolianScaleType;
+
+impl ScaleType for AeolianScaleType {
+    fn name() -> &'static str {
+        "aeolian"
+    }
+}
+
+/// The pattern for a aeolian scale.
+///
+/// This pattern defines the sequence of intervals that make up a aeolian scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Perfect 4th +
+/// Perfect 5th + Minor 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AeolianScalePattern;
+
+impl ScalePattern for AeolianScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = AeolianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NaturalMinorScalePattern;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_aeolian_scale() {
+        let scale = AeolianScalePattern::apply(A4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], A4);
+        assert_eq!(pitches[1], B4);
+        assert_eq!(pitches[2], C5);
+        assert_eq!(pitches[3], D5);
+        assert_eq!(pitches[4], E5);
+        assert_eq!(pitches[5], F5);
+        assert_eq!(pitches[6], G5);
+
+        assert_eq!(scale.root(), A4);
+        assert_eq!(scale.name(), "aeolian");
+        assert_eq!(scale.to_string(), "A4 aeolian");
+    }
+
+    #[test]
+    fn test_aeolian_is_natural_minor() {
+        assert_eq!(
+            AeolianScalePattern::PATTERN,
+            NaturalMinorScalePattern::PATTERN
+        );
+        assert_eq!(
+            AeolianScalePattern::apply(E4).pitches(),
+            NaturalMinorScalePattern::apply(E4).pitches()
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Altered scale implementation.
+//!
+//! The altered scale, also known as the super locrian scale, is the seventh mode
+//! of the melodic minor scale. Above a major third, it holds every alteration of
+//! the fifth and ninth, and is played over altered dominant chords.
+//!
+//! # Scale Structure
+//!
+//! The altered scale follows the pattern of whole and half steps:
+//! ```text
+//! H W H W W W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The altered scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Minor 3rd (3) + Diminished 4th (4) +
+//! Diminished 5th (6) + Minor 6th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for altered scales.
+///
+/// This type is used to distinguish altered scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlteredScaleType;
+
+impl ScaleType for AlteredScaleType {
+    fn name() -> &'static str {
+        "altered"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["super locrian"]
+    }
+}
+
+/// The pattern for a altered scale.
+///
+/// This pattern defines the sequence of intervals that make up a altered scale:
+/// ```text
+/// Root + Minor 2nd + Minor 3rd + Diminished 4th +
+/// Diminished 5th + Minor 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlteredScalePattern;
+
+impl ScalePattern for AlteredScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MINOR_THIRD,
+        DIMINISHED_FOURTH,
+        DIMINISHED_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = AlteredScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MelodicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_altered_scale() {
+        let scale = AlteredScalePattern::apply(B3);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], B3);
+        assert_eq!(pitches[1], C4);
+        assert_eq!(pitches[2], D4);
+        assert_eq!(pitches[3], EFLAT4);
+        assert_eq!(pitches[4], F4);
+        assert_eq!(pitches[5], G4);
+        assert_eq!(pitches[6], A4);
+
+        assert_eq!(scale.root(), B3);
+        assert_eq!(scale.name(), "altered");
+        assert_eq!(scale.to_string(), "B3 altered");
+    }
+
+    #[test]
+    fn test_altered_is_seventh_mode_of_melodic_minor() {
+        let c_melodic_minor = MelodicMinorScalePattern::apply(C4);
+        let mode = mode(&c_melodic_minor, 7).unwrap();
+        assert_eq!(mode.pitches(), AlteredScalePattern::apply(B4).pitches());
+        assert_eq!(mode.name(), "altered");
+    }
+}
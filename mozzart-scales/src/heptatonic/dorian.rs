@@ -0,0 +1,98 @@
+//! Dorian mode implementation.
+//!
+//! The dorian mode is the second mode of the major scale. It is a minor scale
+//! with a raised sixth, giving it a brighter sound than the natural minor scale,
+//! and is used extensively in jazz, funk and folk music.
+//!
+//! # Scale Structure
+//!
+//! The dorian mode follows the pattern of whole and half steps:
+//! ```text
+//! W H W W W H W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The dorian mode is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Perfect 4th (5) +
+//! Perfect 5th (7) + Major 6th (9) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for dorian scales.
+///
+/// This type is used to distinguish dorian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DorianScaleType;
+
+impl ScaleType for DorianScaleType {
+    fn name() -> &'static str {
+        "dorian"
+    }
+}
+
+/// The pattern for a dorian scale.
+///
+/// This pattern defines the sequence of intervals that make up a dorian scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Perfect 4th +
+/// Perfect 5th + Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DorianScalePattern;
+
+impl ScalePattern for DorianScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = DorianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MajorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_dorian_scale() {
+        let scale = DorianScalePattern::apply(D4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], F4);
+        assert_eq!(pitches[3], G4);
+        assert_eq!(pitches[4], A4);
+        assert_eq!(pitches[5], B4);
+        assert_eq!(pitches[6], C5);
+
+        assert_eq!(scale.root(), D4);
+        assert_eq!(scale.name(), "dorian");
+        assert_eq!(scale.to_string(), "D4 dorian");
+    }
+
+    #[test]
+    fn test_dorian_is_second_mode_of_major() {
+        let c_major = MajorScalePattern::apply(C4);
+        let mode = mode(&c_major, 2).unwrap();
+        assert_eq!(mode.pitches(), DorianScalePattern::apply(D4).pitches());
+        assert_eq!(mode.name(), "dorian");
+    }
+}
@@ -0,0 +1,102 @@
+//! Dorian b2 scale implementation.
+//!
+//! The dorian b2 scale, also known as phrygian #6, is the second mode of the
+//! melodic minor scale. It is a dorian mode with a lowered second, and is played
+//! over suspended chords with a flat ninth.
+//!
+//! # Scale Structure
+//!
+//! The dorian b2 scale follows the pattern of whole and half steps:
+//! ```text
+//! H W W W W H W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The dorian b2 scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Minor 3rd (3) + Perfect 4th (5) +
+//! Perfect 5th (7) + Major 6th (9) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for dorian b2 scales.
+///
+/// This type is used to distinguish dorian b2 scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DorianFlat2ScaleType;
+
+impl ScaleType for DorianFlat2ScaleType {
+    fn name() -> &'static str {
+        "dorian b2"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["phrygian #6"]
+    }
+}
+
+/// The pattern for a dorian b2 scale.
+///
+/// This pattern defines the sequence of intervals that make up a dorian b2 scale:
+/// ```text
+/// Root + Minor 2nd + Minor 3rd + Perfect 4th +
+/// Perfect 5th + Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DorianFlat2ScalePattern;
+
+impl ScalePattern for DorianFlat2ScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = DorianFlat2ScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MelodicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_dorian_b2_scale() {
+        let scale = DorianFlat2ScalePattern::apply(D4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], EFLAT4);
+        assert_eq!(pitches[2], F4);
+        assert_eq!(pitches[3], G4);
+        assert_eq!(pitches[4], A4);
+        assert_eq!(pitches[5], B4);
+        assert_eq!(pitches[6], C5);
+
+        assert_eq!(scale.root(), D4);
+        assert_eq!(scale.name(), "dorian b2");
+        assert_eq!(scale.to_string(), "D4 dorian b2");
+    }
+
+    #[test]
+    fn test_dorian_b2_is_second_mode_of_melodic_minor() {
+        let c_melodic_minor = MelodicMinorScalePattern::apply(C4);
+        let mode = mode(&c_melodic_minor, 2).unwrap();
+        assert_eq!(mode.pitches(), DorianFlat2ScalePattern::apply(D4).pitches());
+        assert_eq!(mode.name(), "dorian b2");
+    }
+}
@@ -0,0 +1,106 @@
+//! Dorian #4 scale implementation.
+//!
+//! The dorian #4 scale, also known as the Ukrainian dorian scale, is the fourth
+//! mode of the harmonic minor scale. It is a dorian mode with a raised fourth,
+//! and is heard in Eastern European folk and klezmer music.
+//!
+//! # Scale Structure
+//!
+//! The dorian #4 scale follows the pattern of whole and half steps:
+//! ```text
+//! W H WH H W H W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The dorian #4 scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Augmented 4th (6) +
+//! Perfect 5th (7) + Major 6th (9) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for dorian #4 scales.
+///
+/// This type is used to distinguish dorian #4 scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DorianSharp4ScaleType;
+
+impl ScaleType for DorianSharp4ScaleType {
+    fn name() -> &'static str {
+        "dorian #4"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["ukrainian dorian"]
+    }
+}
+
+/// The pattern for a dorian #4 scale.
+///
+/// This pattern defines the sequence of intervals that make up a dorian #4 scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Augmented 4th +
+/// Perfect 5th + Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DorianSharp4ScalePattern;
+
+impl ScalePattern for DorianSharp4ScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        AUGMENTED_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = DorianSharp4ScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HarmonicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_dorian_sharp4_scale() {
+        let scale = DorianSharp4ScalePattern::apply(F4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], F4);
+        assert_eq!(pitches[1], G4);
+        assert_eq!(pitches[2], AFLAT4);
+        assert_eq!(pitches[3], B4);
+        assert_eq!(pitches[4], C5);
+        assert_eq!(pitches[5], D5);
+        assert_eq!(pitches[6], EFLAT5);
+
+        assert_eq!(scale.root(), F4);
+        assert_eq!(scale.name(), "dorian #4");
+        assert_eq!(scale.to_string(), "F4 dorian #4");
+    }
+
+    #[test]
+    fn test_dorian_sharp4_is_fourth_mode_of_harmonic_minor() {
+        let c_harmonic_minor = HarmonicMinorScalePattern::apply(C4);
+        let mode = mode(&c_harmonic_minor, 4).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            DorianSharp4ScalePattern::apply(F4).pitches()
+        );
+        assert_eq!(mode.name(), "dorian #4");
+    }
+}
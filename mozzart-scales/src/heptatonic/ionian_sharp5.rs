@@ -0,0 +1,106 @@
+//! Ionian #5 scale implementation.
+//!
+//! The ionian #5 scale, also known as ionian augmented, is the third mode of the
+//! harmonic minor scale. It is a major scale with a raised fifth, and is played
+//! over major seventh chords with an augmented fifth.
+//!
+//! # Scale Structure
+//!
+//! The ionian #5 scale follows the pattern of whole and half steps:
+//! ```text
+//! W W H WH H W H
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The ionian #5 scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Perfect 4th (5) +
+//! Augmented 5th (8) + Major 6th (9) + Major 7th (11)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for ionian #5 scales.
+///
+/// This type is used to distinguish ionian #5 scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IonianSharp5ScaleType;
+
+impl ScaleType for IonianSharp5ScaleType {
+    fn name() -> &'static str {
+        "ionian #5"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["ionian augmented"]
+    }
+}
+
+/// The pattern for a ionian #5 scale.
+///
+/// This pattern defines the sequence of intervals that make up a ionian #5 scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Perfect 4th +
+/// Augmented 5th + Major 6th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IonianSharp5ScalePattern;
+
+impl ScalePattern for IonianSharp5ScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        PERFECT_FOURTH,
+        AUGMENTED_FIFTH,
+        MAJOR_SIXTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = IonianSharp5ScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HarmonicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_ionian_sharp5_scale() {
+        let scale = IonianSharp5ScalePattern::apply(EFLAT4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], EFLAT4);
+        assert_eq!(pitches[1], F4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], AFLAT4);
+        assert_eq!(pitches[4], B4);
+        assert_eq!(pitches[5], C5);
+        assert_eq!(pitches[6], D5);
+
+        assert_eq!(scale.root(), EFLAT4);
+        assert_eq!(scale.name(), "ionian #5");
+        assert_eq!(scale.to_string(), "D#4 ionian #5");
+    }
+
+    #[test]
+    fn test_ionian_sharp5_is_third_mode_of_harmonic_minor() {
+        let c_harmonic_minor = HarmonicMinorScalePattern::apply(C4);
+        let mode = mode(&c_harmonic_minor, 3).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            IonianSharp5ScalePattern::apply(EFLAT4).pitches()
+        );
+        assert_eq!(mode.name(), "ionian #5");
+    }
+}
@@ -0,0 +1,98 @@
+//! Locrian mode implementation.
+//!
+//! The locrian mode is the seventh mode of the major scale. Its diminished
+//! fifth makes its tonic chord diminished, giving it an unstable sound, and it is
+//! mostly played over half-diminished chords.
+//!
+//! # Scale Structure
+//!
+//! The locrian mode follows the pattern of whole and half steps:
+//! ```text
+//! H W W H W W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The locrian mode is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Minor 3rd (3) + Perfect 4th (5) +
+//! Diminished 5th (6) + Minor 6th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for locrian scales.
+///
+/// This type is used to distinguish locrian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocrianScaleType;
+
+impl ScaleType for LocrianScaleType {
+    fn name() -> &'static str {
+        "locrian"
+    }
+}
+
+/// The pattern for a locrian scale.
+///
+/// This pattern defines the sequence of intervals that make up a locrian scale:
+/// ```text
+/// Root + Minor 2nd + Minor 3rd + Perfect 4th +
+/// Diminished 5th + Minor 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocrianScalePattern;
+
+impl ScalePattern for LocrianScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        DIMINISHED_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = LocrianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MajorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_locrian_scale() {
+        let scale = LocrianScalePattern::apply(B3);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], B3);
+        assert_eq!(pitches[1], C4);
+        assert_eq!(pitches[2], D4);
+        assert_eq!(pitches[3], E4);
+        assert_eq!(pitches[4], F4);
+        assert_eq!(pitches[5], G4);
+        assert_eq!(pitches[6], A4);
+
+        assert_eq!(scale.root(), B3);
+        assert_eq!(scale.name(), "locrian");
+        assert_eq!(scale.to_string(), "B3 locrian");
+    }
+
+    #[test]
+    fn test_locrian_is_seventh_mode_of_major() {
+        let c_major = MajorScalePattern::apply(C4);
+        let mode = mode(&c_major, 7).unwrap();
+        assert_eq!(mode.pitches(), LocrianScalePattern::apply(B4).pitches());
+        assert_eq!(mode.name(), "locrian");
+    }
+}
@@ -0,0 +1,105 @@
+//! Locrian #2 scale implementation.
+//!
+//! The locrian #2 scale, also written locrian ♮2 or known as half-diminished,
+//! is the sixth mode of the melodic minor scale. It is a locrian mode with a
+//! major second, and is played over half-diminished chords.
+//!
+//! # Scale Structure
+//!
+//! The locrian #2 scale follows the pattern of whole and half steps:
+//! ```text
+//! W H W H W W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The locrian #2 scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Perfect 4th (5) +
+//! Diminished 5th (6) + Minor 6th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for locrian #2 scales.
+///
+/// This type is used to distinguish locrian #2 scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocrianSharp2ScaleType;
+
+impl ScaleType for LocrianSharp2ScaleType {
+    fn name() -> &'static str {
+        "locrian #2"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["half-diminished"]
+    }
+}
+
+/// The pattern for a locrian #2 scale.
+///
+/// This pattern defines the sequence of intervals that make up a locrian #2 scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Perfect 4th +
+/// Diminished 5th + Minor 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocrianSharp2ScalePattern;
+
+impl ScalePattern for LocrianSharp2ScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        DIMINISHED_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = LocrianSharp2ScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MelodicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_locrian_sharp2_scale() {
+        let scale = LocrianSharp2ScalePattern::apply(A4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], A4);
+        assert_eq!(pitches[1], B4);
+        assert_eq!(pitches[2], C5);
+        assert_eq!(pitches[3], D5);
+        assert_eq!(pitches[4], EFLAT5);
+        assert_eq!(pitches[5], F5);
+        assert_eq!(pitches[6], G5);
+
+        assert_eq!(scale.root(), A4);
+        assert_eq!(scale.name(), "locrian #2");
+        assert_eq!(scale.to_string(), "A4 locrian #2");
+    }
+
+    #[test]
+    fn test_locrian_sharp2_is_sixth_mode_of_melodic_minor() {
+        let c_melodic_minor = MelodicMinorScalePattern::apply(C4);
+        let mode = mode(&c_melodic_minor, 6).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            LocrianSharp2ScalePattern::apply(A4).pitches()
+        );
+        assert_eq!(mode.name(), "locrian #2");
+    }
+}
@@ -0,0 +1,102 @@
+//! Locrian #6 scale implementation.
+//!
+//! The locrian #6 scale is the second mode of the harmonic minor scale. It is a
+//! locrian mode with a major sixth, and is played over half-diminished chords
+//! in minor keys.
+//!
+//! # Scale Structure
+//!
+//! The locrian #6 scale follows the pattern of whole and half steps:
+//! ```text
+//! H W W H WH H W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The locrian #6 scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Minor 3rd (3) + Perfect 4th (5) +
+//! Diminished 5th (6) + Major 6th (9) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for locrian #6 scales.
+///
+/// This type is used to distinguish locrian #6 scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocrianSharp6ScaleType;
+
+impl ScaleType for LocrianSharp6ScaleType {
+    fn name() -> &'static str {
+        "locrian #6"
+    }
+}
+
+/// The pattern for a locrian #6 scale.
+///
+/// This pattern defines the sequence of intervals that make up a locrian #6 scale:
+/// ```text
+/// Root + Minor 2nd + Minor 3rd + Perfect 4th +
+/// Diminished 5th + Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocrianSharp6ScalePattern;
+
+impl ScalePattern for LocrianSharp6ScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        DIMINISHED_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = LocrianSharp6ScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HarmonicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_locrian_sharp6_scale() {
+        let scale = LocrianSharp6ScalePattern::apply(D4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], EFLAT4);
+        assert_eq!(pitches[2], F4);
+        assert_eq!(pitches[3], G4);
+        assert_eq!(pitches[4], AFLAT4);
+        assert_eq!(pitches[5], B4);
+        assert_eq!(pitches[6], C5);
+
+        assert_eq!(scale.root(), D4);
+        assert_eq!(scale.name(), "locrian #6");
+        assert_eq!(scale.to_string(), "D4 locrian #6");
+    }
+
+    #[test]
+    fn test_locrian_sharp6_is_second_mode_of_harmonic_minor() {
+        let c_harmonic_minor = HarmonicMinorScalePattern::apply(C4);
+        let mode = mode(&c_harmonic_minor, 2).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            LocrianSharp6ScalePattern::apply(D4).pitches()
+        );
+        assert_eq!(mode.name(), "locrian #6");
+    }
+}
@@ -0,0 +1,98 @@
+//! Lydian mode implementation.
+//!
+//! The lydian mode is the fourth mode of the major scale. It is a major scale
+//! with a raised fourth, giving it a bright, floating sound often heard in film
+//! music and over major seventh chords in jazz.
+//!
+//! # Scale Structure
+//!
+//! The lydian mode follows the pattern of whole and half steps:
+//! ```text
+//! W W W H W W H
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The lydian mode is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Augmented 4th (6) +
+//! Perfect 5th (7) + Major 6th (9) + Major 7th (11)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for lydian scales.
+///
+/// This type is used to distinguish lydian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianScaleType;
+
+impl ScaleType for LydianScaleType {
+    fn name() -> &'static str {
+        "lydian"
+    }
+}
+
+/// The pattern for a lydian scale.
+///
+/// This pattern defines the sequence of intervals that make up a lydian scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Augmented 4th +
+/// Perfect 5th + Major 6th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianScalePattern;
+
+impl ScalePattern for LydianScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        AUGMENTED_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = LydianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MajorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_lydian_scale() {
+        let scale = LydianScalePattern::apply(F4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], F4);
+        assert_eq!(pitches[1], G4);
+        assert_eq!(pitches[2], A4);
+        assert_eq!(pitches[3], B4);
+        assert_eq!(pitches[4], C5);
+        assert_eq!(pitches[5], D5);
+        assert_eq!(pitches[6], E5);
+
+        assert_eq!(scale.root(), F4);
+        assert_eq!(scale.name(), "lydian");
+        assert_eq!(scale.to_string(), "F4 lydian");
+    }
+
+    #[test]
+    fn test_lydian_is_fourth_mode_of_major() {
+        let c_major = MajorScalePattern::apply(C4);
+        let mode = mode(&c_major, 4).unwrap();
+        assert_eq!(mode.pitches(), LydianScalePattern::apply(F4).pitches());
+        assert_eq!(mode.name(), "lydian");
+    }
+}
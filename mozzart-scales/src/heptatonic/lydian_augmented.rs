@@ -0,0 +1,101 @@
+//! Lydian augmented scale implementation.
+//!
+//! The lydian augmented scale is the third mode of the melodic minor scale. It
+//! is a lydian mode with a raised fifth, and is played over major seventh chords
+//! with an augmented fifth.
+//!
+//! # Scale Structure
+//!
+//! The lydian augmented scale follows the pattern of whole and half steps:
+//! ```text
+//! W W W W H W H
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The lydian augmented scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Augmented 4th (6) +
+//! Augmented 5th (8) + Major 6th (9) + Major 7th (11)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for lydian augmented scales.
+///
+/// This type is used to distinguish lydian augmented scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianAugmentedScaleType;
+
+impl ScaleType for LydianAugmentedScaleType {
+    fn name() -> &'static str {
+        "lydian augmented"
+    }
+}
+
+/// The pattern for a lydian augmented scale.
+///
+/// This pattern defines the sequence of intervals that make up a lydian augmented scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Augmented 4th +
+/// Augmented 5th + Major 6th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianAugmentedScalePattern;
+
+impl ScalePattern for LydianAugmentedScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        AUGMENTED_FOURTH,
+        AUGMENTED_FIFTH,
+        MAJOR_SIXTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = LydianAugmentedScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MelodicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_lydian_augmented_scale() {
+        let scale = LydianAugmentedScalePattern::apply(EFLAT4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], EFLAT4);
+        assert_eq!(pitches[1], F4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], A4);
+        assert_eq!(pitches[4], B4);
+        assert_eq!(pitches[5], C5);
+        assert_eq!(pitches[6], D5);
+
+        assert_eq!(scale.root(), EFLAT4);
+        assert_eq!(scale.name(), "lydian augmented");
+        assert_eq!(scale.to_string(), "D#4 lydian augmented");
+    }
+
+    #[test]
+    fn test_lydian_augmented_is_third_mode_of_melodic_minor() {
+        let c_melodic_minor = MelodicMinorScalePattern::apply(C4);
+        let mode = mode(&c_melodic_minor, 3).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            LydianAugmentedScalePattern::apply(EFLAT4).pitches()
+        );
+        assert_eq!(mode.name(), "lydian augmented");
+    }
+}
@@ -0,0 +1,105 @@
+//! Lydian dominant scale implementation.
+//!
+//! The lydian dominant scale, also known as the acoustic scale, is the fourth
+//! mode of the melodic minor scale. It is a mixolydian mode with a raised fourth,
+//! and is played over dominant seventh chords with a sharp eleventh.
+//!
+//! # Scale Structure
+//!
+//! The lydian dominant scale follows the pattern of whole and half steps:
+//! ```text
+//! W W W H W H W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The lydian dominant scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Augmented 4th (6) +
+//! Perfect 5th (7) + Major 6th (9) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for lydian dominant scales.
+///
+/// This type is used to distinguish lydian dominant scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianDominantScaleType;
+
+impl ScaleType for LydianDominantScaleType {
+    fn name() -> &'static str {
+        "lydian dominant"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["acoustic"]
+    }
+}
+
+/// The pattern for a lydian dominant scale.
+///
+/// This pattern defines the sequence of intervals that make up a lydian dominant scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Augmented 4th +
+/// Perfect 5th + Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianDominantScalePattern;
+
+impl ScalePattern for LydianDominantScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        AUGMENTED_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = LydianDominantScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MelodicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_lydian_dominant_scale() {
+        let scale = LydianDominantScalePattern::apply(F4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], F4);
+        assert_eq!(pitches[1], G4);
+        assert_eq!(pitches[2], A4);
+        assert_eq!(pitches[3], B4);
+        assert_eq!(pitches[4], C5);
+        assert_eq!(pitches[5], D5);
+        assert_eq!(pitches[6], EFLAT5);
+
+        assert_eq!(scale.root(), F4);
+        assert_eq!(scale.name(), "lydian dominant");
+        assert_eq!(scale.to_string(), "F4 lydian dominant");
+    }
+
+    #[test]
+    fn test_lydian_dominant_is_fourth_mode_of_melodic_minor() {
+        let c_melodic_minor = MelodicMinorScalePattern::apply(C4);
+        let mode = mode(&c_melodic_minor, 4).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            LydianDominantScalePattern::apply(F4).pitches()
+        );
+        assert_eq!(mode.name(), "lydian dominant");
+    }
+}
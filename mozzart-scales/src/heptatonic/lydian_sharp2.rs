@@ -0,0 +1,102 @@
+//! Lydian #2 scale implementation.
+//!
+//! The lydian #2 scale is the sixth mode of the harmonic minor scale. It is a
+//! lydian mode with a raised second, and is played over major seventh chords
+//! with a sharp ninth.
+//!
+//! # Scale Structure
+//!
+//! The lydian #2 scale follows the pattern of whole and half steps:
+//! ```text
+//! WH H W H W W H
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The lydian #2 scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Augmented 2nd (3) + Major 3rd (4) + Augmented 4th (6) +
+//! Perfect 5th (7) + Major 6th (9) + Major 7th (11)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for lydian #2 scales.
+///
+/// This type is used to distinguish lydian #2 scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianSharp2ScaleType;
+
+impl ScaleType for LydianSharp2ScaleType {
+    fn name() -> &'static str {
+        "lydian #2"
+    }
+}
+
+/// The pattern for a lydian #2 scale.
+///
+/// This pattern defines the sequence of intervals that make up a lydian #2 scale:
+/// ```text
+/// Root + Augmented 2nd + Major 3rd + Augmented 4th +
+/// Perfect 5th + Major 6th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LydianSharp2ScalePattern;
+
+impl ScalePattern for LydianSharp2ScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        AUGMENTED_SECOND,
+        MAJOR_THIRD,
+        AUGMENTED_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = LydianSharp2ScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HarmonicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_lydian_sharp2_scale() {
+        let scale = LydianSharp2ScalePattern::apply(AFLAT4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], AFLAT4);
+        assert_eq!(pitches[1], B4);
+        assert_eq!(pitches[2], C5);
+        assert_eq!(pitches[3], D5);
+        assert_eq!(pitches[4], EFLAT5);
+        assert_eq!(pitches[5], F5);
+        assert_eq!(pitches[6], G5);
+
+        assert_eq!(scale.root(), AFLAT4);
+        assert_eq!(scale.name(), "lydian #2");
+        assert_eq!(scale.to_string(), "G#4 lydian #2");
+    }
+
+    #[test]
+    fn test_lydian_sharp2_is_sixth_mode_of_harmonic_minor() {
+        let c_harmonic_minor = HarmonicMinorScalePattern::apply(C4);
+        let mode = mode(&c_harmonic_minor, 6).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            LydianSharp2ScalePattern::apply(AFLAT4).pitches()
+        );
+        assert_eq!(mode.name(), "lydian #2");
+    }
+}
@@ -23,7 +23,9 @@
 //! Perfect 5th (7) + Major 6th (9) + Major 7th (11)
 //! ```
 use mozzart_core::constants::*;
-use mozzart_core::{Interval, ScalePattern, ScaleType};
+use mozzart_core::{Interval, Pitch, Scale, ScalePattern, ScaleType};
+
+use crate::{NaturalMinorScalePattern, NaturalMinorScaleType};
 
 /// A marker type for major scales.
 ///
@@ -36,6 +38,10 @@ impl ScaleType for MajorScaleType {
     fn name() -> &'static str {
         "major"
     }
+
+    fn aliases() -> &'static [&'static str] {
+        &["ionian"]
+    }
 }
 
 /// The pattern for a major scale.
@@ -63,10 +69,82 @@ impl ScalePattern for MajorScalePattern {
     type ScaleTyp = MajorScaleType;
 }
 
+/// Extension methods for major scales.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScalePattern;
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::{MajorScaleExt, MajorScalePattern};
+///
+/// let c_major = MajorScalePattern::apply(C4);
+/// assert_eq!(c_major.relative_minor().to_string(), "A3 natural minor");
+/// assert_eq!(c_major.parallel_minor().to_string(), "C4 natural minor");
+/// ```
+pub trait MajorScaleExt {
+    /// Returns the relative minor scale, sharing the pitch classes of this scale
+    /// and rooted a minor third below it.
+    ///
+    /// Roots less than a minor third above C-1, such as the pitch classes C, C#
+    /// and D, have their relative minor rooted a major sixth above them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScalePattern;
+    /// use mozzart_core::constants::*;
+    /// use mozzart_scales::{MajorScaleExt, MajorScalePattern};
+    ///
+    /// let g_major = MajorScalePattern::apply(G4);
+    /// assert_eq!(
+    ///     g_major.relative_minor().pitches(),
+    ///     [E4, FSHARP4, G4, A4, B4, C5, D5]
+    /// );
+    ///
+    /// let d_major = MajorScalePattern::apply(D);
+    /// assert_eq!(d_major.relative_minor().root(), B);
+    /// ```
+    fn relative_minor(&self) -> Scale<NaturalMinorScaleType>;
+
+    /// Returns the parallel minor scale, sharing the root of this scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScalePattern;
+    /// use mozzart_core::constants::*;
+    /// use mozzart_scales::{MajorScaleExt, MajorScalePattern};
+    ///
+    /// let d_major = MajorScalePattern::apply(D4);
+    /// assert_eq!(d_major.parallel_minor().pitches(), [D4, E4, F4, G4, A4, ASHARP4, C5]);
+    /// ```
+    fn parallel_minor(&self) -> Scale<NaturalMinorScaleType>;
+}
+
+impl MajorScaleExt for Scale<MajorScaleType> {
+    fn relative_minor(&self) -> Scale<NaturalMinorScaleType> {
+        let root = self.root();
+        let minor_root = match root.semitones().checked_sub(MINOR_THIRD.semitones()) {
+            Some(semitones) => Pitch::new(semitones),
+            None => root.transpose(MAJOR_SIXTH),
+        };
+        NaturalMinorScalePattern::apply(minor_root)
+    }
+
+    fn parallel_minor(&self) -> Scale<NaturalMinorScaleType> {
+        NaturalMinorScalePattern::apply(self.root())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mozzart_core::ScalePattern;
+    use crate::{
+        DorianScalePattern, LocrianScalePattern, LydianScalePattern, MixolydianScalePattern,
+        NaturalMinorScalePattern, PhrygianScalePattern,
+    };
+    use mozzart_core::{ScaleCategory, ScalePattern};
 
     #[test]
     fn test_major_scale() {
@@ -85,6 +163,13 @@ mod tests {
         assert_eq!(scale.root(), C4);
         assert_eq!(scale.name(), "major");
         assert_eq!(scale.to_string(), "C4 major");
+        assert_eq!(MajorScalePattern::apply_array(C4), pitches);
+    }
+
+    #[test]
+    fn test_major_scale_at_compile_time() {
+        const D_MAJOR: [Pitch; 7] = D4.apply_array(MajorScalePattern::PATTERN);
+        assert_eq!(D_MAJOR, MajorScalePattern::apply_array(D4));
     }
 
     #[test]
@@ -100,4 +185,53 @@ mod tests {
         assert_eq!(pitches[5], E5);
         assert_eq!(pitches[6], FSHARP5);
     }
+
+    #[test]
+    fn test_relative_and_parallel_minor() {
+        let scale = MajorScalePattern::apply(EFLAT4);
+        assert_eq!(scale.relative_minor().root(), C4);
+        assert!(
+            scale
+                .relative_minor()
+                .pitches()
+                .iter()
+                .all(|&p| scale.contains(p))
+        );
+        assert_eq!(scale.parallel_minor().root(), EFLAT4);
+        assert_eq!(scale.parallel_minor().degree(3), Some(GFLAT4));
+    }
+
+    #[test]
+    fn test_relative_minor_of_low_roots() {
+        // Pitch classes keep a pitch-class root
+        let d_major = MajorScalePattern::apply(D);
+        let b_minor = d_major.relative_minor();
+        assert_eq!(b_minor.root(), B);
+        assert!(b_minor.pitch_classes().is_subset(&d_major.pitch_classes()));
+
+        // The relative minor of D#-1 is rooted on C-1
+        let low = MajorScalePattern::apply(Pitch::new(3));
+        assert_eq!(low.relative_minor().root(), Pitch::new(0));
+    }
+
+    #[test]
+    fn test_major_scale_metadata() {
+        assert_eq!(MajorScalePattern::aliases(), ["ionian"]);
+        assert_eq!(
+            MajorScalePattern::category(),
+            Some(ScaleCategory::Heptatonic)
+        );
+
+        // The modes of the major scale, from the brightest to the darkest
+        let brightness = [
+            LydianScalePattern::brightness(),
+            MajorScalePattern::brightness(),
+            MixolydianScalePattern::brightness(),
+            DorianScalePattern::brightness(),
+            NaturalMinorScalePattern::brightness(),
+            PhrygianScalePattern::brightness(),
+            LocrianScalePattern::brightness(),
+        ];
+        assert!(brightness.windows(2).all(|pair| pair[0] == pair[1] + 1));
+    }
 }
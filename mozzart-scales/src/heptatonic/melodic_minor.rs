@@ -67,6 +67,9 @@ impl ScaleType for MelodicMinorScaleType {
 /// Root + Major 2nd + Minor 3rd + Perfect 4th +
 /// Perfect 5th + Minor 6th + Minor 7th
 /// ```
+///
+/// The descending form is the [`DESCENDING_PATTERN`](ScalePattern::DESCENDING_PATTERN)
+/// of the pattern, used by [`ScalePattern::apply_descending`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MelodicMinorScalePattern;
 
@@ -81,6 +84,15 @@ impl ScalePattern for MelodicMinorScalePattern {
         MAJOR_SIXTH,
         MAJOR_SEVENTH,
     ];
+    const DESCENDING_PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
 
     type ScaleTyp = MelodicMinorScaleType;
 }
@@ -88,6 +100,7 @@ impl ScalePattern for MelodicMinorScalePattern {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::NaturalMinorScalePattern;
     use mozzart_core::ScalePattern;
 
     #[test]
@@ -108,4 +121,24 @@ mod tests {
         assert_eq!(scale.name(), "melodic minor");
         assert_eq!(scale.to_string(), "C4 melodic minor");
     }
+
+    #[test]
+    fn test_melodic_minor_descending() {
+        assert_eq!(
+            MelodicMinorScalePattern::apply_descending(C4),
+            [BFLAT4, AFLAT4, G4, F4, EFLAT4, D4, C4]
+        );
+        assert_eq!(
+            MelodicMinorScalePattern::apply_descending(A4),
+            NaturalMinorScalePattern::apply(A4).reversed()
+        );
+
+        // Reversing a melodic minor scale keeps its raised sixth and seventh
+        let scale = MelodicMinorScalePattern::apply(A4);
+        assert_eq!(scale.reversed(), [GSHARP5, FSHARP5, E5, D5, C5, B4, A4]);
+        assert_ne!(
+            scale.reversed(),
+            MelodicMinorScalePattern::apply_descending(scale.root())
+        );
+    }
 }
@@ -0,0 +1,98 @@
+//! Mixolydian mode implementation.
+//!
+//! The mixolydian mode is the fifth mode of the major scale. It is a major scale
+//! with a lowered seventh, matching the dominant seventh chord, and is used
+//! extensively in blues, rock and folk music.
+//!
+//! # Scale Structure
+//!
+//! The mixolydian mode follows the pattern of whole and half steps:
+//! ```text
+//! W W H W W H W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The mixolydian mode is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Perfect 4th (5) +
+//! Perfect 5th (7) + Major 6th (9) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for mixolydian scales.
+///
+/// This type is used to distinguish mixolydian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MixolydianScaleType;
+
+impl ScaleType for MixolydianScaleType {
+    fn name() -> &'static str {
+        "mixolydian"
+    }
+}
+
+/// The pattern for a mixolydian scale.
+///
+/// This pattern defines the sequence of intervals that make up a mixolydian scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Perfect 4th +
+/// Perfect 5th + Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MixolydianScalePattern;
+
+impl ScalePattern for MixolydianScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = MixolydianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MajorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_mixolydian_scale() {
+        let scale = MixolydianScalePattern::apply(G4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], G4);
+        assert_eq!(pitches[1], A4);
+        assert_eq!(pitches[2], B4);
+        assert_eq!(pitches[3], C5);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], E5);
+        assert_eq!(pitches[6], F5);
+
+        assert_eq!(scale.root(), G4);
+        assert_eq!(scale.name(), "mixolydian");
+        assert_eq!(scale.to_string(), "G4 mixolydian");
+    }
+
+    #[test]
+    fn test_mixolydian_is_fifth_mode_of_major() {
+        let c_major = MajorScalePattern::apply(C4);
+        let mode = mode(&c_major, 5).unwrap();
+        assert_eq!(mode.pitches(), MixolydianScalePattern::apply(G4).pitches());
+        assert_eq!(mode.name(), "mixolydian");
+    }
+}
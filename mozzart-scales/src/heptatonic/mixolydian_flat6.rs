@@ -0,0 +1,105 @@
+//! Mixolydian b6 scale implementation.
+//!
+//! The mixolydian b6 scale, also known as the aeolian dominant scale, is the
+//! fifth mode of the melodic minor scale. It is a mixolydian mode with a lowered
+//! sixth, and is played over dominant seventh chords with a flat thirteenth.
+//!
+//! # Scale Structure
+//!
+//! The mixolydian b6 scale follows the pattern of whole and half steps:
+//! ```text
+//! W W H W H W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The mixolydian b6 scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Perfect 4th (5) +
+//! Perfect 5th (7) + Minor 6th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for mixolydian b6 scales.
+///
+/// This type is used to distinguish mixolydian b6 scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MixolydianFlat6ScaleType;
+
+impl ScaleType for MixolydianFlat6ScaleType {
+    fn name() -> &'static str {
+        "mixolydian b6"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["aeolian dominant"]
+    }
+}
+
+/// The pattern for a mixolydian b6 scale.
+///
+/// This pattern defines the sequence of intervals that make up a mixolydian b6 scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Perfect 4th +
+/// Perfect 5th + Minor 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MixolydianFlat6ScalePattern;
+
+impl ScalePattern for MixolydianFlat6ScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = MixolydianFlat6ScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MelodicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_mixolydian_b6_scale() {
+        let scale = MixolydianFlat6ScalePattern::apply(G4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], G4);
+        assert_eq!(pitches[1], A4);
+        assert_eq!(pitches[2], B4);
+        assert_eq!(pitches[3], C5);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], EFLAT5);
+        assert_eq!(pitches[6], F5);
+
+        assert_eq!(scale.root(), G4);
+        assert_eq!(scale.name(), "mixolydian b6");
+        assert_eq!(scale.to_string(), "G4 mixolydian b6");
+    }
+
+    #[test]
+    fn test_mixolydian_b6_is_fifth_mode_of_melodic_minor() {
+        let c_melodic_minor = MelodicMinorScalePattern::apply(C4);
+        let mode = mode(&c_melodic_minor, 5).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            MixolydianFlat6ScalePattern::apply(G4).pitches()
+        );
+        assert_eq!(mode.name(), "mixolydian b6");
+    }
+}
@@ -1,11 +1,47 @@
+pub mod aeolian;
+pub mod altered;
+pub mod dorian;
+pub mod dorian_flat2;
+pub mod dorian_sharp4;
 pub mod harmonic_major;
 pub mod harmonic_minor;
+pub mod ionian_sharp5;
+pub mod locrian;
+pub mod locrian_sharp2;
+pub mod locrian_sharp6;
+pub mod lydian;
+pub mod lydian_augmented;
+pub mod lydian_dominant;
+pub mod lydian_sharp2;
 pub mod major;
 pub mod melodic_minor;
+pub mod mixolydian;
+pub mod mixolydian_flat6;
 pub mod natural_minor;
+pub mod phrygian;
+pub mod phrygian_dominant;
+pub mod ultralocrian;
 
+pub use aeolian::*;
+pub use altered::*;
+pub use dorian::*;
+pub use dorian_flat2::*;
+pub use dorian_sharp4::*;
 pub use harmonic_major::*;
 pub use harmonic_minor::*;
+pub use ionian_sharp5::*;
+pub use locrian::*;
+pub use locrian_sharp2::*;
+pub use locrian_sharp6::*;
+pub use lydian::*;
+pub use lydian_augmented::*;
+pub use lydian_dominant::*;
+pub use lydian_sharp2::*;
 pub use major::*;
 pub use melodic_minor::*;
+pub use mixolydian::*;
+pub use mixolydian_flat6::*;
 pub use natural_minor::*;
+pub use phrygian::*;
+pub use phrygian_dominant::*;
+pub use ultralocrian::*;
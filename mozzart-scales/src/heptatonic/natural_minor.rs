@@ -23,7 +23,9 @@
 //! Perfect 5th (7) + Minor 6th (8) + Minor 7th (10)
 //! ```
 use mozzart_core::constants::*;
-use mozzart_core::{Interval, ScalePattern, ScaleType};
+use mozzart_core::{Interval, Scale, ScalePattern, ScaleType};
+
+use crate::{MajorScalePattern, MajorScaleType};
 
 /// A marker type for natural minor scales.
 ///
@@ -63,9 +65,67 @@ impl ScalePattern for NaturalMinorScalePattern {
     type ScaleTyp = NaturalMinorScaleType;
 }
 
+/// Extension methods for natural minor scales.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::ScalePattern;
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::{MinorScaleExt, NaturalMinorScalePattern};
+///
+/// let a_minor = NaturalMinorScalePattern::apply(A3);
+/// assert_eq!(a_minor.relative_major().to_string(), "C4 major");
+/// assert_eq!(a_minor.parallel_major().to_string(), "A3 major");
+/// ```
+pub trait MinorScaleExt {
+    /// Returns the relative major scale, sharing the pitch classes of this scale
+    /// and rooted a minor third above it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScalePattern;
+    /// use mozzart_core::constants::*;
+    /// use mozzart_scales::{MinorScaleExt, NaturalMinorScalePattern};
+    ///
+    /// let e_minor = NaturalMinorScalePattern::apply(E4);
+    /// assert_eq!(
+    ///     e_minor.relative_major().pitches(),
+    ///     [G4, A4, B4, C5, D5, E5, FSHARP5]
+    /// );
+    /// ```
+    fn relative_major(&self) -> Scale<MajorScaleType>;
+
+    /// Returns the parallel major scale, sharing the root of this scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::ScalePattern;
+    /// use mozzart_core::constants::*;
+    /// use mozzart_scales::{MinorScaleExt, NaturalMinorScalePattern};
+    ///
+    /// let c_minor = NaturalMinorScalePattern::apply(C4);
+    /// assert_eq!(c_minor.parallel_major().pitches(), [C4, D4, E4, F4, G4, A4, B4]);
+    /// ```
+    fn parallel_major(&self) -> Scale<MajorScaleType>;
+}
+
+impl MinorScaleExt for Scale<NaturalMinorScaleType> {
+    fn relative_major(&self) -> Scale<MajorScaleType> {
+        MajorScalePattern::apply(self.root().transpose(MINOR_THIRD))
+    }
+
+    fn parallel_major(&self) -> Scale<MajorScaleType> {
+        MajorScalePattern::apply(self.root())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::MajorScaleExt;
     use mozzart_core::ScalePattern;
 
     #[test]
@@ -100,4 +160,18 @@ mod tests {
         assert_eq!(pitches[5], C5);
         assert_eq!(pitches[6], D5);
     }
+
+    #[test]
+    fn test_relative_and_parallel_major() {
+        let scale = NaturalMinorScalePattern::apply(FSHARP3);
+        assert_eq!(scale.relative_major().root(), A3);
+        assert_eq!(
+            scale.relative_major().relative_minor().pitches(),
+            scale.pitches()
+        );
+        assert_eq!(
+            scale.parallel_major().parallel_minor().pitches(),
+            scale.pitches()
+        );
+    }
 }
@@ -0,0 +1,98 @@
+//! Phrygian mode implementation.
+//!
+//! The phrygian mode is the third mode of the major scale. It is a minor scale
+//! with a lowered second, giving it a dark, Spanish sound heard in flamenco and
+//! metal music.
+//!
+//! # Scale Structure
+//!
+//! The phrygian mode follows the pattern of whole and half steps:
+//! ```text
+//! H W W W H W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! The phrygian mode is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Minor 3rd (3) + Perfect 4th (5) +
+//! Perfect 5th (7) + Minor 6th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for phrygian scales.
+///
+/// This type is used to distinguish phrygian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhrygianScaleType;
+
+impl ScaleType for PhrygianScaleType {
+    fn name() -> &'static str {
+        "phrygian"
+    }
+}
+
+/// The pattern for a phrygian scale.
+///
+/// This pattern defines the sequence of intervals that make up a phrygian scale:
+/// ```text
+/// Root + Minor 2nd + Minor 3rd + Perfect 4th +
+/// Perfect 5th + Minor 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhrygianScalePattern;
+
+impl ScalePattern for PhrygianScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = PhrygianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MajorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_phrygian_scale() {
+        let scale = PhrygianScalePattern::apply(E4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], E4);
+        assert_eq!(pitches[1], F4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], A4);
+        assert_eq!(pitches[4], B4);
+        assert_eq!(pitches[5], C5);
+        assert_eq!(pitches[6], D5);
+
+        assert_eq!(scale.root(), E4);
+        assert_eq!(scale.name(), "phrygian");
+        assert_eq!(scale.to_string(), "E4 phrygian");
+    }
+
+    #[test]
+    fn test_phrygian_is_third_mode_of_major() {
+        let c_major = MajorScalePattern::apply(C4);
+        let mode = mode(&c_major, 3).unwrap();
+        assert_eq!(mode.pitches(), PhrygianScalePattern::apply(E4).pitches());
+        assert_eq!(mode.name(), "phrygian");
+    }
+}
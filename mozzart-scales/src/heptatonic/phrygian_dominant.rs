@@ -0,0 +1,106 @@
+//! Phrygian dominant scale implementation.
+//!
+//! The phrygian dominant scale, also known as the Spanish phrygian scale, is the
+//! fifth mode of the harmonic minor scale. It is a phrygian mode with a major
+//! third, and is used extensively in flamenco, metal and Middle Eastern music.
+//!
+//! # Scale Structure
+//!
+//! The phrygian dominant scale follows the pattern of whole and half steps:
+//! ```text
+//! H WH H W H W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The phrygian dominant scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Major 3rd (4) + Perfect 4th (5) +
+//! Perfect 5th (7) + Minor 6th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for phrygian dominant scales.
+///
+/// This type is used to distinguish phrygian dominant scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhrygianDominantScaleType;
+
+impl ScaleType for PhrygianDominantScaleType {
+    fn name() -> &'static str {
+        "phrygian dominant"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["spanish phrygian"]
+    }
+}
+
+/// The pattern for a phrygian dominant scale.
+///
+/// This pattern defines the sequence of intervals that make up a phrygian dominant scale:
+/// ```text
+/// Root + Minor 2nd + Major 3rd + Perfect 4th +
+/// Perfect 5th + Minor 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhrygianDominantScalePattern;
+
+impl ScalePattern for PhrygianDominantScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MAJOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MINOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = PhrygianDominantScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HarmonicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_phrygian_dominant_scale() {
+        let scale = PhrygianDominantScalePattern::apply(G4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], G4);
+        assert_eq!(pitches[1], AFLAT4);
+        assert_eq!(pitches[2], B4);
+        assert_eq!(pitches[3], C5);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], EFLAT5);
+        assert_eq!(pitches[6], F5);
+
+        assert_eq!(scale.root(), G4);
+        assert_eq!(scale.name(), "phrygian dominant");
+        assert_eq!(scale.to_string(), "G4 phrygian dominant");
+    }
+
+    #[test]
+    fn test_phrygian_dominant_is_fifth_mode_of_harmonic_minor() {
+        let c_harmonic_minor = HarmonicMinorScalePattern::apply(C4);
+        let mode = mode(&c_harmonic_minor, 5).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            PhrygianDominantScalePattern::apply(G4).pitches()
+        );
+        assert_eq!(mode.name(), "phrygian dominant");
+    }
+}
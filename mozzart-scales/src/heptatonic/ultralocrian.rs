@@ -0,0 +1,106 @@
+//! Ultralocrian scale implementation.
+//!
+//! The ultralocrian scale, also known as the altered diminished scale, is the
+//! seventh mode of the harmonic minor scale. It is an altered scale with a
+//! diminished seventh, and is played over diminished seventh chords.
+//!
+//! # Scale Structure
+//!
+//! The ultralocrian scale follows the pattern of whole and half steps:
+//! ```text
+//! H W H W W H WH
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The ultralocrian scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Minor 3rd (3) + Diminished 4th (4) +
+//! Diminished 5th (6) + Minor 6th (8) + Diminished 7th (9)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for ultralocrian scales.
+///
+/// This type is used to distinguish ultralocrian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UltralocrianScaleType;
+
+impl ScaleType for UltralocrianScaleType {
+    fn name() -> &'static str {
+        "ultralocrian"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["altered diminished"]
+    }
+}
+
+/// The pattern for a ultralocrian scale.
+///
+/// This pattern defines the sequence of intervals that make up a ultralocrian scale:
+/// ```text
+/// Root + Minor 2nd + Minor 3rd + Diminished 4th +
+/// Diminished 5th + Minor 6th + Diminished 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UltralocrianScalePattern;
+
+impl ScalePattern for UltralocrianScalePattern {
+    type Pattern = [Interval; 7];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MINOR_THIRD,
+        DIMINISHED_FOURTH,
+        DIMINISHED_FIFTH,
+        MINOR_SIXTH,
+        DIMINISHED_SEVENTH,
+    ];
+
+    type ScaleTyp = UltralocrianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HarmonicMinorScalePattern, mode};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_ultralocrian_scale() {
+        let scale = UltralocrianScalePattern::apply(B4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 7);
+        assert_eq!(pitches[0], B4);
+        assert_eq!(pitches[1], C5);
+        assert_eq!(pitches[2], D5);
+        assert_eq!(pitches[3], EFLAT5);
+        assert_eq!(pitches[4], F5);
+        assert_eq!(pitches[5], G5);
+        assert_eq!(pitches[6], AFLAT5);
+
+        assert_eq!(scale.root(), B4);
+        assert_eq!(scale.name(), "ultralocrian");
+        assert_eq!(scale.to_string(), "B4 ultralocrian");
+    }
+
+    #[test]
+    fn test_ultralocrian_is_seventh_mode_of_harmonic_minor() {
+        let c_harmonic_minor = HarmonicMinorScalePattern::apply(C4);
+        let mode = mode(&c_harmonic_minor, 7).unwrap();
+        assert_eq!(
+            mode.pitches(),
+            UltralocrianScalePattern::apply(B4).pitches()
+        );
+        assert_eq!(mode.name(), "ultralocrian");
+    }
+}
@@ -0,0 +1,95 @@
+//! Augmented scale implementation.
+//!
+//! The augmented scale alternates minor thirds and half steps. It is the union
+//! of two augmented triads a half step apart, is symmetric by a major third, and
+//! has only four distinct transpositions.
+//!
+//! # Scale Structure
+//!
+//! The augmented scale follows the pattern of augmented seconds and half steps:
+//! ```text
+//! WH H WH H WH H
+//! ```
+//!
+//! Where:
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The augmented scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 3rd (3) + Major 3rd (4) + Perfect 5th (7) +
+//! Augmented 5th (8) + Major 7th (11)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for augmented scales.
+///
+/// This type is used to distinguish augmented scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AugmentedScaleType;
+
+impl ScaleType for AugmentedScaleType {
+    fn name() -> &'static str {
+        "augmented"
+    }
+}
+
+/// The pattern for a augmented scale.
+///
+/// This pattern defines the sequence of intervals that make up a augmented scale:
+/// ```text
+/// Root + Minor 3rd + Major 3rd + Perfect 5th +
+/// Augmented 5th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AugmentedScalePattern;
+
+impl ScalePattern for AugmentedScalePattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_THIRD,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        AUGMENTED_FIFTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = AugmentedScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_augmented_scale() {
+        let scale = AugmentedScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], DSHARP4);
+        assert_eq!(pitches[2], E4);
+        assert_eq!(pitches[3], G4);
+        assert_eq!(pitches[4], GSHARP4);
+        assert_eq!(pitches[5], B4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "augmented");
+        assert_eq!(scale.to_string(), "C4 augmented");
+    }
+
+    #[test]
+    fn test_augmented_scale_symmetry() {
+        let scale = AugmentedScalePattern::apply(C4);
+        assert_eq!(scale.transpositional_symmetries().len(), 3);
+        assert!(scale.same_pitches(&AugmentedScalePattern::apply(E4)));
+        assert!(!scale.same_pitches(&AugmentedScalePattern::apply(D4)));
+    }
+}
@@ -0,0 +1,101 @@
+//! Major blues scale implementation.
+//!
+//! The major blues scale adds the minor third to the major pentatonic scale as
+//! a blue note, passing chromatically into the major third. It is used in
+//! blues, country and rock music over major and dominant chords.
+//!
+//! # Scale Structure
+//!
+//! The major blues scale follows the pattern of whole and half steps:
+//! ```text
+//! W H H WH W WH
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The major blues scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Major 3rd (4) +
+//! Perfect 5th (7) + Major 6th (9)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for major blues scales.
+///
+/// This type is used to distinguish major blues scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BluesMajorScaleType;
+
+impl ScaleType for BluesMajorScaleType {
+    fn name() -> &'static str {
+        "blues major"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["major blues"]
+    }
+}
+
+/// The pattern for a major blues scale.
+///
+/// This pattern defines the sequence of intervals that make up a major blues scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Major 3rd +
+/// Perfect 5th + Major 6th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BluesMajorScalePattern;
+
+impl ScalePattern for BluesMajorScalePattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        MAJOR_THIRD,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+    ];
+
+    type ScaleTyp = BluesMajorScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PentatonicMajorScalePattern;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_blues_major_scale() {
+        let scale = BluesMajorScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], EFLAT4);
+        assert_eq!(pitches[3], E4);
+        assert_eq!(pitches[4], G4);
+        assert_eq!(pitches[5], A4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "blues major");
+        assert_eq!(scale.to_string(), "C4 blues major");
+    }
+
+    #[test]
+    fn test_blues_major_extends_pentatonic_major() {
+        let pentatonic = PentatonicMajorScalePattern::apply(G4).pitch_classes();
+        let blues = BluesMajorScalePattern::apply(G4).pitch_classes();
+        assert!(pentatonic.is_subset(&blues));
+        assert_eq!(blues.len(), pentatonic.len() + 1);
+    }
+}
@@ -1,3 +1,11 @@
+pub mod augmented;
 pub mod blues;
+pub mod blues_major;
+pub mod prometheus;
+pub mod whole_tone;
 
+pub use augmented::*;
 pub use blues::*;
+pub use blues_major::*;
+pub use prometheus::*;
+pub use whole_tone::*;
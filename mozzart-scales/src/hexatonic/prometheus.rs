@@ -0,0 +1,92 @@
+//! Prometheus scale implementation.
+//!
+//! The Prometheus scale, also known as the mystic chord scale, was used by
+//! Scriabin in Prometheus: The Poem of Fire. It is a whole tone scale with the
+//! augmented fifth replaced by a major sixth.
+//!
+//! # Scale Structure
+//!
+//! The Prometheus scale follows the pattern of whole and half steps:
+//! ```text
+//! W W W WH H W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The Prometheus scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Augmented 4th (6) +
+//! Major 6th (9) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for Prometheus scales.
+///
+/// This type is used to distinguish Prometheus scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrometheusScaleType;
+
+impl ScaleType for PrometheusScaleType {
+    fn name() -> &'static str {
+        "prometheus"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["mystic"]
+    }
+}
+
+/// The pattern for a Prometheus scale.
+///
+/// This pattern defines the sequence of intervals that make up a Prometheus scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Augmented 4th +
+/// Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrometheusScalePattern;
+
+impl ScalePattern for PrometheusScalePattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        AUGMENTED_FOURTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = PrometheusScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_prometheus_scale() {
+        let scale = PrometheusScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], E4);
+        assert_eq!(pitches[3], FSHARP4);
+        assert_eq!(pitches[4], A4);
+        assert_eq!(pitches[5], BFLAT4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "prometheus");
+        assert_eq!(scale.to_string(), "C4 prometheus");
+    }
+}
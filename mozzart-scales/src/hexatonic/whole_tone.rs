@@ -0,0 +1,97 @@
+//! Whole tone scale implementation.
+//!
+//! The whole tone scale divides the octave into six equal whole steps. It has
+//! no perfect fifth and no leading tone, giving it a floating, ambiguous sound
+//! used by impressionist composers and over augmented and altered dominant chords.
+//!
+//! # Scale Structure
+//!
+//! The whole tone scale follows the pattern of whole steps:
+//! ```text
+//! W W W W W W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//!
+//! Being symmetric, the scale only has two distinct transpositions: the one on
+//! C and the one on C#.
+//!
+//! # Interval Pattern
+//!
+//! The whole tone scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Augmented 4th (6) +
+//! Augmented 5th (8) + Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for whole tone scales.
+///
+/// This type is used to distinguish whole tone scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WholeToneScaleType;
+
+impl ScaleType for WholeToneScaleType {
+    fn name() -> &'static str {
+        "whole tone"
+    }
+}
+
+/// The pattern for a whole tone scale.
+///
+/// This pattern defines the sequence of intervals that make up a whole tone scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Augmented 4th +
+/// Augmented 5th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WholeToneScalePattern;
+
+impl ScalePattern for WholeToneScalePattern {
+    type Pattern = [Interval; 6];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        AUGMENTED_FOURTH,
+        AUGMENTED_FIFTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = WholeToneScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_whole_tone_scale() {
+        let scale = WholeToneScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 6);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], E4);
+        assert_eq!(pitches[3], FSHARP4);
+        assert_eq!(pitches[4], GSHARP4);
+        assert_eq!(pitches[5], ASHARP4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "whole tone");
+        assert_eq!(scale.to_string(), "C4 whole tone");
+    }
+
+    #[test]
+    fn test_whole_tone_scale_symmetry() {
+        let scale = WholeToneScalePattern::apply(C4);
+        assert_eq!(scale.transpositional_symmetries().len(), 6);
+        assert!(scale.same_pitches(&WholeToneScalePattern::apply(D4)));
+        assert!(!scale.same_pitches(&WholeToneScalePattern::apply(CSHARP4)));
+    }
+}
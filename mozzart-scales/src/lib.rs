@@ -1,7 +1,14 @@
+pub mod catalogue;
 pub mod heptatonic;
 pub mod hexatonic;
+pub mod maqam;
+pub mod octatonic;
 pub mod pentatonic;
+pub mod theory;
 
+pub use catalogue::*;
 pub use heptatonic::*;
 pub use hexatonic::*;
+pub use maqam::*;
+pub use octatonic::*;
 pub use pentatonic::*;
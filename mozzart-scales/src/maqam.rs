@@ -0,0 +1,312 @@
+//! Maqam scale implementations.
+//!
+//! The maqamat of Arabic music use degrees lying between the pitches of twelve-tone
+//! equal temperament, such as the half-flat third of Rast. Their degrees are
+//! measured in quarter tones, half an equal-tempered semitone, instead of
+//! [`Interval`]s:
+//!
+//! ```text
+//! Maqam      Tonic   Degrees                      Quarter tones
+//! Rast       C       C  D  E‡  F  G  A  B‡        0 4 7 10 14 18 21
+//! Bayati     D       D  E‡ F   G  A  Bb C         0 3 6 10 14 16 20
+//! Sikah      E‡      E‡ F  G   A  B‡ C  D         0 3 7 11 14 17 21
+//! Hijaz      D       D  Eb F#  G  A  Bb C         0 2 8 10 14 16 20
+//! Saba       D       D  E‡ F   Gb A  Bb C         0 3 6 8  14 16 20
+//! Nahawand   C       C  D  Eb  F  G  Ab B         0 4 6 10 14 16 22
+//! Kurd       D       D  Eb F   G  A  Bb C         0 2 6 10 14 16 20
+//! ```
+//!
+//! Where ‡ is a half-flat, lowering a pitch by a quarter tone.
+//!
+//! The degrees are those of the ascending scale on its conventional tonic. The
+//! intonation of the quarter tones varies between regions and performers; they
+//! are given here at exactly 50 cents.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::constants::*;
+//! use mozzart_scales::Maqam;
+//!
+//! let rast = Maqam::Rast;
+//! assert_eq!(rast.cents(), [0.0, 200.0, 350.0, 500.0, 700.0, 900.0, 1050.0]);
+//! assert!(rast.is_microtonal());
+//! assert!(!Maqam::Hijaz.is_microtonal());
+//!
+//! let frequencies = rast.frequencies(C4);
+//! assert_eq!(frequencies[0], C4.frequency());
+//! assert!(EFLAT4.frequency() < frequencies[2] && frequencies[2] < E4.frequency());
+//! ```
+
+use std::fmt;
+
+use mozzart_core::{Interval, Pitch};
+
+/// The number of quarter tones in an octave.
+const QUARTER_TONES_PER_OCTAVE: u16 = 24;
+
+/// An interval measured in quarter tones.
+///
+/// The count is held in a `u16`, so that every [`Interval`] converts to quarter
+/// tones without overflowing.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::QuarterTones;
+///
+/// let neutral_third = QuarterTones::new(7);
+/// assert_eq!(neutral_third.cents(), 350.0);
+/// assert!(neutral_third.is_microtonal());
+///
+/// assert_eq!(QuarterTones::from(MAJOR_THIRD), QuarterTones::new(8));
+/// assert_eq!(QuarterTones::new(8).interval(), Some(MAJOR_THIRD));
+/// assert_eq!(neutral_third.interval(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuarterTones(u16);
+
+impl QuarterTones {
+    /// Creates a new interval from a number of quarter tones.
+    #[inline]
+    pub const fn new(count: u16) -> Self {
+        Self(count)
+    }
+
+    /// Returns the number of quarter tones in the interval.
+    #[inline]
+    pub const fn count(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the size of the interval in cents.
+    #[inline]
+    pub fn cents(&self) -> f64 {
+        self.0 as f64 * 50.0
+    }
+
+    /// Returns whether the interval lies between two equal-tempered semitones.
+    #[inline]
+    pub const fn is_microtonal(&self) -> bool {
+        self.0 % 2 == 1
+    }
+
+    /// Returns the equal-tempered interval of the same size, or `None` if the
+    /// interval is microtonal or wider than the largest [`Interval`].
+    #[inline]
+    pub const fn interval(&self) -> Option<Interval> {
+        if self.is_microtonal() || self.0 / 2 > u8::MAX as u16 {
+            None
+        } else {
+            Some(Interval::new((self.0 / 2) as u8))
+        }
+    }
+
+    /// Returns the frequency ratio of the interval.
+    #[inline]
+    pub fn ratio(&self) -> f64 {
+        2f64.powf(self.0 as f64 / QUARTER_TONES_PER_OCTAVE as f64)
+    }
+}
+
+impl From<Interval> for QuarterTones {
+    #[inline]
+    fn from(interval: Interval) -> Self {
+        Self(interval.semitones() as u16 * 2)
+    }
+}
+
+/// Represents a maqam, a scale of Arabic music.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_scales::{Maqam, QuarterTones};
+///
+/// assert_eq!(Maqam::Bayati.name(), "bayati");
+/// assert_eq!(Maqam::Bayati.degrees()[1], QuarterTones::new(3));
+/// assert_eq!(Maqam::Saba.to_string(), "saba");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Maqam {
+    /// Maqam Rast, with a half-flat third and seventh.
+    Rast,
+    /// Maqam Bayati, with a half-flat second.
+    Bayati,
+    /// Maqam Sikah, starting on a half-flat tonic.
+    Sikah,
+    /// Maqam Hijaz, with an augmented second between its second and third.
+    Hijaz,
+    /// Maqam Saba, with a half-flat second and a diminished fourth.
+    Saba,
+    /// Maqam Nahawand, matching the harmonic minor scale when ascending.
+    Nahawand,
+    /// Maqam Kurd, matching the phrygian mode.
+    Kurd,
+}
+
+impl Maqam {
+    /// All the maqamat, in the order of their declaration.
+    pub const ALL: [Maqam; 7] = [
+        Maqam::Rast,
+        Maqam::Bayati,
+        Maqam::Sikah,
+        Maqam::Hijaz,
+        Maqam::Saba,
+        Maqam::Nahawand,
+        Maqam::Kurd,
+    ];
+
+    /// Returns the name of the maqam.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Maqam::Rast => "rast",
+            Maqam::Bayati => "bayati",
+            Maqam::Sikah => "sikah",
+            Maqam::Hijaz => "hijaz",
+            Maqam::Saba => "saba",
+            Maqam::Nahawand => "nahawand",
+            Maqam::Kurd => "kurd",
+        }
+    }
+
+    /// Returns the degrees of the ascending maqam, measured from the tonic.
+    pub const fn degrees(&self) -> [QuarterTones; 7] {
+        let counts = match self {
+            Maqam::Rast => [0, 4, 7, 10, 14, 18, 21],
+            Maqam::Bayati => [0, 3, 6, 10, 14, 16, 20],
+            Maqam::Sikah => [0, 3, 7, 11, 14, 17, 21],
+            Maqam::Hijaz => [0, 2, 8, 10, 14, 16, 20],
+            Maqam::Saba => [0, 3, 6, 8, 14, 16, 20],
+            Maqam::Nahawand => [0, 4, 6, 10, 14, 16, 22],
+            Maqam::Kurd => [0, 2, 6, 10, 14, 16, 20],
+        };
+
+        let mut degrees = [QuarterTones(0); 7];
+        let mut i = 0;
+        while i < counts.len() {
+            degrees[i] = QuarterTones(counts[i]);
+            i += 1;
+        }
+        degrees
+    }
+
+    /// Returns the degrees of the ascending maqam in cents above the tonic.
+    pub fn cents(&self) -> [f64; 7] {
+        self.degrees().map(|degree| degree.cents())
+    }
+
+    /// Returns whether any degree of the maqam lies between two equal-tempered
+    /// semitones.
+    pub fn is_microtonal(&self) -> bool {
+        self.degrees().iter().any(QuarterTones::is_microtonal)
+    }
+
+    /// Returns the degrees of the maqam as equal-tempered intervals, or `None` if
+    /// the maqam is microtonal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::constants::*;
+    /// use mozzart_scales::Maqam;
+    ///
+    /// let hijaz = Maqam::Hijaz.intervals().unwrap();
+    /// assert_eq!(D4.apply_pattern(hijaz), [D4, EFLAT4, FSHARP4, G4, A4, BFLAT4, C5]);
+    ///
+    /// assert_eq!(Maqam::Rast.intervals(), None);
+    /// ```
+    pub fn intervals(&self) -> Option<[Interval; 7]> {
+        let degrees = self.degrees();
+        let mut intervals = [Interval::new(0); 7];
+        for (interval, degree) in intervals.iter_mut().zip(degrees) {
+            *interval = degree.interval()?;
+        }
+        Some(intervals)
+    }
+
+    /// Returns the frequencies in Hz of the degrees of the maqam on a tonic.
+    ///
+    /// The tonic keeps its equal-tempered frequency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mozzart_core::constants::*;
+    /// use mozzart_scales::Maqam;
+    ///
+    /// let bayati = Maqam::Bayati.frequencies(D4);
+    /// assert_eq!(bayati.len(), 7);
+    /// assert!((bayati[4] - A4.frequency()).abs() < 1e-9);
+    /// ```
+    pub fn frequencies(&self, tonic: Pitch) -> Vec<f64> {
+        let frequency = tonic.frequency();
+        self.degrees()
+            .iter()
+            .map(|degree| frequency * degree.ratio())
+            .collect()
+    }
+}
+
+impl fmt::Display for Maqam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HarmonicMinorScalePattern, PhrygianScalePattern};
+    use mozzart_core::ScalePattern;
+    use mozzart_core::constants::*;
+
+    #[test]
+    fn test_maqam_degrees() {
+        for maqam in Maqam::ALL {
+            let degrees = maqam.degrees();
+            assert_eq!(degrees[0], QuarterTones::new(0));
+            assert!(degrees.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(degrees[6].count() < QUARTER_TONES_PER_OCTAVE);
+            // Every maqam has a perfect fifth, even sikah from its half-flat tonic
+            let fifth = QuarterTones::from(PERFECT_FIFTH);
+            assert!(degrees.contains(&fifth), "{maqam}");
+        }
+    }
+
+    #[test]
+    fn test_quarter_tones_of_wide_intervals() {
+        let widest = QuarterTones::from(Interval::new(u8::MAX));
+        assert_eq!(widest.count(), 510);
+        assert_eq!(widest.cents(), 25500.0);
+        assert_eq!(widest.interval(), Some(Interval::new(u8::MAX)));
+        assert_eq!(QuarterTones::new(512).interval(), None);
+    }
+
+    #[test]
+    fn test_maqam_intervals() {
+        assert_eq!(
+            Maqam::Nahawand.intervals(),
+            Some(HarmonicMinorScalePattern::PATTERN)
+        );
+        assert_eq!(Maqam::Kurd.intervals(), Some(PhrygianScalePattern::PATTERN));
+
+        let microtonal: Vec<_> = Maqam::ALL
+            .into_iter()
+            .filter(Maqam::is_microtonal)
+            .collect();
+        assert_eq!(
+            microtonal,
+            [Maqam::Rast, Maqam::Bayati, Maqam::Sikah, Maqam::Saba]
+        );
+    }
+
+    #[test]
+    fn test_maqam_frequencies() {
+        let rast = Maqam::Rast.frequencies(C4);
+        let third = (rast[2] / C4.frequency()).log2() * 1200.0;
+        assert!((third - 350.0).abs() < 1e-9);
+        assert!((rast[4] - G4.frequency()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,257 @@
+//! Bebop scale implementations.
+//!
+//! The bebop scales add a chromatic passing tone to a seven-note scale. With
+//! eight notes, a line running in eighth notes from a chord tone on the beat
+//! keeps landing on chord tones on the beat.
+//!
+//! # Scale Structure
+//!
+//! ## Bebop Dominant
+//! The mixolydian mode with a passing major seventh:
+//! ```text
+//! W W H W W H H H
+//! ```
+//!
+//! ## Bebop Major
+//! The major scale with a passing augmented fifth:
+//! ```text
+//! W W H W H H W H
+//! ```
+//!
+//! ## Bebop Melodic Minor
+//! The melodic minor scale with a passing augmented fifth:
+//! ```text
+//! W H W W H H W H
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! ## Bebop Dominant
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Perfect 4th (5) +
+//! Perfect 5th (7) + Major 6th (9) + Minor 7th (10) + Major 7th (11)
+//! ```
+//!
+//! ## Bebop Major
+//! ```text
+//! Root (0) + Major 2nd (2) + Major 3rd (4) + Perfect 4th (5) +
+//! Perfect 5th (7) + Augmented 5th (8) + Major 6th (9) + Major 7th (11)
+//! ```
+//!
+//! ## Bebop Melodic Minor
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Perfect 4th (5) +
+//! Perfect 5th (7) + Augmented 5th (8) + Major 6th (9) + Major 7th (11)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for bebop dominant scales.
+///
+/// This type is used to distinguish bebop dominant scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BebopDominantScaleType;
+
+impl ScaleType for BebopDominantScaleType {
+    fn name() -> &'static str {
+        "bebop dominant"
+    }
+}
+
+/// The pattern for a bebop dominant scale.
+///
+/// This pattern defines the sequence of intervals that make up a bebop dominant scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Perfect 4th +
+/// Perfect 5th + Major 6th + Minor 7th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BebopDominantScalePattern;
+
+impl ScalePattern for BebopDominantScalePattern {
+    type Pattern = [Interval; 8];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = BebopDominantScaleType;
+}
+
+/// A marker type for bebop major scales.
+///
+/// This type is used to distinguish bebop major scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BebopMajorScaleType;
+
+impl ScaleType for BebopMajorScaleType {
+    fn name() -> &'static str {
+        "bebop major"
+    }
+}
+
+/// The pattern for a bebop major scale.
+///
+/// This pattern defines the sequence of intervals that make up a bebop major scale:
+/// ```text
+/// Root + Major 2nd + Major 3rd + Perfect 4th +
+/// Perfect 5th + Augmented 5th + Major 6th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BebopMajorScalePattern;
+
+impl ScalePattern for BebopMajorScalePattern {
+    type Pattern = [Interval; 8];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MAJOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        AUGMENTED_FIFTH,
+        MAJOR_SIXTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = BebopMajorScaleType;
+}
+
+/// A marker type for bebop melodic minor scales.
+///
+/// This type is used to distinguish bebop melodic minor scales from other scale
+/// types at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BebopMelodicMinorScaleType;
+
+impl ScaleType for BebopMelodicMinorScaleType {
+    fn name() -> &'static str {
+        "bebop melodic minor"
+    }
+}
+
+/// The pattern for a bebop melodic minor scale.
+///
+/// This pattern defines the sequence of intervals that make up a bebop melodic
+/// minor scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Perfect 4th +
+/// Perfect 5th + Augmented 5th + Major 6th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BebopMelodicMinorScalePattern;
+
+impl ScalePattern for BebopMelodicMinorScalePattern {
+    type Pattern = [Interval; 8];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        AUGMENTED_FIFTH,
+        MAJOR_SIXTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = BebopMelodicMinorScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MajorScalePattern, MelodicMinorScalePattern, MixolydianScalePattern};
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_bebop_dominant_scale() {
+        let scale = BebopDominantScalePattern::apply(G4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 8);
+        assert_eq!(pitches[0], G4);
+        assert_eq!(pitches[1], A4);
+        assert_eq!(pitches[2], B4);
+        assert_eq!(pitches[3], C5);
+        assert_eq!(pitches[4], D5);
+        assert_eq!(pitches[5], E5);
+        assert_eq!(pitches[6], F5);
+        assert_eq!(pitches[7], FSHARP5);
+
+        assert_eq!(scale.root(), G4);
+        assert_eq!(scale.name(), "bebop dominant");
+        assert_eq!(scale.to_string(), "G4 bebop dominant");
+    }
+
+    #[test]
+    fn test_bebop_major_scale() {
+        let scale = BebopMajorScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 8);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], E4);
+        assert_eq!(pitches[3], F4);
+        assert_eq!(pitches[4], G4);
+        assert_eq!(pitches[5], GSHARP4);
+        assert_eq!(pitches[6], A4);
+        assert_eq!(pitches[7], B4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "bebop major");
+        assert_eq!(scale.to_string(), "C4 bebop major");
+    }
+
+    #[test]
+    fn test_bebop_melodic_minor_scale() {
+        let scale = BebopMelodicMinorScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 8);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], EFLAT4);
+        assert_eq!(pitches[3], F4);
+        assert_eq!(pitches[4], G4);
+        assert_eq!(pitches[5], GSHARP4);
+        assert_eq!(pitches[6], A4);
+        assert_eq!(pitches[7], B4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "bebop melodic minor");
+        assert_eq!(scale.to_string(), "C4 bebop melodic minor");
+    }
+
+    #[test]
+    fn test_bebop_scales_add_one_passing_tone() {
+        let bebop = BebopDominantScalePattern::apply(D4).pitch_classes();
+        let mixolydian = MixolydianScalePattern::apply(D4).pitch_classes();
+        assert!(mixolydian.is_subset(&bebop));
+
+        let bebop = BebopMajorScalePattern::apply(F4).pitch_classes();
+        assert!(
+            MajorScalePattern::apply(F4)
+                .pitch_classes()
+                .is_subset(&bebop)
+        );
+
+        let bebop = BebopMelodicMinorScalePattern::apply(A4).pitch_classes();
+        assert!(
+            MelodicMinorScalePattern::apply(A4)
+                .pitch_classes()
+                .is_subset(&bebop)
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Diminished scale implementations.
+//!
+//! The diminished scales are the two octatonic scales alternating half and whole
+//! steps. They are symmetric by a minor third, so each has only three distinct
+//! transpositions, and they are used over diminished seventh and dominant chords.
+//!
+//! # Scale Structure
+//!
+//! ## Half-Whole Form
+//! ```text
+//! H W H W H W H W
+//! ```
+//!
+//! ## Whole-Half Form
+//! ```text
+//! W H W H W H W H
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//!
+//! # Interval Pattern
+//!
+//! ## Half-Whole Form
+//! ```text
+//! Root (0) + Minor 2nd (1) + Minor 3rd (3) + Major 3rd (4) +
+//! Augmented 4th (6) + Perfect 5th (7) + Major 6th (9) + Minor 7th (10)
+//! ```
+//!
+//! ## Whole-Half Form
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Perfect 4th (5) +
+//! Diminished 5th (6) + Minor 6th (8) + Diminished 7th (9) + Major 7th (11)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for half-whole diminished scales.
+///
+/// This type is used to distinguish half-whole diminished scales from other
+/// scale types at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HalfWholeDiminishedScaleType;
+
+impl ScaleType for HalfWholeDiminishedScaleType {
+    fn name() -> &'static str {
+        "half-whole diminished"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["dominant diminished"]
+    }
+}
+
+/// The pattern for a half-whole diminished scale, also known as the dominant
+/// diminished scale.
+///
+/// This pattern defines the sequence of intervals that make up a half-whole
+/// diminished scale:
+/// ```text
+/// Root + Minor 2nd + Minor 3rd + Major 3rd +
+/// Augmented 4th + Perfect 5th + Major 6th + Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HalfWholeDiminishedScalePattern;
+
+impl ScalePattern for HalfWholeDiminishedScalePattern {
+    type Pattern = [Interval; 8];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        MINOR_THIRD,
+        MAJOR_THIRD,
+        AUGMENTED_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = HalfWholeDiminishedScaleType;
+}
+
+/// A marker type for whole-half diminished scales.
+///
+/// This type is used to distinguish whole-half diminished scales from other
+/// scale types at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WholeHalfDiminishedScaleType;
+
+impl ScaleType for WholeHalfDiminishedScaleType {
+    fn name() -> &'static str {
+        "whole-half diminished"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["diminished"]
+    }
+}
+
+/// The pattern for a whole-half diminished scale.
+///
+/// This pattern defines the sequence of intervals that make up a whole-half
+/// diminished scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Perfect 4th +
+/// Diminished 5th + Minor 6th + Diminished 7th + Major 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WholeHalfDiminishedScalePattern;
+
+impl ScalePattern for WholeHalfDiminishedScalePattern {
+    type Pattern = [Interval; 8];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FOURTH,
+        DIMINISHED_FIFTH,
+        MINOR_SIXTH,
+        DIMINISHED_SEVENTH,
+        MAJOR_SEVENTH,
+    ];
+
+    type ScaleTyp = WholeHalfDiminishedScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_half_whole_diminished_scale() {
+        let scale = HalfWholeDiminishedScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 8);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], CSHARP4);
+        assert_eq!(pitches[2], DSHARP4);
+        assert_eq!(pitches[3], E4);
+        assert_eq!(pitches[4], FSHARP4);
+        assert_eq!(pitches[5], G4);
+        assert_eq!(pitches[6], A4);
+        assert_eq!(pitches[7], ASHARP4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "half-whole diminished");
+        assert_eq!(scale.to_string(), "C4 half-whole diminished");
+    }
+
+    #[test]
+    fn test_whole_half_diminished_scale() {
+        let scale = WholeHalfDiminishedScalePattern::apply(C4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 8);
+        assert_eq!(pitches[0], C4);
+        assert_eq!(pitches[1], D4);
+        assert_eq!(pitches[2], DSHARP4);
+        assert_eq!(pitches[3], F4);
+        assert_eq!(pitches[4], FSHARP4);
+        assert_eq!(pitches[5], GSHARP4);
+        assert_eq!(pitches[6], A4);
+        assert_eq!(pitches[7], B4);
+
+        assert_eq!(scale.root(), C4);
+        assert_eq!(scale.name(), "whole-half diminished");
+        assert_eq!(scale.to_string(), "C4 whole-half diminished");
+    }
+
+    #[test]
+    fn test_diminished_scales_are_rotations() {
+        // Starting the half-whole scale on its second degree gives the whole-half scale
+        let half_whole = HalfWholeDiminishedScalePattern::apply(B3);
+        let whole_half = WholeHalfDiminishedScalePattern::apply(C4);
+        assert_eq!(half_whole.rotate(1), whole_half.pitches());
+
+        assert_eq!(half_whole.transpositional_symmetries().len(), 4);
+        assert!(whole_half.same_pitches(&WholeHalfDiminishedScalePattern::apply(DSHARP4)));
+    }
+}
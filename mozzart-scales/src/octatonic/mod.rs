@@ -0,0 +1,5 @@
+pub mod bebop;
+pub mod diminished;
+
+pub use bebop::*;
+pub use diminished::*;
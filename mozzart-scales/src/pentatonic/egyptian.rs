@@ -0,0 +1,96 @@
+//! Egyptian scale implementation.
+//!
+//! The Egyptian scale, also known as the suspended pentatonic scale, has no
+//! third and no half steps. It has the pitches of the pentatonic major scale,
+//! starting on the second degree.
+//!
+//! # Scale Structure
+//!
+//! The Egyptian scale follows the pattern of whole and half steps:
+//! ```text
+//! W WH W WH W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The Egyptian scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Perfect 4th (5) + Perfect 5th (7) +
+//! Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for Egyptian scales.
+///
+/// This type is used to distinguish Egyptian scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EgyptianScaleType;
+
+impl ScaleType for EgyptianScaleType {
+    fn name() -> &'static str {
+        "egyptian"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["suspended pentatonic"]
+    }
+}
+
+/// The pattern for a Egyptian scale.
+///
+/// This pattern defines the sequence of intervals that make up a Egyptian scale:
+/// ```text
+/// Root + Major 2nd + Perfect 4th + Perfect 5th +
+/// Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EgyptianScalePattern;
+
+impl ScalePattern for EgyptianScalePattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = EgyptianScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PentatonicMajorScalePattern;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_egyptian_scale() {
+        let scale = EgyptianScalePattern::apply(G4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], G4);
+        assert_eq!(pitches[1], A4);
+        assert_eq!(pitches[2], C5);
+        assert_eq!(pitches[3], D5);
+        assert_eq!(pitches[4], F5);
+
+        assert_eq!(scale.root(), G4);
+        assert_eq!(scale.name(), "egyptian");
+        assert_eq!(scale.to_string(), "G4 egyptian");
+    }
+
+    #[test]
+    fn test_egyptian_is_mode_of_pentatonic_major() {
+        let scale = EgyptianScalePattern::apply(G4);
+        assert!(scale.same_pitches(&PentatonicMajorScalePattern::apply(F4)));
+    }
+}
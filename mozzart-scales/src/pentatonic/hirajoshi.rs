@@ -0,0 +1,86 @@
+//! Hirajoshi scale implementation.
+//!
+//! The hirajoshi scale is a Japanese pentatonic scale adapted from the tuning
+//! of the koto. It is a natural minor scale without its fourth and seventh, and
+//! its two half steps give it a darker sound than the pentatonic minor scale.
+//!
+//! # Scale Structure
+//!
+//! The hirajoshi scale follows the pattern of whole and half steps:
+//! ```text
+//! W H WW H WW
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WW = Major third (4 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The hirajoshi scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Minor 3rd (3) + Perfect 5th (7) +
+//! Minor 6th (8)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for hirajoshi scales.
+///
+/// This type is used to distinguish hirajoshi scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HirajoshiScaleType;
+
+impl ScaleType for HirajoshiScaleType {
+    fn name() -> &'static str {
+        "hirajoshi"
+    }
+}
+
+/// The pattern for a hirajoshi scale.
+///
+/// This pattern defines the sequence of intervals that make up a hirajoshi scale:
+/// ```text
+/// Root + Major 2nd + Minor 3rd + Perfect 5th +
+/// Minor 6th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HirajoshiScalePattern;
+
+impl ScalePattern for HirajoshiScalePattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        MINOR_THIRD,
+        PERFECT_FIFTH,
+        MINOR_SIXTH,
+    ];
+
+    type ScaleTyp = HirajoshiScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_hirajoshi_scale() {
+        let scale = HirajoshiScalePattern::apply(A4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], A4);
+        assert_eq!(pitches[1], B4);
+        assert_eq!(pitches[2], C5);
+        assert_eq!(pitches[3], E5);
+        assert_eq!(pitches[4], F5);
+
+        assert_eq!(scale.root(), A4);
+        assert_eq!(scale.name(), "hirajoshi");
+        assert_eq!(scale.to_string(), "A4 hirajoshi");
+    }
+}
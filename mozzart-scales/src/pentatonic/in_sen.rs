@@ -0,0 +1,87 @@
+//! In-sen scale implementation.
+//!
+//! The in-sen scale is a Japanese pentatonic scale used in shakuhachi and koto
+//! music. It is a phrygian mode without its third and sixth, opening with a
+//! half step.
+//!
+//! # Scale Structure
+//!
+//! The in-sen scale follows the pattern of whole and half steps:
+//! ```text
+//! H WW W WH W
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - H = Half step (1 semitone)
+//! - WH = Augmented second (3 semitones)
+//! - WW = Major third (4 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The in-sen scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Minor 2nd (1) + Perfect 4th (5) + Perfect 5th (7) +
+//! Minor 7th (10)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for in-sen scales.
+///
+/// This type is used to distinguish in-sen scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InSenScaleType;
+
+impl ScaleType for InSenScaleType {
+    fn name() -> &'static str {
+        "in-sen"
+    }
+}
+
+/// The pattern for a in-sen scale.
+///
+/// This pattern defines the sequence of intervals that make up a in-sen scale:
+/// ```text
+/// Root + Minor 2nd + Perfect 4th + Perfect 5th +
+/// Minor 7th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InSenScalePattern;
+
+impl ScalePattern for InSenScalePattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MINOR_SECOND,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MINOR_SEVENTH,
+    ];
+
+    type ScaleTyp = InSenScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_in_sen_scale() {
+        let scale = InSenScalePattern::apply(E4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], E4);
+        assert_eq!(pitches[1], F4);
+        assert_eq!(pitches[2], A4);
+        assert_eq!(pitches[3], B4);
+        assert_eq!(pitches[4], D5);
+
+        assert_eq!(scale.root(), E4);
+        assert_eq!(scale.name(), "in-sen");
+        assert_eq!(scale.to_string(), "E4 in-sen");
+    }
+}
@@ -1,5 +1,13 @@
+pub mod egyptian;
+pub mod hirajoshi;
+pub mod in_sen;
 pub mod pentatonic_major;
 pub mod pentatonic_minor;
+pub mod yo;
 
+pub use egyptian::*;
+pub use hirajoshi::*;
+pub use in_sen::*;
 pub use pentatonic_major::*;
 pub use pentatonic_minor::*;
+pub use yo::*;
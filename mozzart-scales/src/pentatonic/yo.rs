@@ -0,0 +1,92 @@
+//! Yo scale implementation.
+//!
+//! The yo scale is a Japanese pentatonic scale without half steps, used in
+//! folk songs and gagaku. It has the pitches of the pentatonic major scale,
+//! starting on the fourth degree.
+//!
+//! # Scale Structure
+//!
+//! The yo scale follows the pattern of whole and half steps:
+//! ```text
+//! W WH W W WH
+//! ```
+//!
+//! Where:
+//! - W = Whole step (2 semitones)
+//! - WH = Augmented second (3 semitones)
+//!
+//! # Interval Pattern
+//!
+//! The yo scale is built using the following intervals from the root:
+//! ```text
+//! Root (0) + Major 2nd (2) + Perfect 4th (5) + Perfect 5th (7) +
+//! Major 6th (9)
+//! ```
+use mozzart_core::constants::*;
+use mozzart_core::{Interval, ScalePattern, ScaleType};
+
+/// A marker type for yo scales.
+///
+/// This type is used to distinguish yo scales from other scale types
+/// at the type level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YoScaleType;
+
+impl ScaleType for YoScaleType {
+    fn name() -> &'static str {
+        "yo"
+    }
+}
+
+/// The pattern for a yo scale.
+///
+/// This pattern defines the sequence of intervals that make up a yo scale:
+/// ```text
+/// Root + Major 2nd + Perfect 4th + Perfect 5th +
+/// Major 6th
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YoScalePattern;
+
+impl ScalePattern for YoScalePattern {
+    type Pattern = [Interval; 5];
+    const PATTERN: Self::Pattern = [
+        PERFECT_UNISON,
+        MAJOR_SECOND,
+        PERFECT_FOURTH,
+        PERFECT_FIFTH,
+        MAJOR_SIXTH,
+    ];
+
+    type ScaleTyp = YoScaleType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PentatonicMajorScalePattern;
+    use mozzart_core::ScalePattern;
+
+    #[test]
+    fn test_yo_scale() {
+        let scale = YoScalePattern::apply(D4);
+
+        let pitches = scale.pitches();
+        assert_eq!(pitches.len(), 5);
+        assert_eq!(pitches[0], D4);
+        assert_eq!(pitches[1], E4);
+        assert_eq!(pitches[2], G4);
+        assert_eq!(pitches[3], A4);
+        assert_eq!(pitches[4], B4);
+
+        assert_eq!(scale.root(), D4);
+        assert_eq!(scale.name(), "yo");
+        assert_eq!(scale.to_string(), "D4 yo");
+    }
+
+    #[test]
+    fn test_yo_is_mode_of_pentatonic_major() {
+        let scale = YoScalePattern::apply(D4);
+        assert!(scale.same_pitches(&PentatonicMajorScalePattern::apply(G4)));
+    }
+}
@@ -0,0 +1,135 @@
+//! One-line conveniences for common theory questions.
+//!
+//! This module provides functionality for getting results from plain text,
+//! without naming pattern types, including:
+//! - Building a scale from a root and a scale name, such as `"D dorian"`
+//!
+//! The functions are built on the pitch parser of `mozzart-core` and on the scale
+//! [`catalogue`](crate::catalogue). They are not re-exported at the crate root and
+//! are meant to be called through the module:
+//!
+//! ```text
+//! theory::scale("D4 dorian")          D4 E4 F4 G4 A4 B4 C5
+//! theory::scale("Bb4 melodic minor")  Bb4 C5 Db5 Eb5 F5 G5 A5
+//! ```
+//!
+//! Chord symbols and chart transposition need a chord symbol parser and a chart
+//! model, which do not exist yet, and are not covered.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mozzart_core::constants::*;
+//! use mozzart_scales::theory;
+//!
+//! assert_eq!(theory::scale("A4 pentatonic minor")?, [A4, C5, D5, E5, G5]);
+//! # Ok::<(), theory::ParseScaleError>(())
+//! ```
+
+use std::fmt;
+
+use mozzart_core::{ParsePitchError, Pitch};
+
+use crate::catalogue::intervals_for;
+
+/// Builds a scale from a root pitch and a catalogued scale name, separated by
+/// whitespace.
+///
+/// The root is parsed like [`Pitch`]: without an octave number the scale starts
+/// on the canonical pitch. Scale names are compared ignoring ASCII case.
+///
+/// # Errors
+///
+/// Returns an error if the root is not a valid pitch name, if the scale name is
+/// not catalogued, or if the scale would go above G9.
+///
+/// # Examples
+///
+/// ```rust
+/// use mozzart_core::constants::*;
+/// use mozzart_scales::theory::{self, ParseScaleError};
+///
+/// assert_eq!(theory::scale("D4 dorian")?, [D4, E4, F4, G4, A4, B4, C5]);
+/// assert_eq!(theory::scale("D dorian")?[..3], [D, E, F]);
+/// assert_eq!(theory::scale("C4 Whole Tone")?, [C4, D4, E4, FSHARP4, GSHARP4, ASHARP4]);
+///
+/// assert_eq!(theory::scale("D bebop"), Err(ParseScaleError::UnknownScale));
+/// assert!(matches!(theory::scale("H dorian"), Err(ParseScaleError::InvalidRoot(_))));
+/// # Ok::<(), ParseScaleError>(())
+/// ```
+pub fn scale(name: &str) -> Result<Vec<Pitch>, ParseScaleError> {
+    let name = name.trim();
+    let (root, scale) = name
+        .split_once(char::is_whitespace)
+        .ok_or(ParseScaleError::MissingScale)?;
+    let root: Pitch = root.parse().map_err(ParseScaleError::InvalidRoot)?;
+    let intervals = intervals_for(scale.trim()).ok_or(ParseScaleError::UnknownScale)?;
+
+    intervals
+        .into_iter()
+        .map(|interval| root.checked_transpose(interval))
+        .collect::<Option<_>>()
+        .ok_or(ParseScaleError::OutOfRange)
+}
+
+/// An error returned when building a scale from text fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseScaleError {
+    /// The input had no scale name after the root.
+    MissingScale,
+    /// The root was not a valid pitch name.
+    InvalidRoot(ParsePitchError),
+    /// The scale name was not found in the catalogue.
+    UnknownScale,
+    /// The scale goes above the MIDI range (C-1 to G9).
+    OutOfRange,
+}
+
+impl fmt::Display for ParseScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseScaleError::MissingScale => write!(f, "missing scale name"),
+            ParseScaleError::InvalidRoot(error) => write!(f, "invalid root: {}", error),
+            ParseScaleError::UnknownScale => write!(f, "unknown scale name"),
+            ParseScaleError::OutOfRange => write!(f, "scale outside the MIDI range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseScaleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use mozzart_core::ScalePattern;
+    use mozzart_core::constants::*;
+
+    #[test]
+    fn test_scale_matches_patterns() {
+        assert_eq!(
+            scale("C4 major").unwrap(),
+            MajorScalePattern::apply(C4).pitches()
+        );
+        assert_eq!(
+            scale("  F#3   harmonic minor ").unwrap(),
+            HarmonicMinorScalePattern::apply(FSHARP3).pitches()
+        );
+        assert_eq!(scale("eb blues").unwrap()[0], EFLAT);
+    }
+
+    #[test]
+    fn test_scale_errors() {
+        assert_eq!(scale("dorian"), Err(ParseScaleError::MissingScale));
+        assert_eq!(scale(""), Err(ParseScaleError::MissingScale));
+        assert_eq!(
+            scale("X4 dorian"),
+            Err(ParseScaleError::InvalidRoot(ParsePitchError::InvalidLetter))
+        );
+        assert_eq!(scale("G9 major"), Err(ParseScaleError::OutOfRange));
+        assert_eq!(
+            ParseScaleError::InvalidRoot(ParsePitchError::Empty).to_string(),
+            "invalid root: empty pitch name"
+        );
+    }
+}